@@ -0,0 +1,1945 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Storage, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_storage_plus::Bound;
+
+use common::{Asset, AssetInfo};
+
+use crate::error::ContractError;
+use crate::msg::{
+    AuctionResponse, AuctionsResponse, BidHistoryEntry, BidHistoryResponse, ConfigResponse,
+    EscrowResponse, EscrowsResponse, ExecuteMsg, IncentiveAllocation, IncentiveStandingResponse,
+    IncentivesResponse, InstantiateMsg, MigrateMsg, PendingGovernanceResponse, QueryMsg,
+    ReceiveMsg, TopBidderEntry, TopBiddersResponse, Ust1ControllerExecuteMsg,
+};
+use crate::state::{
+    Auction, AuctionStatus, BidRecord, Config, PendingGovernance, TopBidder, AUCTIONS,
+    AUCTION_DURATION, BID_COUNTS, BID_EXTENSION, BID_HISTORY, CONFIG, CONTRACT_NAME,
+    CONTRACT_VERSION, DEFAULT_QUERY_LIMIT, EARLY_BIDDER_SHARE, EARLY_BIDDER_WINDOW, ESCROWS,
+    FREQUENCY_SHARES, GOVERNANCE_TIMELOCK, INCENTIVE_RATE, MAX_QUERY_LIMIT, MAX_TOP_BIDDERS,
+    NEXT_AUCTION_ID, PENDING_GOVERNANCE, SETTLEMENT_GRACE_PERIOD, TOP_BIDDERS,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        treasury: deps.api.addr_validate(&msg.treasury)?,
+        ust1_controller: deps.api.addr_validate(&msg.ust1_controller)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    NEXT_AUCTION_ID.save(deps.storage, &1)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("treasury", config.treasury)
+        .add_attribute("ust1_controller", config.ust1_controller))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::CreateAuction {
+            collateral_token,
+            ust1_amount,
+            min_bid,
+        } => execute_create_auction(deps, env, info, collateral_token, ust1_amount, min_bid),
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, env, info, cw20_msg),
+        ExecuteMsg::WithdrawOutbid { auction_id } => {
+            execute_withdraw_outbid(deps, info, auction_id)
+        }
+        ExecuteMsg::Settle { auction_id } => execute_settle(deps, env, auction_id),
+        ExecuteMsg::CancelIfNoBids { auction_id } => {
+            execute_cancel_if_no_bids(deps, env, info, auction_id)
+        }
+        ExecuteMsg::ExpireUnsettled { auction_id } => {
+            execute_expire_unsettled(deps, env, info, auction_id)
+        }
+        ExecuteMsg::UpdateConfig {
+            treasury,
+            ust1_controller,
+        } => execute_update_config(deps, info, treasury, ust1_controller),
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_create_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collateral_token: String,
+    ust1_amount: Uint128,
+    min_bid: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+    if ust1_amount.is_zero() || min_bid.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    let collateral_token = deps.api.addr_validate(&collateral_token)?;
+    let id = NEXT_AUCTION_ID.load(deps.storage)?;
+    NEXT_AUCTION_ID.save(deps.storage, &(id + 1))?;
+
+    let auction = Auction {
+        id,
+        collateral_token,
+        ust1_amount,
+        min_bid,
+        start_time: env.block.time,
+        end_time: env.block.time.plus_seconds(AUCTION_DURATION),
+        highest_bidder: None,
+        highest_bid: Uint128::zero(),
+        bid_count: 0,
+        status: AuctionStatus::Active,
+    };
+    AUCTIONS.save(deps.storage, id, &auction)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_auction")
+        .add_attribute("auction_id", id.to_string())
+        .add_attribute("collateral_token", auction.collateral_token)
+        .add_attribute("ust1_amount", ust1_amount)
+        .add_attribute("min_bid", min_bid)
+        .add_attribute("end_time", auction.end_time.seconds().to_string()))
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let bidder = deps.api.addr_validate(&cw20_msg.sender)?;
+    match from_json(&cw20_msg.msg)? {
+        ReceiveMsg::Bid { auction_id } => execute_bid(
+            deps,
+            env,
+            info.sender,
+            bidder,
+            auction_id,
+            cw20_msg.amount,
+            false,
+        ),
+        ReceiveMsg::TopUp { auction_id } => execute_bid(
+            deps,
+            env,
+            info.sender,
+            bidder,
+            auction_id,
+            cw20_msg.amount,
+            true,
+        ),
+    }
+}
+
+/// Adds `amount` to the bidder's escrow and makes the new total their bid
+fn execute_bid(
+    deps: DepsMut,
+    env: Env,
+    token: Addr,
+    bidder: Addr,
+    auction_id: u64,
+    amount: Uint128,
+    top_up: bool,
+) -> Result<Response, ContractError> {
+    let mut auction = load_auction(deps.storage, auction_id)?;
+    if token != auction.collateral_token {
+        return Err(ContractError::InvalidCollateral {
+            auction_id,
+            expected: auction.collateral_token.to_string(),
+        });
+    }
+    if auction.status != AuctionStatus::Active {
+        return Err(ContractError::AuctionNotActive { auction_id });
+    }
+    if env.block.time >= auction.end_time {
+        return Err(ContractError::AuctionEnded { auction_id });
+    }
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    let existing = ESCROWS
+        .may_load(deps.storage, (auction_id, &bidder))?
+        .unwrap_or_default();
+    if top_up && existing.is_zero() {
+        return Err(ContractError::NoExistingBid { auction_id });
+    }
+    if !top_up && !existing.is_zero() {
+        return Err(ContractError::AlreadyBidding { auction_id });
+    }
+
+    let bid = existing.checked_add(amount).map_err(StdError::from)?;
+    let min_bid = auction.next_min_bid();
+    if bid < min_bid {
+        return Err(ContractError::BidTooLow { bid, min_bid });
+    }
+
+    ESCROWS.save(deps.storage, (auction_id, &bidder), &bid)?;
+
+    BID_HISTORY.save(
+        deps.storage,
+        (auction_id, auction.bid_count),
+        &BidRecord {
+            bidder: bidder.clone(),
+            amount: bid,
+            time: env.block.time,
+        },
+    )?;
+    let bidder_count = BID_COUNTS
+        .may_load(deps.storage, (auction_id, &bidder))?
+        .unwrap_or_default()
+        + 1;
+    BID_COUNTS.save(deps.storage, (auction_id, &bidder), &bidder_count)?;
+    update_top_bidders(deps.storage, auction_id, &bidder, bidder_count)?;
+
+    // +8h per bid, never more than 48h remaining
+    let extended = auction.end_time.plus_seconds(BID_EXTENSION);
+    let cap = env.block.time.plus_seconds(AUCTION_DURATION);
+    auction.end_time = extended.min(cap);
+    auction.highest_bidder = Some(bidder.clone());
+    auction.highest_bid = bid;
+    auction.bid_count += 1;
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    Ok(Response::new()
+        .add_attribute("action", if top_up { "top_up" } else { "bid" })
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bidder", bidder)
+        .add_attribute("amount", amount)
+        .add_attribute("bid", bid)
+        .add_attribute("bidder_bid_count", bidder_count.to_string())
+        .add_attribute("end_time", auction.end_time.seconds().to_string()))
+}
+
+fn execute_withdraw_outbid(
+    deps: DepsMut,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let auction = load_auction(deps.storage, auction_id)?;
+    if auction.status == AuctionStatus::Active
+        && auction.highest_bidder.as_ref() == Some(&info.sender)
+    {
+        return Err(ContractError::HighestBidderCannotWithdraw);
+    }
+
+    let amount = ESCROWS
+        .may_load(deps.storage, (auction_id, &info.sender))?
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw);
+    }
+    ESCROWS.remove(deps.storage, (auction_id, &info.sender));
+
+    let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: auction.collateral_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(transfer_msg)
+        .add_attribute("action", "withdraw_outbid")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bidder", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_settle(deps: DepsMut, env: Env, auction_id: u64) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut auction = load_auction(deps.storage, auction_id)?;
+    if auction.status != AuctionStatus::Active {
+        return Err(ContractError::AuctionNotActive { auction_id });
+    }
+    if env.block.time < auction.end_time {
+        return Err(ContractError::AuctionNotEnded {
+            auction_id,
+            remaining_seconds: auction.end_time.seconds() - env.block.time.seconds(),
+        });
+    }
+    let winner = auction
+        .highest_bidder
+        .clone()
+        .ok_or(ContractError::NoBids { auction_id })?;
+
+    auction.status = AuctionStatus::Settled;
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+    ESCROWS.remove(deps.storage, (auction_id, &winner));
+
+    let collateral = Asset {
+        info: AssetInfo::Cw20 {
+            contract_addr: auction.collateral_token.clone(),
+        },
+        amount: auction.highest_bid,
+    };
+
+    let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: auction.collateral_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: config.treasury.to_string(),
+            amount: auction.highest_bid,
+        })?,
+        funds: vec![],
+    });
+    let mint_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_controller.to_string(),
+        msg: to_json_binary(&Ust1ControllerExecuteMsg::Mint {
+            recipient: winner.to_string(),
+            amount: auction.ust1_amount,
+            collateral: Some(collateral),
+        })?,
+        funds: vec![],
+    });
+
+    let mut response = Response::new()
+        .add_message(transfer_msg)
+        .add_message(mint_msg)
+        .add_attribute("action", "settle")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("winner", winner)
+        .add_attribute("collateral_amount", auction.highest_bid)
+        .add_attribute("ust1_amount", auction.ust1_amount);
+
+    // Bidding incentives are minted in the same transaction
+    let incentives = compute_incentives(deps.storage, &auction)?;
+    if let Some(early_bidder) = &incentives.early_bidder {
+        response = response.add_attribute("early_bidder", early_bidder);
+    }
+    for allocation in incentives.allocations {
+        response = response
+            .add_message(WasmMsg::Execute {
+                contract_addr: config.ust1_controller.to_string(),
+                msg: to_json_binary(&Ust1ControllerExecuteMsg::Mint {
+                    recipient: allocation.recipient.to_string(),
+                    amount: allocation.amount,
+                    collateral: None,
+                })?,
+                funds: vec![],
+            })
+            .add_attribute(
+                "incentive",
+                format!("{}:{}", allocation.recipient, allocation.amount),
+            );
+    }
+
+    Ok(response)
+}
+
+fn execute_cancel_if_no_bids(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut auction = load_auction(deps.storage, auction_id)?;
+    if auction.status != AuctionStatus::Active {
+        return Err(ContractError::AuctionNotActive { auction_id });
+    }
+    if auction.highest_bidder.is_some() {
+        return Err(ContractError::AuctionHasBids { auction_id });
+    }
+    if info.sender != config.governance && env.block.time < auction.end_time {
+        return Err(ContractError::AuctionNotEnded {
+            auction_id,
+            remaining_seconds: auction.end_time.seconds() - env.block.time.seconds(),
+        });
+    }
+
+    auction.status = AuctionStatus::Cancelled;
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_auction")
+        .add_attribute("auction_id", auction_id.to_string()))
+}
+
+/// Escape hatch for auctions whose settlement keeps failing. The collateral
+/// stays in escrow and the winner reclaims it through WithdrawOutbid; no UST1
+/// is minted.
+fn execute_expire_unsettled(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut auction = load_auction(deps.storage, auction_id)?;
+    if auction.status != AuctionStatus::Active {
+        return Err(ContractError::AuctionNotActive { auction_id });
+    }
+    if env.block.time < auction.end_time {
+        return Err(ContractError::AuctionNotEnded {
+            auction_id,
+            remaining_seconds: auction.end_time.seconds() - env.block.time.seconds(),
+        });
+    }
+    let winner = auction
+        .highest_bidder
+        .clone()
+        .ok_or(ContractError::NoBids { auction_id })?;
+
+    let grace_end = auction.end_time.plus_seconds(SETTLEMENT_GRACE_PERIOD);
+    if info.sender != config.governance && env.block.time < grace_end {
+        return Err(ContractError::SettlementGracePeriod {
+            auction_id,
+            remaining_seconds: grace_end.seconds() - env.block.time.seconds(),
+        });
+    }
+
+    auction.status = AuctionStatus::Expired;
+    AUCTIONS.save(deps.storage, auction_id, &auction)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "expire_unsettled")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("highest_bidder", winner)
+        .add_attribute("highest_bid", auction.highest_bid))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    treasury: Option<String>,
+    ust1_controller: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if let Some(treasury) = treasury {
+        config.treasury = deps.api.addr_validate(&treasury)?;
+    }
+    if let Some(ust1_controller) = ust1_controller {
+        config.ust1_controller = deps.api.addr_validate(&ust1_controller)?;
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("treasury", config.treasury)
+        .add_attribute("ust1_controller", config.ust1_controller))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Auction { auction_id } => to_json_binary(&query_auction(deps, auction_id)?),
+        QueryMsg::Auctions {
+            start_after,
+            limit,
+            status,
+        } => to_json_binary(&query_auctions(deps, start_after, limit, status)?),
+        QueryMsg::Escrow { auction_id, bidder } => {
+            to_json_binary(&query_escrow(deps, auction_id, bidder)?)
+        }
+        QueryMsg::Escrows {
+            auction_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_escrows(deps, auction_id, start_after, limit)?),
+        QueryMsg::BidHistory {
+            auction_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_bid_history(deps, auction_id, start_after, limit)?),
+        QueryMsg::TopBidders { auction_id } => {
+            to_json_binary(&query_top_bidders(deps, auction_id)?)
+        }
+        QueryMsg::Incentives { auction_id } => {
+            let auction = AUCTIONS.load(deps.storage, auction_id)?;
+            to_json_binary(&compute_incentives(deps.storage, &auction)?)
+        }
+        QueryMsg::IncentiveStanding { auction_id, bidder } => {
+            to_json_binary(&query_incentive_standing(deps, auction_id, bidder)?)
+        }
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        treasury: config.treasury,
+        ust1_controller: config.ust1_controller,
+    })
+}
+
+fn query_auction(deps: Deps, auction_id: u64) -> StdResult<AuctionResponse> {
+    let auction = AUCTIONS.load(deps.storage, auction_id)?;
+    Ok(auction_response(auction))
+}
+
+fn query_auctions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    status: Option<AuctionStatus>,
+) -> StdResult<AuctionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let auctions = AUCTIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|r| match (r, status) {
+            (Ok((_, auction)), Some(status)) => auction.status == status,
+            _ => true,
+        })
+        .take(limit)
+        .map(|r| r.map(|(_, auction)| auction_response(auction)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AuctionsResponse { auctions })
+}
+
+fn query_escrow(deps: Deps, auction_id: u64, bidder: String) -> StdResult<EscrowResponse> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    let amount = ESCROWS
+        .may_load(deps.storage, (auction_id, &bidder))?
+        .unwrap_or_default();
+    Ok(EscrowResponse { bidder, amount })
+}
+
+fn query_escrows(
+    deps: Deps,
+    auction_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<EscrowsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let escrows = ESCROWS
+        .prefix(auction_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(bidder, amount)| EscrowResponse { bidder, amount }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(EscrowsResponse { escrows })
+}
+
+fn query_bid_history(
+    deps: Deps,
+    auction_id: u64,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> StdResult<BidHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let bids = BID_HISTORY
+        .prefix(auction_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| {
+            r.map(|(sequence, record)| BidHistoryEntry {
+                sequence,
+                bidder: record.bidder,
+                amount: record.amount,
+                time: record.time,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(BidHistoryResponse { bids })
+}
+
+fn query_top_bidders(deps: Deps, auction_id: u64) -> StdResult<TopBiddersResponse> {
+    let auction = AUCTIONS.load(deps.storage, auction_id)?;
+    let pool = incentive_pool(&auction);
+    let top_bidders = TOP_BIDDERS
+        .may_load(deps.storage, auction_id)?
+        .unwrap_or_default()
+        .into_iter()
+        .zip(FREQUENCY_SHARES)
+        .enumerate()
+        .map(|(i, (top, share))| TopBidderEntry {
+            rank: i as u32 + 1,
+            bidder: top.bidder,
+            bids: top.bids,
+            incentive: pool.mul_floor(share),
+        })
+        .collect();
+
+    Ok(TopBiddersResponse { top_bidders })
+}
+
+fn query_incentive_standing(
+    deps: Deps,
+    auction_id: u64,
+    bidder: String,
+) -> StdResult<IncentiveStandingResponse> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    let auction = AUCTIONS.load(deps.storage, auction_id)?;
+    let bid_count = BID_COUNTS
+        .may_load(deps.storage, (auction_id, &bidder))?
+        .unwrap_or_default();
+    let frequency_rank = TOP_BIDDERS
+        .may_load(deps.storage, auction_id)?
+        .unwrap_or_default()
+        .iter()
+        .position(|top| top.bidder == bidder)
+        .map(|i| i as u32 + 1);
+
+    let incentives = compute_incentives(deps.storage, &auction)?;
+    let projected_incentive = incentives
+        .allocations
+        .iter()
+        .find(|a| a.recipient == bidder)
+        .map(|a| a.amount)
+        .unwrap_or_default();
+
+    Ok(IncentiveStandingResponse {
+        is_early_bidder: incentives.early_bidder.as_ref() == Some(&bidder),
+        bidder,
+        bid_count,
+        frequency_rank,
+        projected_incentive,
+    })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+fn load_auction(storage: &dyn Storage, auction_id: u64) -> Result<Auction, ContractError> {
+    AUCTIONS
+        .may_load(storage, auction_id)?
+        .ok_or(ContractError::AuctionNotFound { auction_id })
+}
+
+/// Moves the bidder up the bounded most-frequent list after their count grew.
+///
+/// Counts only ever grow by one, so a bidder outside the list can enter only
+/// by strictly exceeding the last entry; everyone outside never has more bids
+/// than the last entry.
+fn update_top_bidders(
+    storage: &mut dyn Storage,
+    auction_id: u64,
+    bidder: &Addr,
+    bids: u32,
+) -> StdResult<()> {
+    let mut top = TOP_BIDDERS
+        .may_load(storage, auction_id)?
+        .unwrap_or_default();
+
+    let mut idx = match top.iter().position(|t| t.bidder == bidder) {
+        Some(i) => {
+            top[i].bids = bids;
+            i
+        }
+        None if top.len() < MAX_TOP_BIDDERS => {
+            top.push(TopBidder {
+                bidder: bidder.clone(),
+                bids,
+            });
+            top.len() - 1
+        }
+        None => {
+            let last = top.len() - 1;
+            if bids <= top[last].bids {
+                return Ok(());
+            }
+            top[last] = TopBidder {
+                bidder: bidder.clone(),
+                bids,
+            };
+            last
+        }
+    };
+
+    // Ties keep whoever reached the count first ahead
+    while idx > 0 && top[idx].bids > top[idx - 1].bids {
+        top.swap(idx, idx - 1);
+        idx -= 1;
+    }
+
+    TOP_BIDDERS.save(storage, auction_id, &top)
+}
+
+fn incentive_pool(auction: &Auction) -> Uint128 {
+    auction.ust1_amount.mul_floor(INCENTIVE_RATE)
+}
+
+/// First bid at or above 90% of the highest bid.
+///
+/// Bids are strictly increasing by sequence, so this is a binary search over
+/// the bid history.
+fn find_early_bidder(storage: &dyn Storage, auction: &Auction) -> StdResult<Option<Addr>> {
+    if auction.bid_count == 0 {
+        return Ok(None);
+    }
+    let threshold = auction.highest_bid.mul_ceil(EARLY_BIDDER_WINDOW);
+
+    let (mut lo, mut hi) = (0u32, auction.bid_count - 1);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if BID_HISTORY.load(storage, (auction.id, mid))?.amount >= threshold {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Ok(Some(BID_HISTORY.load(storage, (auction.id, lo))?.bidder))
+}
+
+/// Incentives if the auction settled at its current highest bid
+fn compute_incentives(storage: &dyn Storage, auction: &Auction) -> StdResult<IncentivesResponse> {
+    let incentive_pool = incentive_pool(auction);
+    let early_bidder = find_early_bidder(storage, auction)?;
+
+    let mut shares: Vec<(Addr, Uint128)> = vec![];
+    if let Some(early_bidder) = &early_bidder {
+        shares.push((
+            early_bidder.clone(),
+            incentive_pool.mul_floor(EARLY_BIDDER_SHARE),
+        ));
+    }
+    let top = TOP_BIDDERS
+        .may_load(storage, auction.id)?
+        .unwrap_or_default();
+    for (entry, share) in top.into_iter().zip(FREQUENCY_SHARES) {
+        shares.push((entry.bidder, incentive_pool.mul_floor(share)));
+    }
+
+    // One allocation per recipient, in first-award order
+    let mut allocations: Vec<IncentiveAllocation> = vec![];
+    for (recipient, amount) in shares {
+        if amount.is_zero() {
+            continue;
+        }
+        match allocations.iter_mut().find(|a| a.recipient == recipient) {
+            Some(existing) => existing.amount += amount,
+            None => allocations.push(IncentiveAllocation { recipient, amount }),
+        }
+    }
+
+    Ok(IncentivesResponse {
+        incentive_pool,
+        early_bidder,
+        allocations,
+    })
+}
+
+fn auction_response(auction: Auction) -> AuctionResponse {
+    let next_min_bid = auction.next_min_bid();
+    AuctionResponse {
+        id: auction.id,
+        collateral_token: auction.collateral_token,
+        ust1_amount: auction.ust1_amount,
+        min_bid: auction.min_bid,
+        start_time: auction.start_time,
+        end_time: auction.end_time,
+        highest_bidder: auction.highest_bidder,
+        highest_bid: auction.highest_bid,
+        bid_count: auction.bid_count,
+        status: auction.status,
+        next_min_bid,
+    }
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::OwnedDeps;
+
+    const GOVERNANCE: &str = "governance";
+    const TREASURY: &str = "treasury";
+    const CONTROLLER: &str = "ust1_controller";
+    const COLLATERAL: &str = "collateral_token";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn setup_contract(deps: &mut MockDeps) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            treasury: TREASURY.to_string(),
+            ust1_controller: CONTROLLER.to_string(),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    /// Creates an auction: 10,000 UST1 (500 UST1 incentives) for at least 1,000 collateral units
+    fn create_auction(deps: &mut MockDeps) {
+        let msg = ExecuteMsg::CreateAuction {
+            collateral_token: COLLATERAL.to_string(),
+            ust1_amount: Uint128::new(10_000),
+            min_bid: Uint128::new(1_000),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    fn receive(
+        deps: &mut MockDeps,
+        env: Env,
+        bidder: &str,
+        amount: u128,
+        msg: ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: bidder.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_json_binary(&msg).unwrap(),
+        });
+        execute(deps.as_mut(), env, mock_info(COLLATERAL, &[]), msg)
+    }
+
+    fn bid(
+        deps: &mut MockDeps,
+        env: Env,
+        bidder: &str,
+        amount: u128,
+    ) -> Result<Response, ContractError> {
+        receive(deps, env, bidder, amount, ReceiveMsg::Bid { auction_id: 1 })
+    }
+
+    fn auction(deps: &MockDeps) -> AuctionResponse {
+        query_auction(deps.as_ref(), 1).unwrap()
+    }
+
+    fn env_at(seconds_after_start: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds_after_start);
+        env
+    }
+
+    #[test]
+    fn test_create_auction() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let msg = ExecuteMsg::CreateAuction {
+            collateral_token: COLLATERAL.to_string(),
+            ust1_amount: Uint128::new(100),
+            min_bid: Uint128::new(1_000),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        create_auction(&mut deps);
+        let auction = auction(&deps);
+        assert_eq!(auction.id, 1);
+        assert_eq!(auction.status, AuctionStatus::Active);
+        assert_eq!(
+            auction.end_time,
+            mock_env().block.time.plus_seconds(AUCTION_DURATION)
+        );
+        assert_eq!(auction.next_min_bid, Uint128::new(1_000));
+    }
+
+    #[test]
+    fn test_bid_minimum_and_increment() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+
+        let err = bid(&mut deps, mock_env(), ALICE, 999).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::BidTooLow {
+                bid: Uint128::new(999),
+                min_bid: Uint128::new(1_000)
+            }
+        );
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+
+        // 1% increment: 1,010 required
+        assert_eq!(auction(&deps).next_min_bid, Uint128::new(1_010));
+        let err = bid(&mut deps, mock_env(), BOB, 1_009).unwrap_err();
+        assert!(matches!(err, ContractError::BidTooLow { .. }));
+        bid(&mut deps, mock_env(), BOB, 1_010).unwrap();
+
+        let auction = auction(&deps);
+        assert_eq!(auction.highest_bidder, Some(Addr::unchecked(BOB)));
+        assert_eq!(auction.highest_bid, Uint128::new(1_010));
+        assert_eq!(auction.bid_count, 2);
+    }
+
+    #[test]
+    fn test_bid_wrong_token_and_duplicate() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: ALICE.to_string(),
+            amount: Uint128::new(5_000),
+            msg: to_json_binary(&ReceiveMsg::Bid { auction_id: 1 }).unwrap(),
+        });
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_token", &[]),
+            msg,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidCollateral {
+                auction_id: 1,
+                expected: COLLATERAL.to_string()
+            }
+        );
+
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+        let err = bid(&mut deps, mock_env(), ALICE, 2_000).unwrap_err();
+        assert_eq!(err, ContractError::AlreadyBidding { auction_id: 1 });
+
+        let err = receive(
+            &mut deps,
+            mock_env(),
+            BOB,
+            2_000,
+            ReceiveMsg::TopUp { auction_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoExistingBid { auction_id: 1 });
+
+        let err = receive(
+            &mut deps,
+            mock_env(),
+            BOB,
+            2_000,
+            ReceiveMsg::Bid { auction_id: 9 },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::AuctionNotFound { auction_id: 9 });
+    }
+
+    #[test]
+    fn test_top_up_rebids_with_total_escrow() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+        bid(&mut deps, mock_env(), BOB, 2_000).unwrap();
+
+        // Alice's 1,000 + 1,000 does not beat 2,000 by 1%
+        let err = receive(
+            &mut deps,
+            mock_env(),
+            ALICE,
+            1_000,
+            ReceiveMsg::TopUp { auction_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::BidTooLow {
+                bid: Uint128::new(2_000),
+                min_bid: Uint128::new(2_020)
+            }
+        );
+        receive(
+            &mut deps,
+            mock_env(),
+            ALICE,
+            1_020,
+            ReceiveMsg::TopUp { auction_id: 1 },
+        )
+        .unwrap();
+
+        let auction = auction(&deps);
+        assert_eq!(auction.highest_bidder, Some(Addr::unchecked(ALICE)));
+        assert_eq!(auction.highest_bid, Uint128::new(2_020));
+        let escrow = query_escrow(deps.as_ref(), 1, ALICE.to_string()).unwrap();
+        assert_eq!(escrow.amount, Uint128::new(2_020));
+    }
+
+    #[test]
+    fn test_timer_extension_capped_at_48h() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+        let start = mock_env().block.time;
+
+        // Early bid: +8h would exceed 48h remaining, so capped at now + 48h
+        bid(&mut deps, env_at(3_600), ALICE, 1_000).unwrap();
+        assert_eq!(
+            auction(&deps).end_time,
+            start.plus_seconds(3_600 + AUCTION_DURATION)
+        );
+
+        // Late bid with 1h left: +8h
+        let end = auction(&deps).end_time;
+        let late = end.seconds() - start.seconds() - 3_600;
+        bid(&mut deps, env_at(late), BOB, 2_000).unwrap();
+        assert_eq!(auction(&deps).end_time, end.plus_seconds(BID_EXTENSION));
+
+        // No bids after expiry
+        let end = auction(&deps).end_time;
+        let err = bid(
+            &mut deps,
+            env_at(end.seconds() - start.seconds()),
+            "carol",
+            5_000,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::AuctionEnded { auction_id: 1 });
+    }
+
+    #[test]
+    fn test_withdraw_outbid() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+        let msg = ExecuteMsg::WithdrawOutbid { auction_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::HighestBidderCannotWithdraw);
+
+        bid(&mut deps, mock_env(), BOB, 1_500).unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, COLLATERAL);
+                let parsed: Cw20ExecuteMsg = from_json(msg).unwrap();
+                assert_eq!(
+                    parsed,
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: ALICE.to_string(),
+                        amount: Uint128::new(1_000)
+                    }
+                );
+            }
+            _ => panic!("Expected WasmMsg::Execute"),
+        }
+
+        let err = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::NothingToWithdraw);
+
+        // Withdrawn bidders re-enter with Bid
+        bid(&mut deps, mock_env(), ALICE, 2_000).unwrap();
+    }
+
+    #[test]
+    fn test_settle() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+        bid(&mut deps, mock_env(), BOB, 1_500).unwrap();
+
+        let end = auction(&deps).end_time;
+        let mut env = mock_env();
+        env.block.time = end.minus_seconds(1);
+        let err = execute_settle(deps.as_mut(), env.clone(), 1).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AuctionNotEnded {
+                auction_id: 1,
+                remaining_seconds: 1
+            }
+        );
+
+        env.block.time = end;
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::Settle { auction_id: 1 },
+        )
+        .unwrap();
+        // Collateral transfer, winner mint, two incentive mints
+        assert_eq!(res.messages.len(), 4);
+
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, COLLATERAL);
+                let parsed: Cw20ExecuteMsg = from_json(msg).unwrap();
+                assert_eq!(
+                    parsed,
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: TREASURY.to_string(),
+                        amount: Uint128::new(1_500)
+                    }
+                );
+            }
+            _ => panic!("Expected WasmMsg::Execute"),
+        }
+        match &res.messages[1].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, CONTROLLER);
+                let parsed: Ust1ControllerExecuteMsg = from_json(msg).unwrap();
+                assert_eq!(
+                    parsed,
+                    Ust1ControllerExecuteMsg::Mint {
+                        recipient: BOB.to_string(),
+                        amount: Uint128::new(10_000),
+                        collateral: Some(Asset {
+                            info: AssetInfo::Cw20 {
+                                contract_addr: Addr::unchecked(COLLATERAL)
+                            },
+                            amount: Uint128::new(1_500),
+                        }),
+                    }
+                );
+            }
+            _ => panic!("Expected WasmMsg::Execute"),
+        }
+
+        // Bob: early bidder (250) + 2nd most frequent (50); Alice: 1st most frequent (125)
+        let incentive_mints: Vec<Ust1ControllerExecuteMsg> = res.messages[2..]
+            .iter()
+            .map(|m| match &m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => from_json(msg).unwrap(),
+                _ => panic!("Expected WasmMsg::Execute"),
+            })
+            .collect();
+        assert_eq!(
+            incentive_mints,
+            vec![
+                Ust1ControllerExecuteMsg::Mint {
+                    recipient: BOB.to_string(),
+                    amount: Uint128::new(300),
+                    collateral: None,
+                },
+                Ust1ControllerExecuteMsg::Mint {
+                    recipient: ALICE.to_string(),
+                    amount: Uint128::new(125),
+                    collateral: None,
+                },
+            ]
+        );
+
+        assert_eq!(auction(&deps).status, AuctionStatus::Settled);
+        let err = execute_settle(deps.as_mut(), env.clone(), 1).unwrap_err();
+        assert_eq!(err, ContractError::AuctionNotActive { auction_id: 1 });
+
+        // Losing escrow stays withdrawable after settlement; the winner's is gone
+        let msg = ExecuteMsg::WithdrawOutbid { auction_id: 1 };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ALICE, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        let err = execute(deps.as_mut(), env, mock_info(BOB, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::NothingToWithdraw);
+    }
+
+    #[test]
+    fn test_cancel_if_no_bids() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+        create_auction(&mut deps);
+
+        let msg = ExecuteMsg::CancelIfNoBids { auction_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AuctionNotEnded {
+                auction_id: 1,
+                remaining_seconds: AUCTION_DURATION
+            }
+        );
+
+        // Anyone after expiry
+        let expired = env_at(AUCTION_DURATION);
+        execute(
+            deps.as_mut(),
+            expired.clone(),
+            mock_info(ALICE, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(auction(&deps).status, AuctionStatus::Cancelled);
+        let err = execute_settle(deps.as_mut(), expired, 1).unwrap_err();
+        assert_eq!(err, ContractError::AuctionNotActive { auction_id: 1 });
+
+        // Auctions with bids cannot be cancelled, even by governance
+        receive(
+            &mut deps,
+            mock_env(),
+            ALICE,
+            1_000,
+            ReceiveMsg::Bid { auction_id: 2 },
+        )
+        .unwrap();
+        let msg = ExecuteMsg::CancelIfNoBids { auction_id: 2 };
+        let err = execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::AuctionHasBids { auction_id: 2 });
+    }
+
+    #[test]
+    fn test_expire_unsettled_refunds_winner() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+        create_auction(&mut deps);
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+        bid(&mut deps, mock_env(), BOB, 1_500).unwrap();
+
+        let msg = ExecuteMsg::ExpireUnsettled { auction_id: 1 };
+        let end = auction(&deps).end_time;
+        let mut env = mock_env();
+        env.block.time = end.minus_seconds(1);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(GOVERNANCE, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AuctionNotEnded {
+                auction_id: 1,
+                remaining_seconds: 1
+            }
+        );
+
+        // Only Settle (or governance) during the grace period
+        env.block.time = end;
+        let err =
+            execute(deps.as_mut(), env.clone(), mock_info(BOB, &[]), msg.clone()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::SettlementGracePeriod {
+                auction_id: 1,
+                remaining_seconds: SETTLEMENT_GRACE_PERIOD
+            }
+        );
+
+        // Afterwards the winner can expire it and take their escrow back
+        env.block.time = end.plus_seconds(SETTLEMENT_GRACE_PERIOD);
+        execute(deps.as_mut(), env.clone(), mock_info(BOB, &[]), msg.clone()).unwrap();
+        assert_eq!(auction(&deps).status, AuctionStatus::Expired);
+        let err = execute_settle(deps.as_mut(), env.clone(), 1).unwrap_err();
+        assert_eq!(err, ContractError::AuctionNotActive { auction_id: 1 });
+        let err = execute(deps.as_mut(), env.clone(), mock_info(BOB, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::AuctionNotActive { auction_id: 1 });
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(BOB, &[]),
+            ExecuteMsg::WithdrawOutbid { auction_id: 1 },
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                let parsed: Cw20ExecuteMsg = from_json(msg).unwrap();
+                assert_eq!(
+                    parsed,
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: BOB.to_string(),
+                        amount: Uint128::new(1_500)
+                    }
+                );
+            }
+            _ => panic!("Expected WasmMsg::Execute"),
+        }
+
+        // Governance does not have to wait out the grace period; auctions
+        // without bids go through CancelIfNoBids instead
+        let msg = ExecuteMsg::ExpireUnsettled { auction_id: 2 };
+        env.block.time = end;
+        let err = execute(deps.as_mut(), env, mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::NoBids { auction_id: 2 });
+    }
+
+    #[test]
+    fn test_list_auctions_by_status() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        for _ in 0..3 {
+            create_auction(&mut deps);
+        }
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::CancelIfNoBids { auction_id: 2 },
+        )
+        .unwrap();
+
+        let active =
+            query_auctions(deps.as_ref(), None, None, Some(AuctionStatus::Active)).unwrap();
+        let ids: Vec<u64> = active.auctions.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+
+        let page = query_auctions(deps.as_ref(), Some(1), Some(1), None).unwrap();
+        assert_eq!(page.auctions.len(), 1);
+        assert_eq!(page.auctions[0].id, 2);
+        assert_eq!(page.auctions[0].status, AuctionStatus::Cancelled);
+    }
+
+    /// Places the smallest accepted bid for `bidder`, topping up if already bidding
+    fn place_min_bid(deps: &mut MockDeps, bidder: &str) {
+        let next = auction(deps).next_min_bid;
+        let escrow = query_escrow(deps.as_ref(), 1, bidder.to_string())
+            .unwrap()
+            .amount;
+        let msg = if escrow.is_zero() {
+            ReceiveMsg::Bid { auction_id: 1 }
+        } else {
+            ReceiveMsg::TopUp { auction_id: 1 }
+        };
+        receive(deps, mock_env(), bidder, (next - escrow).u128(), msg).unwrap();
+    }
+
+    fn top_bidders(deps: &MockDeps) -> Vec<(String, u32)> {
+        query_top_bidders(deps.as_ref(), 1)
+            .unwrap()
+            .top_bidders
+            .into_iter()
+            .map(|t| (t.bidder.to_string(), t.bids))
+            .collect()
+    }
+
+    #[test]
+    fn test_top_bidders_bounded_and_ordered() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+
+        // bidder1 x3, bidder2 x2, bidder3..bidder6 x1
+        for bidder in [
+            "bidder1", "bidder2", "bidder1", "bidder3", "bidder2", "bidder4", "bidder5", "bidder1",
+            "bidder6",
+        ] {
+            place_min_bid(&mut deps, bidder);
+        }
+        assert_eq!(
+            top_bidders(&deps),
+            vec![
+                ("bidder1".to_string(), 3),
+                ("bidder2".to_string(), 2),
+                ("bidder3".to_string(), 1),
+                ("bidder4".to_string(), 1),
+                ("bidder5".to_string(), 1),
+            ]
+        );
+
+        // bidder6 was tied with the last entry; a second bid moves it past bidder3..bidder5
+        place_min_bid(&mut deps, "bidder6");
+        assert_eq!(
+            top_bidders(&deps),
+            vec![
+                ("bidder1".to_string(), 3),
+                ("bidder2".to_string(), 2),
+                ("bidder6".to_string(), 2),
+                ("bidder3".to_string(), 1),
+                ("bidder4".to_string(), 1),
+            ]
+        );
+
+        let res = query_top_bidders(deps.as_ref(), 1).unwrap();
+        let incentives: Vec<u128> = res.top_bidders.iter().map(|t| t.incentive.u128()).collect();
+        assert_eq!(incentives, vec![125, 50, 25, 25, 25]);
+        assert_eq!(res.top_bidders[0].rank, 1);
+
+        // Counts are kept for bidders outside the list
+        let standing = query_incentive_standing(deps.as_ref(), 1, "bidder5".to_string()).unwrap();
+        assert_eq!(standing.bid_count, 1);
+        assert_eq!(standing.frequency_rank, None);
+    }
+
+    #[test]
+    fn test_early_bidder_is_first_within_ten_percent() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+
+        bid(&mut deps, mock_env(), "bidder_a", 1_000).unwrap();
+        bid(&mut deps, mock_env(), "bidder_b", 4_000).unwrap();
+        bid(&mut deps, mock_env(), "bidder_c", 4_500).unwrap();
+        bid(&mut deps, mock_env(), "bidder_d", 4_600).unwrap();
+        bid(&mut deps, mock_env(), "bidder_e", 5_000).unwrap();
+
+        // Threshold 4,500: bidder_c's bid is the first to reach it
+        let incentives =
+            compute_incentives(&deps.storage, &load_auction(&deps.storage, 1).unwrap()).unwrap();
+        assert_eq!(incentives.incentive_pool, Uint128::new(500));
+        assert_eq!(incentives.early_bidder, Some(Addr::unchecked("bidder_c")));
+
+        // A higher winning bid moves the window
+        bid(&mut deps, mock_env(), "bidder_f", 5_100).unwrap();
+        let standing = query_incentive_standing(deps.as_ref(), 1, "bidder_d".to_string()).unwrap();
+        assert!(standing.is_early_bidder);
+        // Early share plus 4th most frequent
+        assert_eq!(standing.projected_incentive, Uint128::new(275));
+        assert_eq!(standing.frequency_rank, Some(4));
+
+        let history = query_bid_history(deps.as_ref(), 1, Some(3), Some(2)).unwrap();
+        let seq: Vec<u32> = history.bids.iter().map(|b| b.sequence).collect();
+        assert_eq!(seq, vec![4, 5]);
+        assert_eq!(history.bids[1].bidder, Addr::unchecked("bidder_f"));
+    }
+
+    #[test]
+    fn test_single_bidder_takes_early_and_top_share() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        create_auction(&mut deps);
+        bid(&mut deps, mock_env(), ALICE, 1_000).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Incentives { auction_id: 1 },
+        )
+        .unwrap();
+        let incentives: IncentivesResponse = from_json(res).unwrap();
+        // 50% + 25% of 500; ranks 2-5 are unfilled and not minted
+        assert_eq!(
+            incentives.allocations,
+            vec![IncentiveAllocation {
+                recipient: Addr::unchecked(ALICE),
+                amount: Uint128::new(375),
+            }]
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use common::AssetInfo;
+    use cosmwasm_std::{to_json_binary, Addr, Decimal, Uint128};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
+    use cw_multi_test::error::AnyResult;
+    use cw_multi_test::{App, AppResponse, Executor};
+
+    use crate::msg::{ExecuteMsg, InstantiateMsg, ReceiveMsg};
+    use crate::state::{AUCTION_DURATION, SETTLEMENT_GRACE_PERIOD};
+    use crate::ContractError;
+
+    const GOVERNANCE: &str = "governance";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+
+    struct Stack {
+        collateral: Addr,
+        ust1: Addr,
+        treasury: Addr,
+        auction: Addr,
+    }
+
+    /// Treasury, cr-engine (collateral priced at 1), UST1 controller and an
+    /// auction for 1,000 UST1 with a 1,000 opening bid. `treasury_collateral`
+    /// is minted to the treasury and `ust1_supply` to a holder before the
+    /// controller takes over, which sets the starting tier.
+    fn setup_stack(app: &mut App, treasury_collateral: u128, ust1_supply: u128) -> Stack {
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let cw20_code = app.store_code(cw20_contract());
+        let treasury_code = app.store_code(contract_wrapper!(treasury));
+        let engine_code = app.store_code(contract_wrapper!(cr_engine));
+        let controller_code = app.store_code(contract_wrapper!(ust1_controller));
+        let auction_code = app.store_code(contract_wrapper!(crate));
+
+        let collateral = instantiate_cw20(
+            app,
+            cw20_code,
+            GOVERNANCE,
+            "WBTC",
+            vec![
+                Cw20Coin {
+                    address: ALICE.to_string(),
+                    amount: Uint128::new(10_000),
+                },
+                Cw20Coin {
+                    address: BOB.to_string(),
+                    amount: Uint128::new(10_000),
+                },
+            ],
+        );
+        let ust1_holders = if ust1_supply > 0 {
+            vec![Cw20Coin {
+                address: "holder".to_string(),
+                amount: Uint128::new(ust1_supply),
+            }]
+        } else {
+            vec![]
+        };
+        let ust1 = instantiate_cw20(app, cw20_code, GOVERNANCE, "USTONE", ust1_holders);
+
+        let treasury = app
+            .instantiate_contract(
+                treasury_code,
+                gov.clone(),
+                &treasury::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+        if treasury_collateral > 0 {
+            app.execute_contract(
+                gov.clone(),
+                collateral.clone(),
+                &Cw20ExecuteMsg::Mint {
+                    recipient: treasury.to_string(),
+                    amount: Uint128::new(treasury_collateral),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        // Whitelisted collateral counts toward the CR
+        app.execute_contract(
+            gov.clone(),
+            treasury.clone(),
+            &treasury::msg::ExecuteMsg::AddCw20 {
+                contract_addr: collateral.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let engine = app
+            .instantiate_contract(
+                engine_code,
+                gov.clone(),
+                &cr_engine::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    treasury: treasury.to_string(),
+                    ust1_token: ust1.to_string(),
+                    price_source: cr_engine::state::PriceSource::Fixed {},
+                    thresholds: None,
+                    prices: vec![cr_engine::msg::PriceEntry {
+                        asset: AssetInfo::cw20(collateral.clone()),
+                        price: Decimal::one(),
+                    }],
+                },
+                &[],
+                "cr-engine",
+                None,
+            )
+            .unwrap();
+
+        let controller = app
+            .instantiate_contract(
+                controller_code,
+                gov.clone(),
+                &ust1_controller::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    cr_engine: engine.to_string(),
+                },
+                &[],
+                "ust1-controller",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            ust1.clone(),
+            &Cw20ExecuteMsg::UpdateMinter {
+                new_minter: Some(controller.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let auction = app
+            .instantiate_contract(
+                auction_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    treasury: treasury.to_string(),
+                    ust1_controller: controller.to_string(),
+                },
+                &[],
+                "auction",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            controller,
+            &ust1_controller::msg::ExecuteMsg::AddMinter {
+                address: auction.to_string(),
+                role: ust1_controller::state::MinterRole::Auction,
+                cap: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            gov,
+            auction.clone(),
+            &ExecuteMsg::CreateAuction {
+                collateral_token: collateral.to_string(),
+                ust1_amount: Uint128::new(1_000),
+                min_bid: Uint128::new(1_000),
+            },
+            &[],
+        )
+        .unwrap();
+
+        Stack {
+            collateral,
+            ust1,
+            treasury,
+            auction,
+        }
+    }
+
+    fn send_bid(app: &mut App, stack: &Stack, bidder: &str, amount: u128, msg: ReceiveMsg) {
+        app.execute_contract(
+            Addr::unchecked(bidder),
+            stack.collateral.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: stack.auction.to_string(),
+                amount: Uint128::new(amount),
+                msg: to_json_binary(&msg).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn settle(app: &mut App, stack: &Stack) -> AnyResult<AppResponse> {
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            stack.auction.clone(),
+            &ExecuteMsg::Settle { auction_id: 1 },
+            &[],
+        )
+    }
+
+    #[test]
+    fn test_auction_settles_into_treasury_and_mints_ust1() {
+        let mut app = App::default();
+        // Zero UST1 supply: BLUE through the winner and incentive mints
+        let stack = setup_stack(&mut app, 0, 0);
+
+        send_bid(
+            &mut app,
+            &stack,
+            ALICE,
+            1_000,
+            ReceiveMsg::Bid { auction_id: 1 },
+        );
+        send_bid(
+            &mut app,
+            &stack,
+            BOB,
+            1_500,
+            ReceiveMsg::Bid { auction_id: 1 },
+        );
+        send_bid(
+            &mut app,
+            &stack,
+            ALICE,
+            1_000,
+            ReceiveMsg::TopUp { auction_id: 1 },
+        );
+        send_bid(
+            &mut app,
+            &stack,
+            BOB,
+            600,
+            ReceiveMsg::TopUp { auction_id: 1 },
+        );
+
+        // Alice (2,000) was outbid by Bob (2,100)
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            stack.auction.clone(),
+            &ExecuteMsg::WithdrawOutbid { auction_id: 1 },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            balance(&app, &stack.collateral, ALICE),
+            Uint128::new(10_000)
+        );
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(AUCTION_DURATION);
+        });
+        settle(&mut app, &stack).unwrap();
+
+        assert_eq!(
+            balance(&app, &stack.collateral, stack.treasury.as_str()),
+            Uint128::new(2_100)
+        );
+        assert_eq!(
+            balance(&app, &stack.collateral, stack.auction.as_str()),
+            Uint128::zero()
+        );
+        // 50 UST1 incentives: Alice is the early bidder (2,000 >= 90% of 2,100) and
+        // the most frequent bidder by tie-break (25 + 12); Bob is second (5)
+        assert_eq!(balance(&app, &stack.ust1, BOB), Uint128::new(1_005));
+        assert_eq!(balance(&app, &stack.ust1, ALICE), Uint128::new(37));
+    }
+
+    #[test]
+    fn test_auction_settles_in_green_tier() {
+        let mut app = App::default();
+        // 12,000 collateral against 10,000 UST1: 120%, GREEN before and after
+        // settlement (14,100 / 11,050)
+        let stack = setup_stack(&mut app, 12_000, 10_000);
+
+        send_bid(
+            &mut app,
+            &stack,
+            ALICE,
+            1_000,
+            ReceiveMsg::Bid { auction_id: 1 },
+        );
+        send_bid(
+            &mut app,
+            &stack,
+            BOB,
+            2_100,
+            ReceiveMsg::Bid { auction_id: 1 },
+        );
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(AUCTION_DURATION);
+        });
+        settle(&mut app, &stack).unwrap();
+
+        assert_eq!(
+            balance(&app, &stack.collateral, stack.treasury.as_str()),
+            Uint128::new(14_100)
+        );
+        // Bob: winner (1,000) + early bidder (25) + second most frequent (5);
+        // Alice: most frequent by tie-break (12)
+        assert_eq!(balance(&app, &stack.ust1, BOB), Uint128::new(1_030));
+        assert_eq!(balance(&app, &stack.ust1, ALICE), Uint128::new(12));
+    }
+
+    #[test]
+    fn test_unsettleable_auction_expires_and_refunds_winner() {
+        let mut app = App::default();
+        // No treasury collateral against 10,000 UST1: RED, so Settle cannot mint
+        let stack = setup_stack(&mut app, 0, 10_000);
+
+        send_bid(
+            &mut app,
+            &stack,
+            ALICE,
+            1_000,
+            ReceiveMsg::Bid { auction_id: 1 },
+        );
+        send_bid(
+            &mut app,
+            &stack,
+            BOB,
+            2_100,
+            ReceiveMsg::Bid { auction_id: 1 },
+        );
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(AUCTION_DURATION);
+        });
+        settle(&mut app, &stack).unwrap_err();
+
+        let expire = ExecuteMsg::ExpireUnsettled { auction_id: 1 };
+        let err = app
+            .execute_contract(Addr::unchecked(BOB), stack.auction.clone(), &expire, &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::SettlementGracePeriod { .. }
+        ));
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(SETTLEMENT_GRACE_PERIOD);
+        });
+        app.execute_contract(Addr::unchecked(BOB), stack.auction.clone(), &expire, &[])
+            .unwrap();
+        for bidder in [ALICE, BOB] {
+            app.execute_contract(
+                Addr::unchecked(bidder),
+                stack.auction.clone(),
+                &ExecuteMsg::WithdrawOutbid { auction_id: 1 },
+                &[],
+            )
+            .unwrap();
+            assert_eq!(
+                balance(&app, &stack.collateral, bidder),
+                Uint128::new(10_000)
+            );
+        }
+        assert_eq!(balance(&app, &stack.ust1, BOB), Uint128::zero());
+    }
+}
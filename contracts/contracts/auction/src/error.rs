@@ -0,0 +1,65 @@
+use cosmwasm_std::{StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Invalid collateral token: auction {auction_id} accepts {expected}")]
+    InvalidCollateral { auction_id: u64, expected: String },
+
+    #[error("Zero amount not allowed")]
+    ZeroAmount,
+
+    #[error("Auction not found: {auction_id}")]
+    AuctionNotFound { auction_id: u64 },
+
+    #[error("Auction {auction_id} is not active")]
+    AuctionNotActive { auction_id: u64 },
+
+    #[error("Auction {auction_id} has ended")]
+    AuctionEnded { auction_id: u64 },
+
+    #[error("Auction {auction_id} has not ended: {remaining_seconds} seconds remaining")]
+    AuctionNotEnded {
+        auction_id: u64,
+        remaining_seconds: u64,
+    },
+
+    #[error("Bid too low: {bid} is below the minimum of {min_bid}")]
+    BidTooLow { bid: Uint128, min_bid: Uint128 },
+
+    #[error("Already bidding in auction {auction_id}; use TopUp to raise your bid")]
+    AlreadyBidding { auction_id: u64 },
+
+    #[error("No existing bid in auction {auction_id}; use Bid to place one")]
+    NoExistingBid { auction_id: u64 },
+
+    #[error("Highest bidder cannot withdraw")]
+    HighestBidderCannotWithdraw,
+
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw,
+
+    #[error("Auction {auction_id} has bids and cannot be cancelled")]
+    AuctionHasBids { auction_id: u64 },
+
+    #[error("Auction {auction_id} has no bids")]
+    NoBids { auction_id: u64 },
+
+    #[error("Auction {auction_id} is still in its settlement grace period: {remaining_seconds} seconds remaining")]
+    SettlementGracePeriod {
+        auction_id: u64,
+        remaining_seconds: u64,
+    },
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
@@ -0,0 +1,38 @@
+//! # Auction Contract
+//!
+//! Governance-created English auctions through which the CMM acquires CW20
+//! collateral in exchange for newly minted UST1.
+//!
+//! ## Mechanics
+//!
+//! - Governance creates an auction for a fixed UST1 amount, naming the
+//!   accepted CW20 collateral and the minimum opening bid
+//! - Bidders escrow collateral via CW20 `Send`; a bidder's bid is their total
+//!   escrowed collateral in that auction
+//! - Each bid must be at least 1% above the current highest bid
+//! - The timer starts at 48 hours; each bid adds 8 hours, capped at 48 hours
+//!   remaining
+//! - Outbid bidders can withdraw their escrow at any time
+//! - Settlement sends the winner's collateral to the treasury and mints UST1
+//!   to the winner (via the UST1 controller) in the same transaction
+//! - Auctions that receive no bids can be cancelled once the timer expires
+//! - Auctions with bids that cannot be settled (the controller refuses UST1
+//!   mints in RED) can be expired 7 days after the timer ends, returning every
+//!   escrow, the winner's included
+//!
+//! ## Bidding Incentives
+//!
+//! An extra 5% of the auction's UST1 is minted at settlement:
+//!
+//! - 50% to the first bidder whose bid is within 10% of the winning bid
+//! - 25% / 10% / 5% / 5% / 5% to the five most frequent bidders
+//!
+//! Bid counts are kept per bidder, with a bounded top-5 list updated on each
+//! bid. A bidder earning several shares receives a single combined mint.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,237 @@
+use common::Asset;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::state::AuctionStatus;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub treasury: String,
+    pub ust1_controller: String,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates an auction running for 48 hours (governance-only)
+    CreateAuction {
+        collateral_token: String,
+        ust1_amount: Uint128,
+        min_bid: Uint128,
+    },
+
+    /// CW20 receive hook for collateral; the embedded message is `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
+    /// Returns the sender's escrow once they are no longer the highest bidder,
+    /// or to any bidder once the auction is no longer active
+    WithdrawOutbid { auction_id: u64 },
+
+    /// Sends the winning collateral to the treasury and mints UST1 to the
+    /// winner (permissionless, after the timer expires)
+    Settle { auction_id: u64 },
+
+    /// Cancels an auction without bids: anyone after expiry, governance at any time
+    CancelIfNoBids { auction_id: u64 },
+
+    /// Closes an ended auction with bids that was never settled, e.g. because
+    /// the tier does not allow the UST1 mint, so the winner can withdraw their
+    /// escrow: anyone once the settlement grace period has passed, governance
+    /// any time after expiry
+    ExpireUnsettled { auction_id: u64 },
+
+    /// Updates treasury and/or UST1 controller addresses (governance-only)
+    UpdateConfig {
+        treasury: Option<String>,
+        ust1_controller: Option<String>,
+    },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+/// Messages embedded in a collateral CW20 `Send` to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Places a first bid with the sent collateral
+    Bid { auction_id: u64 },
+    /// Adds the sent collateral to an existing escrow and bids the new total
+    TopUp { auction_id: u64 },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    #[returns(AuctionResponse)]
+    Auction { auction_id: u64 },
+
+    /// Auctions ordered by ID, optionally filtered by status
+    #[returns(AuctionsResponse)]
+    Auctions {
+        start_after: Option<u64>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+        status: Option<AuctionStatus>,
+    },
+
+    /// A bidder's escrowed collateral in an auction
+    #[returns(EscrowResponse)]
+    Escrow { auction_id: u64, bidder: String },
+
+    /// All escrows in an auction, ordered by bidder address
+    #[returns(EscrowsResponse)]
+    Escrows {
+        auction_id: u64,
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// Accepted bids in order
+    #[returns(BidHistoryResponse)]
+    BidHistory {
+        auction_id: u64,
+        start_after: Option<u32>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// The five most frequent bidders with their incentive shares
+    #[returns(TopBiddersResponse)]
+    TopBidders { auction_id: u64 },
+
+    /// Incentive allocation if the auction settled at the current highest bid
+    /// (final once settled)
+    #[returns(IncentivesResponse)]
+    Incentives { auction_id: u64 },
+
+    /// A bidder's current incentive standing
+    #[returns(IncentiveStandingResponse)]
+    IncentiveStanding { auction_id: u64, bidder: String },
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub treasury: Addr,
+    pub ust1_controller: Addr,
+}
+
+#[cw_serde]
+pub struct AuctionResponse {
+    pub id: u64,
+    pub collateral_token: Addr,
+    pub ust1_amount: Uint128,
+    pub min_bid: Uint128,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub highest_bidder: Option<Addr>,
+    pub highest_bid: Uint128,
+    pub bid_count: u32,
+    pub status: AuctionStatus,
+    /// Smallest bid currently accepted
+    pub next_min_bid: Uint128,
+}
+
+#[cw_serde]
+pub struct AuctionsResponse {
+    pub auctions: Vec<AuctionResponse>,
+}
+
+#[cw_serde]
+pub struct EscrowResponse {
+    pub bidder: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct EscrowsResponse {
+    pub escrows: Vec<EscrowResponse>,
+}
+
+#[cw_serde]
+pub struct BidHistoryEntry {
+    pub sequence: u32,
+    pub bidder: Addr,
+    pub amount: Uint128,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub struct BidHistoryResponse {
+    pub bids: Vec<BidHistoryEntry>,
+}
+
+#[cw_serde]
+pub struct TopBidderEntry {
+    /// 1-indexed frequency rank
+    pub rank: u32,
+    pub bidder: Addr,
+    pub bids: u32,
+    /// UST1 earned by this rank
+    pub incentive: Uint128,
+}
+
+#[cw_serde]
+pub struct TopBiddersResponse {
+    pub top_bidders: Vec<TopBidderEntry>,
+}
+
+#[cw_serde]
+pub struct IncentiveAllocation {
+    pub recipient: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct IncentivesResponse {
+    /// 5% of the auction's UST1 amount
+    pub incentive_pool: Uint128,
+    /// First bidder whose bid is within 10% of the (current) winning bid
+    pub early_bidder: Option<Addr>,
+    /// Per-recipient totals; unfilled frequency ranks are not minted
+    pub allocations: Vec<IncentiveAllocation>,
+}
+
+#[cw_serde]
+pub struct IncentiveStandingResponse {
+    pub bidder: Addr,
+    pub bid_count: u32,
+    /// 1-indexed frequency rank if in the top five
+    pub frequency_rank: Option<u32>,
+    pub is_early_bidder: bool,
+    /// Incentive if the auction settled at the current highest bid
+    pub projected_incentive: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+/// Mint message sent to the UST1 controller
+#[cw_serde]
+pub enum Ust1ControllerExecuteMsg {
+    Mint {
+        recipient: String,
+        amount: Uint128,
+        collateral: Option<Asset>,
+    },
+}
@@ -0,0 +1,141 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:auction";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// Initial auction duration and cap on the remaining time (48 hours)
+pub const AUCTION_DURATION: u64 = 172_800;
+
+/// Time added to the timer by each bid (8 hours)
+pub const BID_EXTENSION: u64 = 28_800;
+
+/// Time after `end_time` during which only `Settle` (or governance) can close
+/// an auction with bids; afterwards anyone can expire it (7 days)
+pub const SETTLEMENT_GRACE_PERIOD: u64 = 604_800;
+
+/// Each bid must be at least 101% of the current highest bid
+pub const MIN_BID_INCREMENT: Decimal = Decimal::percent(101);
+
+/// Extra UST1 minted as bidding incentives, as a share of the auction amount
+pub const INCENTIVE_RATE: Decimal = Decimal::percent(5);
+
+/// Share of the incentive pool for the early bidder
+pub const EARLY_BIDDER_SHARE: Decimal = Decimal::percent(50);
+
+/// A bid qualifies as early if it is at least 90% of the winning bid
+pub const EARLY_BIDDER_WINDOW: Decimal = Decimal::percent(90);
+
+/// Incentive pool shares for the five most frequent bidders, by rank
+pub const FREQUENCY_SHARES: [Decimal; MAX_TOP_BIDDERS] = [
+    Decimal::percent(25),
+    Decimal::percent(10),
+    Decimal::percent(5),
+    Decimal::percent(5),
+    Decimal::percent(5),
+];
+
+/// Number of most-frequent bidders tracked per auction
+pub const MAX_TOP_BIDDERS: usize = 5;
+
+/// Default page size for list queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for list queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    /// Receives the winning collateral
+    pub treasury: Addr,
+    /// UST1 controller; this contract must be registered there as an auction minter
+    pub ust1_controller: Addr,
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum AuctionStatus {
+    Active,
+    Settled,
+    Cancelled,
+    /// Ended with bids but never settled; every escrow, the winner's
+    /// included, is withdrawable
+    Expired,
+}
+
+#[cw_serde]
+pub struct Auction {
+    pub id: u64,
+    /// Accepted CW20 collateral
+    pub collateral_token: Addr,
+    /// UST1 minted to the winner
+    pub ust1_amount: Uint128,
+    /// Minimum opening bid in collateral units
+    pub min_bid: Uint128,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub highest_bidder: Option<Addr>,
+    pub highest_bid: Uint128,
+    pub bid_count: u32,
+    pub status: AuctionStatus,
+}
+
+impl Auction {
+    /// Smallest bid that would currently be accepted
+    pub fn next_min_bid(&self) -> Uint128 {
+        if self.highest_bidder.is_none() {
+            self.min_bid
+        } else {
+            self.highest_bid.mul_ceil(MIN_BID_INCREMENT)
+        }
+    }
+}
+
+/// A single accepted bid; bids in an auction are strictly increasing by sequence
+#[cw_serde]
+pub struct BidRecord {
+    pub bidder: Addr,
+    pub amount: Uint128,
+    pub time: Timestamp,
+}
+
+/// Entry in an auction's most-frequent-bidders list
+#[cw_serde]
+pub struct TopBidder {
+    pub bidder: Addr,
+    pub bids: u32,
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+
+/// ID assigned to the next auction
+pub const NEXT_AUCTION_ID: Item<u64> = Item::new("next_auction_id");
+
+pub const AUCTIONS: Map<u64, Auction> = Map::new("auctions");
+
+/// Escrowed collateral per bidder
+/// Key: (auction_id, bidder)
+pub const ESCROWS: Map<(u64, &Addr), Uint128> = Map::new("escrows");
+
+/// Bid history
+/// Key: (auction_id, sequence starting at 0)
+pub const BID_HISTORY: Map<(u64, u32), BidRecord> = Map::new("bid_history");
+
+/// Number of bids placed per bidder
+/// Key: (auction_id, bidder)
+pub const BID_COUNTS: Map<(u64, &Addr), u32> = Map::new("bid_counts");
+
+/// Most frequent bidders per auction, sorted by bid count descending (at most
+/// MAX_TOP_BIDDERS). Ties rank whoever reached the count first higher.
+pub const TOP_BIDDERS: Map<u64, Vec<TopBidder>> = Map::new("top_bidders");
@@ -0,0 +1,1047 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+
+use common::CrTier;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ConfigResponse, CrEngineCrResponse, CrEngineExecuteMsg, CrEngineQueryMsg,
+    CrEngineSnapshotResponse, CrEngineThresholds, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    MintStateResponse, PendingGovernanceResponse, PendingMintResponse, PoolAllocation,
+    PoolShareMsg, QueryMsg, Ust1ControllerExecuteMsg,
+};
+use crate::state::{
+    Config, MintState, PendingGovernance, PoolShare, CONFIG, CONTRACT_NAME, CONTRACT_VERSION,
+    GOVERNANCE_TIMELOCK, MAX_POOLS, MINT_PERIOD, MINT_STATE, PENDING_GOVERNANCE,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        cr_engine: deps.api.addr_validate(&msg.cr_engine)?,
+        ust1_controller: deps.api.addr_validate(&msg.ust1_controller)?,
+        pools: validate_pools(deps.as_ref(), msg.pools)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    MINT_STATE.save(
+        deps.storage,
+        &MintState {
+            last_mint: env.block.time,
+            total_minted: Uint128::zero(),
+            cancelled_seconds: 0,
+            blue_at_last_mint: false,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("cr_engine", config.cr_engine)
+        .add_attribute("ust1_controller", config.ust1_controller))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Mint {} => execute_mint(deps, env),
+        ExecuteMsg::MintCallback {} => execute_mint_callback(deps, env, info),
+        ExecuteMsg::SetPools { pools } => execute_set_pools(deps, info, pools),
+        ExecuteMsg::UpdateConfig {
+            cr_engine,
+            ust1_controller,
+        } => execute_update_config(deps, info, cr_engine, ust1_controller),
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+/// Dispatches the CR refresh and the mint callback, in that order. Messages
+/// run sequentially, so the callback sees the snapshot taken by the refresh.
+fn execute_mint(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let refresh_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.cr_engine.to_string(),
+        msg: to_json_binary(&CrEngineExecuteMsg::RecalculateCr {})?,
+        funds: vec![],
+    });
+    let callback_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::MintCallback {})?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(refresh_msg)
+        .add_message(callback_msg)
+        .add_attribute("action", "mint"))
+}
+
+fn execute_mint_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let snapshot: Option<CrEngineSnapshotResponse> = deps
+        .querier
+        .query_wasm_smart(&config.cr_engine, &CrEngineQueryMsg::LastSnapshot {})?;
+    let snapshot = match snapshot {
+        Some(s) if s.timestamp == env.block.time => s,
+        other => {
+            return Err(ContractError::CrNotRefreshed {
+                snapshot: other.map(|s| s.timestamp),
+                expected: env.block.time,
+            })
+        }
+    };
+
+    let mut state = MINT_STATE.load(deps.storage)?;
+    let elapsed = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(state.last_mint.seconds());
+    let threshold = blue_threshold(deps.as_ref(), &config)?;
+    let excess = excess_collateral(snapshot.collateral_value, snapshot.ust1_supply, threshold);
+    let blue = snapshot.tier == CrTier::Blue && !excess.is_zero();
+    // Only the interval between two BLUE snapshots accrues; the CR may have
+    // been below the threshold for any part of an interval that starts or
+    // ends outside BLUE
+    let accrues = blue && state.blue_at_last_mint;
+    state.last_mint = env.block.time;
+    state.blue_at_last_mint = blue;
+
+    let response = Response::new()
+        .add_attribute("action", "mint_callback")
+        .add_attribute("collateral_value", snapshot.collateral_value)
+        .add_attribute("ust1_supply", snapshot.ust1_supply)
+        .add_attribute("excess_collateral", excess)
+        .add_attribute("elapsed_seconds", elapsed.to_string());
+
+    // Cancelled seconds are dropped, not deferred
+    if !accrues {
+        state.cancelled_seconds += elapsed;
+        MINT_STATE.save(deps.storage, &state)?;
+        return Ok(response
+            .add_attribute("amount", Uint128::zero())
+            .add_attribute("cancelled_seconds", elapsed.to_string()));
+    }
+
+    let amount = mint_amount(
+        snapshot.collateral_value,
+        snapshot.ust1_supply,
+        threshold,
+        elapsed,
+    );
+    state.total_minted = state
+        .total_minted
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    MINT_STATE.save(deps.storage, &state)?;
+
+    let mut messages = vec![];
+    for allocation in split(&config.pools, amount) {
+        if allocation.amount.is_zero() {
+            continue;
+        }
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.ust1_controller.to_string(),
+            msg: to_json_binary(&Ust1ControllerExecuteMsg::Mint {
+                recipient: allocation.pool.to_string(),
+                amount: allocation.amount,
+                collateral: None,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    Ok(response
+        .add_messages(messages)
+        .add_attribute("amount", amount))
+}
+
+fn execute_set_pools(
+    deps: DepsMut,
+    info: MessageInfo,
+    pools: Vec<PoolShareMsg>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    config.pools = validate_pools(deps.as_ref(), pools)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    let mut response = Response::new().add_attribute("action", "set_pools");
+    for pool in config.pools {
+        response = response.add_attribute(pool.address.to_string(), pool.share.to_string());
+    }
+    Ok(response)
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    cr_engine: Option<String>,
+    ust1_controller: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if let Some(cr_engine) = cr_engine {
+        config.cr_engine = deps.api.addr_validate(&cr_engine)?;
+    }
+    if let Some(ust1_controller) = ust1_controller {
+        config.ust1_controller = deps.api.addr_validate(&ust1_controller)?;
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("cr_engine", config.cr_engine)
+        .add_attribute("ust1_controller", config.ust1_controller))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::MintState {} => to_json_binary(&query_mint_state(deps)?),
+        QueryMsg::PendingMint {} => to_json_binary(&query_pending_mint(deps, env)?),
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        cr_engine: config.cr_engine,
+        ust1_controller: config.ust1_controller,
+        pools: config.pools,
+    })
+}
+
+fn query_mint_state(deps: Deps) -> StdResult<MintStateResponse> {
+    let state = MINT_STATE.load(deps.storage)?;
+    Ok(MintStateResponse {
+        last_mint: state.last_mint,
+        total_minted: state.total_minted,
+        cancelled_seconds: state.cancelled_seconds,
+        blue_at_last_mint: state.blue_at_last_mint,
+    })
+}
+
+fn query_pending_mint(deps: Deps, env: Env) -> StdResult<PendingMintResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let state = MINT_STATE.load(deps.storage)?;
+    let cr: CrEngineCrResponse = deps
+        .querier
+        .query_wasm_smart(&config.cr_engine, &CrEngineQueryMsg::CurrentCr {})?;
+
+    let threshold = blue_threshold(deps, &config)?;
+
+    let elapsed = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(state.last_mint.seconds());
+    let amount = if state.blue_at_last_mint && cr.tier == CrTier::Blue {
+        mint_amount(cr.collateral_value, cr.ust1_supply, threshold, elapsed)
+    } else {
+        Uint128::zero()
+    };
+
+    Ok(PendingMintResponse {
+        excess_collateral: excess_collateral(cr.collateral_value, cr.ust1_supply, threshold),
+        collateral_value: cr.collateral_value,
+        ust1_supply: cr.ust1_supply,
+        elapsed_seconds: elapsed,
+        amount,
+        allocations: split(&config.pools, amount),
+    })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+/// The CR engine's BLUE threshold, above which collateral is mintable
+fn blue_threshold(deps: Deps, config: &Config) -> StdResult<Decimal> {
+    let thresholds: CrEngineThresholds = deps
+        .querier
+        .query_wasm_smart(&config.cr_engine, &CrEngineQueryMsg::Thresholds {})?;
+    Ok(thresholds.blue)
+}
+
+/// Collateral value above `threshold * supply`
+fn excess_collateral(collateral_value: Uint128, supply: Uint128, threshold: Decimal) -> Uint128 {
+    collateral_value.saturating_sub(supply.mul_ceil(threshold))
+}
+
+/// UST1 accrued over `elapsed` seconds at `excess / MINT_PERIOD` per second,
+/// capped so that the CR stays strictly above the threshold after minting
+fn mint_amount(
+    collateral_value: Uint128,
+    supply: Uint128,
+    threshold: Decimal,
+    elapsed: u64,
+) -> Uint128 {
+    let excess = excess_collateral(collateral_value, supply, threshold);
+    if excess.is_zero() {
+        return Uint128::zero();
+    }
+
+    let accrued = if elapsed >= MINT_PERIOD {
+        excess
+    } else {
+        excess.multiply_ratio(elapsed, MINT_PERIOD)
+    };
+    // Largest supply with threshold * supply < collateral_value
+    let max_supply = collateral_value
+        .div_ceil(threshold)
+        .saturating_sub(Uint128::one());
+    accrued.min(max_supply.saturating_sub(supply))
+}
+
+/// Splits `amount` by pool share; rounding dust goes to the last pool
+fn split(pools: &[PoolShare], amount: Uint128) -> Vec<PoolAllocation> {
+    let mut remaining = amount;
+    pools
+        .iter()
+        .enumerate()
+        .map(|(i, pool)| {
+            let share = if i + 1 == pools.len() {
+                remaining
+            } else {
+                amount.mul_floor(pool.share)
+            };
+            remaining -= share;
+            PoolAllocation {
+                pool: pool.address.clone(),
+                amount: share,
+            }
+        })
+        .collect()
+}
+
+fn validate_pools(deps: Deps, pools: Vec<PoolShareMsg>) -> Result<Vec<PoolShare>, ContractError> {
+    if pools.is_empty() || pools.len() > MAX_POOLS {
+        return Err(ContractError::InvalidSplit {
+            reason: format!("expected 1 to {} pools", MAX_POOLS),
+        });
+    }
+
+    let mut validated: Vec<PoolShare> = Vec::with_capacity(pools.len());
+    let mut total = Decimal::zero();
+    for pool in pools {
+        let address: Addr = deps.api.addr_validate(&pool.address)?;
+        if pool.share.is_zero() {
+            return Err(ContractError::InvalidSplit {
+                reason: format!("zero share for {}", address),
+            });
+        }
+        if validated.iter().any(|p| p.address == address) {
+            return Err(ContractError::InvalidSplit {
+                reason: format!("duplicate pool {}", address),
+            });
+        }
+        total += pool.share;
+        validated.push(PoolShare {
+            address,
+            share: pool.share,
+        });
+    }
+
+    if total != Decimal::one() {
+        return Err(ContractError::InvalidSplit {
+            reason: format!("shares sum to {}, expected 1", total),
+        });
+    }
+    Ok(validated)
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+        MOCK_CONTRACT_ADDR,
+    };
+    use cosmwasm_std::{from_json, ContractResult, OwnedDeps, SystemResult, Timestamp, WasmQuery};
+
+    const GOVERNANCE: &str = "governance";
+    const CR_ENGINE: &str = "cr_engine";
+    const CONTROLLER: &str = "ust1_controller";
+    const UST1_POOL: &str = "ust1_staking_pool";
+    const USTR_POOL: &str = "ustr_staking_pool";
+    const BURN_POOL: &str = "buy_and_burn_pool";
+    const ALICE: &str = "alice";
+
+    /// One year in seconds, a fifth of the mint period
+    const YEAR: u64 = MINT_PERIOD / 5;
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn pool_split() -> Vec<PoolShareMsg> {
+        vec![
+            PoolShareMsg {
+                address: UST1_POOL.to_string(),
+                share: Decimal::percent(50),
+            },
+            PoolShareMsg {
+                address: USTR_POOL.to_string(),
+                share: Decimal::percent(30),
+            },
+            PoolShareMsg {
+                address: BURN_POOL.to_string(),
+                share: Decimal::percent(20),
+            },
+        ]
+    }
+
+    fn setup_contract(deps: &mut MockDeps) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            cr_engine: CR_ENGINE.to_string(),
+            ust1_controller: CONTROLLER.to_string(),
+            pools: pool_split(),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    /// Mocks the CR engine with a snapshot taken at `snapshot_time`; the live
+    /// CR matches the snapshot. Tiers use the default 95% / 110% / 190%.
+    fn mock_cr(deps: &mut MockDeps, collateral: u128, supply: u128, snapshot_time: Timestamp) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == CR_ENGINE => {
+                let collateral_value = Uint128::new(collateral);
+                let ust1_supply = Uint128::new(supply);
+                let ratio = Decimal::from_ratio(collateral, supply);
+                let cr = Some(ratio);
+                let tier = if ratio > Decimal::percent(190) {
+                    CrTier::Blue
+                } else {
+                    CrTier::Green
+                };
+                let bin = match from_json(msg).unwrap() {
+                    CrEngineQueryMsg::LastSnapshot {} => {
+                        to_json_binary(&Some(CrEngineSnapshotResponse {
+                            collateral_value,
+                            ust1_supply,
+                            cr,
+                            tier,
+                            timestamp: snapshot_time,
+                        }))
+                    }
+                    CrEngineQueryMsg::CurrentCr {} => to_json_binary(&CrEngineCrResponse {
+                        collateral_value,
+                        ust1_supply,
+                        cr,
+                        tier,
+                        unpriced_assets: vec![],
+                    }),
+                    CrEngineQueryMsg::Thresholds {} => to_json_binary(&CrEngineThresholds {
+                        yellow: Decimal::percent(95),
+                        green: Decimal::percent(110),
+                        blue: Decimal::percent(190),
+                    }),
+                };
+                SystemResult::Ok(ContractResult::Ok(bin.unwrap()))
+            }
+            _ => panic!("unexpected query"),
+        });
+    }
+
+    fn env_at(seconds_after_start: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds_after_start);
+        env
+    }
+
+    fn callback(deps: &mut MockDeps, env: Env) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::MintCallback {},
+        )
+    }
+
+    /// Runs the callback `seconds_after_start` with a freshly refreshed CR
+    fn mint_at(
+        deps: &mut MockDeps,
+        collateral: u128,
+        supply: u128,
+        seconds_after_start: u64,
+    ) -> Result<Response, ContractError> {
+        let env = env_at(seconds_after_start);
+        mock_cr(deps, collateral, supply, env.block.time);
+        callback(deps, env)
+    }
+
+    fn minted(res: &Response) -> Vec<(String, Uint128)> {
+        res.messages
+            .iter()
+            .map(|m| match &m.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr, msg, ..
+                }) => {
+                    assert_eq!(contract_addr, CONTROLLER);
+                    match from_json(msg).unwrap() {
+                        Ust1ControllerExecuteMsg::Mint {
+                            recipient, amount, ..
+                        } => (recipient, amount),
+                    }
+                }
+                _ => panic!("Expected wasm execute"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_instantiate_validates_split() {
+        let mut deps = mock_dependencies();
+        let mut pools = pool_split();
+        pools[2].share = Decimal::percent(10);
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            cr_engine: CR_ENGINE.to_string(),
+            ust1_controller: CONTROLLER.to_string(),
+            pools,
+        };
+        let err =
+            instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSplit { .. }));
+
+        setup_contract(&mut deps);
+        let mut pools = pool_split();
+        pools[1].address = UST1_POOL.to_string();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::SetPools { pools },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSplit { .. }));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::SetPools {
+                pools: pool_split(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+    }
+
+    #[test]
+    fn test_mint_refreshes_before_callback() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Mint {},
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 2);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, CR_ENGINE);
+                assert_eq!(
+                    from_json::<CrEngineExecuteMsg>(msg).unwrap(),
+                    CrEngineExecuteMsg::RecalculateCr {}
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+        match &res.messages[1].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, MOCK_CONTRACT_ADDR);
+                assert_eq!(
+                    from_json::<ExecuteMsg>(msg).unwrap(),
+                    ExecuteMsg::MintCallback {}
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+
+        // The callback is contract-only and needs a snapshot from this block
+        let err = execute(
+            deps.as_mut(),
+            env_at(YEAR),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::MintCallback {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        let stale = env_at(YEAR - 1).block.time;
+        mock_cr(&mut deps, 5_000_000, 1_000_000, stale);
+        let err = callback(&mut deps, env_at(YEAR)).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::CrNotRefreshed {
+                snapshot: Some(stale),
+                expected: env_at(YEAR).block.time,
+            }
+        );
+    }
+
+    #[test]
+    fn test_mints_excess_over_five_years_into_split() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        // The first mint only confirms BLUE
+        let res = mint_at(&mut deps, 5_000_000, 1_000_000, 0).unwrap();
+        assert!(res.messages.is_empty());
+        assert!(query_mint_state(deps.as_ref()).unwrap().blue_at_last_mint);
+
+        // Excess: 5,000,000 - 1.9 * 1,000,000 = 3,100,000; a year accrues a fifth
+        let res = mint_at(&mut deps, 5_000_000, 1_000_000, YEAR).unwrap();
+        assert_eq!(
+            minted(&res),
+            vec![
+                (UST1_POOL.to_string(), Uint128::new(310_000)),
+                (USTR_POOL.to_string(), Uint128::new(186_000)),
+                (BURN_POOL.to_string(), Uint128::new(124_000)),
+            ]
+        );
+
+        let state = query_mint_state(deps.as_ref()).unwrap();
+        assert_eq!(state.last_mint, env_at(YEAR).block.time);
+        assert_eq!(state.total_minted, Uint128::new(620_000));
+    }
+
+    #[test]
+    fn test_accrual_cancelled_below_threshold() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mint_at(&mut deps, 5_000_000, 1_000_000, 0).unwrap();
+
+        // 180% CR: the first year is cancelled
+        let res = mint_at(&mut deps, 1_800_000, 1_000_000, YEAR).unwrap();
+        assert!(res.messages.is_empty());
+        let state = query_mint_state(deps.as_ref()).unwrap();
+        assert_eq!(state.cancelled_seconds, YEAR);
+        assert_eq!(state.last_mint, env_at(YEAR).block.time);
+        assert!(!state.blue_at_last_mint);
+
+        // Back above 190%: the CR may have been below the threshold for most
+        // of the second year, so it is cancelled too
+        let env = env_at(2 * YEAR);
+        mock_cr(&mut deps, 5_000_000, 1_000_000, env.block.time);
+        let pending = query_pending_mint(deps.as_ref(), env.clone()).unwrap();
+        assert_eq!(pending.amount, Uint128::zero());
+        let res = callback(&mut deps, env).unwrap();
+        assert!(res.messages.is_empty());
+        let state = query_mint_state(deps.as_ref()).unwrap();
+        assert_eq!(state.cancelled_seconds, 2 * YEAR);
+        assert!(state.blue_at_last_mint);
+
+        // Only the third year, confirmed BLUE at both ends, accrues
+        let res = mint_at(&mut deps, 5_000_000, 1_000_000, 3 * YEAR).unwrap();
+        let total: Uint128 = minted(&res).iter().map(|(_, a)| *a).sum();
+        assert_eq!(total, Uint128::new(620_000));
+    }
+
+    #[test]
+    fn test_mint_capped_to_keep_cr_above_threshold() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mint_at(&mut deps, 5_000_000, 1_000_000, 0).unwrap();
+
+        // Ten years accrue the whole 3,100,000 excess, but supply may only
+        // grow to 2,631,578 before the CR reaches 190%
+        let env = env_at(10 * YEAR);
+        mock_cr(&mut deps, 5_000_000, 1_000_000, env.block.time);
+        let pending = query_pending_mint(deps.as_ref(), env.clone()).unwrap();
+        assert_eq!(pending.excess_collateral, Uint128::new(3_100_000));
+        assert_eq!(pending.amount, Uint128::new(1_631_578));
+
+        let res = callback(&mut deps, env).unwrap();
+        let allocations = minted(&res);
+        let total: Uint128 = allocations.iter().map(|(_, a)| *a).sum();
+        assert_eq!(total, Uint128::new(1_631_578));
+        // Rounding dust goes to the last pool
+        assert_eq!(allocations[0].1, Uint128::new(815_789));
+        assert_eq!(allocations[1].1, Uint128::new(489_473));
+        assert_eq!(allocations[2].1, Uint128::new(326_316));
+        assert!(
+            Uint128::new(5_000_000)
+                > Uint128::new(1_000_000 + 1_631_578).mul_ceil(Decimal::percent(190))
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use common::AssetInfo;
+    use cosmwasm_std::{Addr, Decimal, Uint128};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{ExecuteMsg, InstantiateMsg, MintStateResponse, PoolShareMsg, QueryMsg};
+    use crate::state::MINT_PERIOD;
+
+    const GOVERNANCE: &str = "governance";
+    const ALICE: &str = "alice";
+    const KEEPER: &str = "keeper";
+
+    #[test]
+    fn test_mint_refreshes_cr_and_fills_rolling_pools() {
+        let mut app = App::default();
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let cw20_code = app.store_code(cw20_contract());
+        let treasury_code = app.store_code(contract_wrapper!(treasury));
+        let engine_code = app.store_code(contract_wrapper!(cr_engine));
+        let controller_code = app.store_code(contract_wrapper!(ust1_controller));
+        let pool_code = app.store_code(contract_wrapper!(rolling_pool));
+        let minter_code = app.store_code(contract_wrapper!(crate));
+
+        let treasury = app
+            .instantiate_contract(
+                treasury_code,
+                gov.clone(),
+                &treasury::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+
+        // 5,000 collateral against 1,000 UST1: 500% CR
+        let collateral = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "WBTC",
+            vec![Cw20Coin {
+                address: treasury.to_string(),
+                amount: Uint128::new(5_000),
+            }],
+        );
+        let ust1 = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTONE",
+            vec![Cw20Coin {
+                address: ALICE.to_string(),
+                amount: Uint128::new(1_000),
+            }],
+        );
+        app.execute_contract(
+            gov.clone(),
+            treasury.clone(),
+            &treasury::msg::ExecuteMsg::AddCw20 {
+                contract_addr: collateral.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let engine = app
+            .instantiate_contract(
+                engine_code,
+                gov.clone(),
+                &cr_engine::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    treasury: treasury.to_string(),
+                    ust1_token: ust1.to_string(),
+                    price_source: cr_engine::state::PriceSource::Fixed {},
+                    thresholds: None,
+                    prices: vec![cr_engine::msg::PriceEntry {
+                        asset: AssetInfo::cw20(collateral.clone()),
+                        price: Decimal::one(),
+                    }],
+                },
+                &[],
+                "cr-engine",
+                None,
+            )
+            .unwrap();
+
+        let controller = app
+            .instantiate_contract(
+                controller_code,
+                gov.clone(),
+                &ust1_controller::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    cr_engine: engine.to_string(),
+                },
+                &[],
+                "ust1-controller",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            ust1.clone(),
+            &Cw20ExecuteMsg::UpdateMinter {
+                new_minter: Some(controller.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let pools: Vec<Addr> = ["ust1-pool", "ustr-pool", "burn-pool"]
+            .iter()
+            .map(|label| {
+                app.instantiate_contract(
+                    pool_code,
+                    gov.clone(),
+                    &rolling_pool::msg::InstantiateMsg {
+                        governance: GOVERNANCE.to_string(),
+                        ust1_token: ust1.to_string(),
+                        beneficiary: None,
+                    },
+                    &[],
+                    *label,
+                    None,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let minter = app
+            .instantiate_contract(
+                minter_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    cr_engine: engine.to_string(),
+                    ust1_controller: controller.to_string(),
+                    pools: pools
+                        .iter()
+                        .zip([50, 30, 20])
+                        .map(|(pool, pct)| PoolShareMsg {
+                            address: pool.to_string(),
+                            share: Decimal::percent(pct),
+                        })
+                        .collect(),
+                },
+                &[],
+                "blue-minter",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            controller.clone(),
+            &ust1_controller::msg::ExecuteMsg::AddMinter {
+                address: minter.to_string(),
+                role: ust1_controller::state::MinterRole::RollingPool,
+                cap: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // The first mint confirms BLUE; accrual starts from it
+        app.execute_contract(
+            Addr::unchecked(KEEPER),
+            minter.clone(),
+            &ExecuteMsg::Mint {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &ust1, &pools[0]), Uint128::zero());
+
+        // A year later the 3,100 excess has accrued a fifth
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(MINT_PERIOD / 5);
+        });
+        app.execute_contract(
+            Addr::unchecked(KEEPER),
+            minter.clone(),
+            &ExecuteMsg::Mint {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(balance(&app, &ust1, &pools[0]), Uint128::new(310));
+        assert_eq!(balance(&app, &ust1, &pools[1]), Uint128::new(186));
+        assert_eq!(balance(&app, &ust1, &pools[2]), Uint128::new(124));
+
+        let snapshot: Option<cr_engine::msg::CrSnapshotResponse> = app
+            .wrap()
+            .query_wasm_smart(&engine, &cr_engine::msg::QueryMsg::LastSnapshot {})
+            .unwrap();
+        assert_eq!(snapshot.unwrap().timestamp, app.block_info().time);
+
+        let state: MintStateResponse = app
+            .wrap()
+            .query_wasm_smart(&minter, &QueryMsg::MintState {})
+            .unwrap();
+        assert_eq!(state.total_minted, Uint128::new(620));
+        assert_eq!(state.last_mint, app.block_info().time);
+
+        // The callback cannot be called directly
+        let err = app
+            .execute_contract(
+                Addr::unchecked(KEEPER),
+                minter.clone(),
+                &ExecuteMsg::MintCallback {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.downcast::<crate::ContractError>().unwrap(),
+            crate::ContractError::Unauthorized
+        );
+    }
+}
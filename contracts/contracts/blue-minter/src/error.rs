@@ -0,0 +1,26 @@
+use cosmwasm_std::{StdError, Timestamp};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Invalid pool split: {reason}")]
+    InvalidSplit { reason: String },
+
+    #[error("CR not refreshed: last snapshot at {snapshot:?}, expected {expected}")]
+    CrNotRefreshed {
+        snapshot: Option<Timestamp>,
+        expected: Timestamp,
+    },
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
@@ -0,0 +1,28 @@
+//! # Blue Minter Contract
+//!
+//! Mints UST1 against collateral held above the CR engine's BLUE threshold
+//! (190% CR by default) and splits it between the rolling pools.
+//!
+//! ## Mint Rate
+//!
+//! While the CR is BLUE, UST1 accrues at `excess / 5 years` per second, where
+//! `excess = collateral_value - blue_threshold * ust1_supply` as seen by the CR
+//! engine (treasury `AllBalances` valued at engine prices, and the UST1 token
+//! supply). A mint is also capped so that it cannot take the CR down to the
+//! threshold.
+//!
+//! ## Refresh-then-Mint
+//!
+//! `Mint` is permissionless. It dispatches `RecalculateCr` to the CR engine
+//! followed by a callback into this contract, which only proceeds if the
+//! engine's snapshot was taken in the same block. The seconds since the last
+//! mint only accrue if both that mint and this one saw a BLUE snapshot;
+//! otherwise they are cancelled: the mint time moves forward and nothing is
+//! minted.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
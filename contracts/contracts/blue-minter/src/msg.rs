@@ -0,0 +1,160 @@
+use common::{Asset, AssetInfo, CrTier};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+
+use crate::state::PoolShare;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub cr_engine: String,
+    pub ust1_controller: String,
+    pub pools: Vec<PoolShareMsg>,
+}
+
+#[cw_serde]
+pub struct PoolShareMsg {
+    pub address: String,
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Refreshes the CR on the CR engine, then mints accrued UST1 into the
+    /// pools (permissionless)
+    Mint {},
+
+    /// Second half of `Mint`, dispatched after `RecalculateCr` (contract-only)
+    MintCallback {},
+
+    /// Replaces the pool split; shares must sum to 100% (governance-only)
+    SetPools { pools: Vec<PoolShareMsg> },
+
+    /// Updates contract references (governance-only)
+    UpdateConfig {
+        cr_engine: Option<String>,
+        ust1_controller: Option<String>,
+    },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    #[returns(MintStateResponse)]
+    MintState {},
+
+    /// What `Mint` would mint now, using the live CR
+    #[returns(PendingMintResponse)]
+    PendingMint {},
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub cr_engine: Addr,
+    pub ust1_controller: Addr,
+    pub pools: Vec<PoolShare>,
+}
+
+#[cw_serde]
+pub struct MintStateResponse {
+    pub last_mint: Timestamp,
+    pub total_minted: Uint128,
+    pub cancelled_seconds: u64,
+    pub blue_at_last_mint: bool,
+}
+
+#[cw_serde]
+pub struct PendingMintResponse {
+    pub collateral_value: Uint128,
+    pub ust1_supply: Uint128,
+    /// Collateral value above `blue_threshold * ust1_supply`
+    pub excess_collateral: Uint128,
+    pub elapsed_seconds: u64,
+    /// Zero unless the CR was BLUE at the last mint and still is (otherwise
+    /// the elapsed time would be cancelled)
+    pub amount: Uint128,
+    pub allocations: Vec<PoolAllocation>,
+}
+
+#[cw_serde]
+pub struct PoolAllocation {
+    pub pool: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+/// Refresh message sent to the CR engine
+#[cw_serde]
+pub enum CrEngineExecuteMsg {
+    RecalculateCr {},
+}
+
+/// Queries sent to the CR engine
+#[cw_serde]
+pub enum CrEngineQueryMsg {
+    CurrentCr {},
+    LastSnapshot {},
+    Thresholds {},
+}
+
+/// Mirrors the cr-engine's TierThresholds
+#[cw_serde]
+pub struct CrEngineThresholds {
+    pub yellow: Decimal,
+    pub green: Decimal,
+    pub blue: Decimal,
+}
+
+/// Mirrors the cr-engine's CrResponse
+#[cw_serde]
+pub struct CrEngineCrResponse {
+    pub collateral_value: Uint128,
+    pub ust1_supply: Uint128,
+    pub cr: Option<Decimal>,
+    pub tier: CrTier,
+    pub unpriced_assets: Vec<AssetInfo>,
+}
+
+/// Mirrors the cr-engine's CrSnapshotResponse
+#[cw_serde]
+pub struct CrEngineSnapshotResponse {
+    pub collateral_value: Uint128,
+    pub ust1_supply: Uint128,
+    pub cr: Option<Decimal>,
+    pub tier: CrTier,
+    pub timestamp: Timestamp,
+}
+
+/// Mint message sent to the UST1 controller
+#[cw_serde]
+pub enum Ust1ControllerExecuteMsg {
+    Mint {
+        recipient: String,
+        amount: Uint128,
+        collateral: Option<Asset>,
+    },
+}
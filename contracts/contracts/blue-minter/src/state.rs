@@ -0,0 +1,53 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw_storage_plus::Item;
+
+pub const CONTRACT_NAME: &str = "crates.io:blue-minter";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// 5 years (5 * 365 days) in seconds
+pub const MINT_PERIOD: u64 = 157_680_000;
+
+/// Maximum number of pools in the split
+pub const MAX_POOLS: usize = 3;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    pub cr_engine: Addr,
+    pub ust1_controller: Addr,
+    /// Recipients and their shares of each mint; shares sum to 100%
+    pub pools: Vec<PoolShare>,
+}
+
+#[cw_serde]
+pub struct PoolShare {
+    pub address: Addr,
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct MintState {
+    /// Time up to which accrual has been minted or cancelled
+    pub last_mint: Timestamp,
+    /// Lifetime UST1 minted into the pools
+    pub total_minted: Uint128,
+    /// Lifetime seconds of accrual cancelled while CR was at or below the threshold
+    pub cancelled_seconds: u64,
+    /// Whether the CR was BLUE at `last_mint`; only intervals that start and
+    /// end on a BLUE snapshot accrue
+    pub blue_at_last_mint: bool,
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const MINT_STATE: Item<MintState> = Item::new("mint_state");
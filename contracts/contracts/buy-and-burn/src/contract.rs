@@ -0,0 +1,1386 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Storage, Timestamp, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MedianResponse, MigrateMsg, ObservationEntry,
+    ObservationsResponse, PairAsset, PairAssetInfo, PairCw20HookMsg, PairQueryMsg,
+    PairSimulationResponse, PendingGovernanceResponse, QueryMsg, RollingPoolExecuteMsg,
+    StatsResponse, TwapResponse,
+};
+use crate::state::{
+    max_price_tolerance, BurnParams, BurnStats, Config, Observation, PendingGovernance,
+    PendingSwap, BURN_STATS, CONFIG, CONTRACT_NAME, CONTRACT_VERSION, DEFAULT_QUERY_LIMIT,
+    GOVERNANCE_TIMELOCK, KEEPER_LAST_SEEN, MAX_OBSERVATIONS, MAX_QUERY_LIMIT, NEXT_OBSERVATION,
+    OBSERVATIONS, PENDING_GOVERNANCE, PENDING_SWAP,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let params = msg.params.unwrap_or_else(BurnParams::default_params);
+    validate_params(&params)?;
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        ust1_token: deps.api.addr_validate(&msg.ust1_token)?,
+        ustr_token: deps.api.addr_validate(&msg.ustr_token)?,
+        keeper: deps.api.addr_validate(&msg.keeper)?,
+        pair: deps.api.addr_validate(&msg.pair)?,
+        rolling_pool: msg
+            .rolling_pool
+            .map(|a| deps.api.addr_validate(&a))
+            .transpose()?,
+        params,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    BURN_STATS.save(deps.storage, &BurnStats::default())?;
+    NEXT_OBSERVATION.save(deps.storage, &0)?;
+    KEEPER_LAST_SEEN.save(deps.storage, &env.block.time)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("keeper", config.keeper)
+        .add_attribute("pair", config.pair))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::BuyAndBurn {} => execute_buy_and_burn(deps, env),
+        ExecuteMsg::SwapCallback {} => execute_swap_callback(deps, env, info),
+        ExecuteMsg::BurnCallback {} => execute_burn_callback(deps, env, info),
+        ExecuteMsg::Observe {} => execute_observe(deps, env, info),
+        ExecuteMsg::UpdateConfig {
+            keeper,
+            pair,
+            rolling_pool,
+            params,
+        } => execute_update_config(deps, env, info, keeper, pair, rolling_pool, params),
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_buy_and_burn(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut messages = vec![];
+    if let Some(pool) = &config.rolling_pool {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: pool.to_string(),
+            msg: to_json_binary(&RollingPoolExecuteMsg::Drip {})?,
+            funds: vec![],
+        }));
+    }
+    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::SwapCallback {})?,
+        funds: vec![],
+    }));
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "buy_and_burn"))
+}
+
+fn execute_swap_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let params = &config.params;
+
+    let balance = token_balance(deps.as_ref(), &env, &config.ust1_token)?;
+    if balance.is_zero() {
+        return Err(ContractError::NothingToSpend);
+    }
+    let amount = balance.min(params.max_swap_amount);
+
+    let (twap, observations) = twap(deps.storage, env.block.time, params.window)?;
+    let (median, _) = median_price(deps.storage, env.block.time, params.window)?;
+    let (twap, median) = match (twap, median) {
+        (Some(twap), Some(median)) if observations >= params.min_observations => (twap, median),
+        _ => {
+            return Err(ContractError::InsufficientObservations {
+                required: params.min_observations,
+                available: observations,
+            })
+        }
+    };
+    // A few skewed observations that stood for long can drag the TWAP but
+    // not the median
+    if deviation(twap, median) > params.max_deviation {
+        return Err(ContractError::MedianDeviation {
+            twap,
+            median,
+            max_deviation: params.max_deviation,
+        });
+    }
+    // The spot price only gates the swap; the limits come from the TWAP,
+    // which one block's price cannot move
+    let spot = spot_price(deps.as_ref(), &config)?;
+    if deviation(spot, twap) > params.max_deviation {
+        return Err(ContractError::TwapDeviation {
+            spot,
+            twap,
+            max_deviation: params.max_deviation,
+        });
+    }
+
+    let min_return = amount
+        .mul_floor(Decimal::one() - params.max_slippage)
+        .div_floor(twap);
+    let ustr_balance_before = token_balance(deps.as_ref(), &env, &config.ustr_token)?;
+    PENDING_SWAP.save(
+        deps.storage,
+        &PendingSwap {
+            ust1_amount: amount,
+            ustr_balance_before,
+            min_return,
+        },
+    )?;
+
+    let swap_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Send {
+            contract: config.pair.to_string(),
+            amount,
+            msg: to_json_binary(&PairCw20HookMsg::Swap {
+                belief_price: Some(twap),
+                max_spread: Some(params.max_slippage),
+                to: None,
+            })?,
+        })?,
+        funds: vec![],
+    });
+    let callback_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::BurnCallback {})?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(callback_msg)
+        .add_attribute("action", "swap")
+        .add_attribute("ust1_amount", amount)
+        .add_attribute("spot_price", spot.to_string())
+        .add_attribute("twap", twap.to_string())
+        .add_attribute("median", median.to_string())
+        .add_attribute("min_return", min_return))
+}
+
+fn execute_burn_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let pending = PENDING_SWAP.load(deps.storage)?;
+    let received = token_balance(deps.as_ref(), &env, &config.ustr_token)?
+        .saturating_sub(pending.ustr_balance_before);
+    if received < pending.min_return || received.is_zero() {
+        return Err(ContractError::SlippageExceeded {
+            received,
+            minimum: pending.min_return,
+        });
+    }
+    PENDING_SWAP.remove(deps.storage);
+
+    let mut stats = BURN_STATS.load(deps.storage)?;
+    stats.total_ust1_spent = stats
+        .total_ust1_spent
+        .checked_add(pending.ust1_amount)
+        .map_err(StdError::from)?;
+    stats.total_ustr_burned = stats
+        .total_ustr_burned
+        .checked_add(received)
+        .map_err(StdError::from)?;
+    stats.burn_count += 1;
+    stats.last_burn = Some(env.block.time);
+    BURN_STATS.save(deps.storage, &stats)?;
+
+    let burn_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ustr_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount: received })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(burn_msg)
+        .add_attribute("action", "burn")
+        .add_attribute("ust1_spent", pending.ust1_amount)
+        .add_attribute("ustr_burned", received)
+        .add_attribute("total_ustr_burned", stats.total_ustr_burned))
+}
+
+fn execute_observe(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender == config.keeper {
+        KEEPER_LAST_SEEN.save(deps.storage, &env.block.time)?;
+    } else {
+        // Observations open up once the keeper stops, so swaps never depend
+        // on it staying online
+        let keeper_last_seen = KEEPER_LAST_SEEN.load(deps.storage)?;
+        if env.block.time < keeper_last_seen.plus_seconds(config.params.keeper_timeout) {
+            return Err(ContractError::Unauthorized);
+        }
+    }
+    let seq = NEXT_OBSERVATION.load(deps.storage)?;
+
+    if let Some(last) = seq
+        .checked_sub(1)
+        .map(|s| OBSERVATIONS.load(deps.storage, s))
+        .transpose()?
+    {
+        let next_allowed = last
+            .timestamp
+            .plus_seconds(config.params.observation_interval);
+        if env.block.time < next_allowed {
+            return Err(ContractError::ObservationTooSoon {
+                remaining_seconds: next_allowed.seconds() - env.block.time.seconds(),
+            });
+        }
+    }
+
+    let price = spot_price(deps.as_ref(), &config)?;
+    OBSERVATIONS.save(
+        deps.storage,
+        seq,
+        &Observation {
+            timestamp: env.block.time,
+            height: env.block.height,
+            price,
+        },
+    )?;
+    NEXT_OBSERVATION.save(deps.storage, &(seq + 1))?;
+    if seq >= MAX_OBSERVATIONS {
+        OBSERVATIONS.remove(deps.storage, seq - MAX_OBSERVATIONS);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "observe")
+        .add_attribute("seq", seq.to_string())
+        .add_attribute("price", price.to_string()))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    keeper: Option<String>,
+    pair: Option<String>,
+    rolling_pool: Option<String>,
+    params: Option<BurnParams>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if let Some(keeper) = keeper {
+        config.keeper = deps.api.addr_validate(&keeper)?;
+        // A new keeper gets a full timeout before others may observe
+        KEEPER_LAST_SEEN.save(deps.storage, &env.block.time)?;
+    }
+    if let Some(pair) = pair {
+        config.pair = deps.api.addr_validate(&pair)?;
+        // Prices from the old pair say nothing about the new one
+        let next = NEXT_OBSERVATION.load(deps.storage)?;
+        for seq in next.saturating_sub(MAX_OBSERVATIONS)..next {
+            OBSERVATIONS.remove(deps.storage, seq);
+        }
+    }
+    if let Some(rolling_pool) = rolling_pool {
+        config.rolling_pool = Some(deps.api.addr_validate(&rolling_pool)?);
+    }
+    if let Some(params) = params {
+        validate_params(&params)?;
+        config.params = params;
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("keeper", config.keeper)
+        .add_attribute("pair", config.pair))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Stats {} => to_json_binary(&query_stats(deps)?),
+        QueryMsg::Twap {} => to_json_binary(&query_twap(deps, env)?),
+        QueryMsg::Median {} => to_json_binary(&query_median(deps, env)?),
+        QueryMsg::Observations {
+            start_before,
+            limit,
+        } => to_json_binary(&query_observations(deps, start_before, limit)?),
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        ust1_token: config.ust1_token,
+        ustr_token: config.ustr_token,
+        keeper: config.keeper,
+        pair: config.pair,
+        rolling_pool: config.rolling_pool,
+        params: config.params,
+    })
+}
+
+fn query_stats(deps: Deps) -> StdResult<StatsResponse> {
+    let stats = BURN_STATS.load(deps.storage)?;
+    Ok(StatsResponse {
+        total_ust1_spent: stats.total_ust1_spent,
+        total_ustr_burned: stats.total_ustr_burned,
+        burn_count: stats.burn_count,
+        last_burn: stats.last_burn,
+    })
+}
+
+fn query_twap(deps: Deps, env: Env) -> StdResult<TwapResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (twap, observations) = twap(deps.storage, env.block.time, config.params.window)?;
+    Ok(TwapResponse {
+        twap,
+        observations,
+        window: config.params.window,
+    })
+}
+
+fn query_median(deps: Deps, env: Env) -> StdResult<MedianResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (median, observations) = median_price(deps.storage, env.block.time, config.params.window)?;
+    Ok(MedianResponse {
+        median,
+        observations,
+        window: config.params.window,
+    })
+}
+
+fn query_observations(
+    deps: Deps,
+    start_before: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ObservationsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let end = start_before.map(Bound::exclusive);
+
+    let observations = OBSERVATIONS
+        .range(deps.storage, None, end, Order::Descending)
+        .take(limit)
+        .map(|r| r.map(|(seq, observation)| ObservationEntry { seq, observation }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ObservationsResponse { observations })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+/// Time-weighted average of observations over `[now - window, now]`. Each
+/// observation holds until the next one; the newest holds until now. Also
+/// returns the number of observations taken within the window.
+fn twap(storage: &dyn Storage, now: Timestamp, window: u64) -> StdResult<(Option<Decimal>, u32)> {
+    let window_start = now.seconds().saturating_sub(window);
+    let mut segment_end = now.seconds();
+    let mut weighted = Decimal256::zero();
+    let mut covered = 0u64;
+    let mut count = 0u32;
+
+    for item in OBSERVATIONS.range(storage, None, None, Order::Descending) {
+        let (_, observation) = item?;
+        let observed = observation.timestamp.seconds();
+        let segment_start = observed.max(window_start);
+        let duration = segment_end.saturating_sub(segment_start);
+
+        weighted += Decimal256::from(observation.price) * Decimal256::from_ratio(duration, 1u64);
+        covered += duration;
+        if observed >= window_start {
+            count += 1;
+        }
+        if observed <= window_start {
+            break;
+        }
+        segment_end = segment_start;
+    }
+
+    if covered == 0 {
+        // Only observations from this very second
+        let latest = OBSERVATIONS
+            .range(storage, None, None, Order::Descending)
+            .next()
+            .transpose()?
+            .map(|(_, o)| o.price);
+        return Ok((latest, count));
+    }
+
+    let twap = weighted / Decimal256::from_ratio(covered, 1u64);
+    let twap = Decimal::try_from(twap).map_err(|e| StdError::generic_err(e.to_string()))?;
+    Ok((Some(twap), count))
+}
+
+/// Median of the observations taken within `[now - window, now]`, and how
+/// many there were. An even count averages the middle two.
+fn median_price(
+    storage: &dyn Storage,
+    now: Timestamp,
+    window: u64,
+) -> StdResult<(Option<Decimal>, u32)> {
+    let window_start = Timestamp::from_seconds(now.seconds().saturating_sub(window));
+    let mut prices = vec![];
+    for item in OBSERVATIONS.range(storage, None, None, Order::Descending) {
+        let (_, observation) = item?;
+        if observation.timestamp < window_start {
+            break;
+        }
+        prices.push(observation.price);
+    }
+    prices.sort();
+
+    let count = prices.len() as u32;
+    let mid = prices.len() / 2;
+    let median = match prices.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(prices[mid]),
+        _ => Some(prices[mid - 1].checked_add(prices[mid])? / Uint128::new(2)),
+    };
+    Ok((median, count))
+}
+
+/// Mid price of USTR in UST1, from simulating a swap of the probe amount.
+/// Return plus spread plus commission is what the probe buys at the mid price.
+fn spot_price(deps: Deps, config: &Config) -> Result<Decimal, ContractError> {
+    let probe = config.params.probe_amount;
+    let sim: PairSimulationResponse = deps.querier.query_wasm_smart(
+        &config.pair,
+        &PairQueryMsg::Simulation {
+            offer_asset: PairAsset {
+                info: PairAssetInfo::Token {
+                    contract_addr: config.ust1_token.to_string(),
+                },
+                amount: probe,
+            },
+        },
+    )?;
+    let ideal = sim.return_amount + sim.spread_amount + sim.commission_amount;
+    if ideal.is_zero() {
+        return Err(StdError::generic_err("Pair simulation returned nothing").into());
+    }
+    let price = Decimal::from_ratio(probe, ideal);
+    if price.is_zero() {
+        return Err(StdError::generic_err("Spot price rounds to zero").into());
+    }
+    Ok(price)
+}
+
+/// Relative distance of `price` from `reference`
+fn deviation(price: Decimal, reference: Decimal) -> Decimal {
+    if price > reference {
+        (price - reference) / reference
+    } else {
+        (reference - price) / reference
+    }
+}
+
+fn token_balance(deps: Deps, env: &Env, token: &cosmwasm_std::Addr) -> StdResult<Uint128> {
+    let res: BalanceResponse = deps.querier.query_wasm_smart(
+        token,
+        &Cw20QueryMsg::Balance {
+            address: env.contract.address.to_string(),
+        },
+    )?;
+    Ok(res.balance)
+}
+
+fn validate_params(params: &BurnParams) -> Result<(), ContractError> {
+    if params.max_slippage > max_price_tolerance() || params.max_deviation > max_price_tolerance() {
+        return Err(ContractError::InvalidParameter {
+            reason: format!(
+                "max_slippage and max_deviation must not exceed {}",
+                max_price_tolerance()
+            ),
+        });
+    }
+    if params.window == 0 || params.min_observations == 0 || params.observation_interval == 0 {
+        return Err(ContractError::InvalidParameter {
+            reason: "window, min_observations and observation_interval must be non-zero"
+                .to_string(),
+        });
+    }
+    if params.min_observations as u64 > MAX_OBSERVATIONS {
+        return Err(ContractError::InvalidParameter {
+            reason: format!("min_observations must not exceed {}", MAX_OBSERVATIONS),
+        });
+    }
+    if params.probe_amount.is_zero() || params.max_swap_amount.is_zero() {
+        return Err(ContractError::InvalidParameter {
+            reason: "probe_amount and max_swap_amount must be non-zero".to_string(),
+        });
+    }
+    if params.keeper_timeout < params.observation_interval {
+        return Err(ContractError::InvalidParameter {
+            reason: "keeper_timeout must be at least observation_interval".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+        MOCK_CONTRACT_ADDR,
+    };
+    use cosmwasm_std::{from_json, ContractResult, OwnedDeps, SystemResult, WasmQuery};
+
+    const GOVERNANCE: &str = "governance";
+    const UST1: &str = "ust1_token";
+    const USTR: &str = "ustr_token";
+    const KEEPER: &str = "keeper";
+    const PAIR: &str = "ust1_ustr_pair";
+    const POOL: &str = "rolling_pool";
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env
+    }
+
+    fn params() -> BurnParams {
+        BurnParams {
+            min_observations: 2,
+            ..BurnParams::default_params()
+        }
+    }
+
+    fn setup_contract(deps: &mut MockDeps) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ust1_token: UST1.to_string(),
+            ustr_token: USTR.to_string(),
+            keeper: KEEPER.to_string(),
+            pair: PAIR.to_string(),
+            rolling_pool: Some(POOL.to_string()),
+            params: Some(params()),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    /// Pair quoting `price` UST1 per USTR with no spread or fees, and the
+    /// contract holding the given token balances
+    fn mock_market(deps: &mut MockDeps, price: Decimal, ust1: u128, ustr: u128) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == PAIR => {
+                let PairQueryMsg::Simulation { offer_asset } = from_json(msg).unwrap();
+                let res = PairSimulationResponse {
+                    return_amount: offer_asset.amount.div_floor(price),
+                    spread_amount: Uint128::zero(),
+                    commission_amount: Uint128::zero(),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&res).unwrap()))
+            }
+            WasmQuery::Smart { contract_addr, .. } => {
+                let balance = if contract_addr == UST1 { ust1 } else { ustr };
+                let res = BalanceResponse {
+                    balance: Uint128::new(balance),
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&res).unwrap()))
+            }
+            _ => panic!("unexpected query"),
+        });
+    }
+
+    fn observe_as(
+        deps: &mut MockDeps,
+        sender: &str,
+        seconds: u64,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env_at(seconds),
+            mock_info(sender, &[]),
+            ExecuteMsg::Observe {},
+        )
+    }
+
+    fn observe_at(deps: &mut MockDeps, seconds: u64) -> Result<Response, ContractError> {
+        observe_as(deps, KEEPER, seconds)
+    }
+
+    fn swap_at(deps: &mut MockDeps, seconds: u64) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env_at(seconds),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::SwapCallback {},
+        )
+    }
+
+    #[test]
+    fn test_observe_is_rate_limited_and_opens_up_without_keeper() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_market(&mut deps, Decimal::percent(200), 0, 0);
+        let start = mock_env().block.time.seconds();
+
+        let err = observe_as(&mut deps, "anyone", start + 10_000).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        observe_at(&mut deps, start + 10_000).unwrap();
+        let err = observe_at(&mut deps, start + 12_000).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ObservationTooSoon {
+                remaining_seconds: 1_600
+            }
+        );
+        observe_at(&mut deps, start + 13_600).unwrap();
+
+        // Anyone may observe once the keeper has been silent for a day
+        let silent = start + 13_600 + 86_400;
+        let err = observe_as(&mut deps, "anyone", silent - 1).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        observe_as(&mut deps, "anyone", silent).unwrap();
+
+        // A new keeper gets a full timeout of its own
+        execute(
+            deps.as_mut(),
+            env_at(silent),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::UpdateConfig {
+                keeper: Some("new_keeper".to_string()),
+                pair: None,
+                rolling_pool: None,
+                params: None,
+            },
+        )
+        .unwrap();
+        let err = observe_as(&mut deps, "anyone", silent + 3_600).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        observe_as(&mut deps, "new_keeper", silent + 3_600).unwrap();
+
+        let res = query_observations(deps.as_ref(), None, None).unwrap();
+        assert_eq!(res.observations.len(), 4);
+        assert_eq!(res.observations[0].seq, 3);
+        assert_eq!(res.observations[0].observation.price, Decimal::percent(200));
+    }
+
+    #[test]
+    fn test_twap_weights_prices_by_duration() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        // Before the window: only its last 3,600s count
+        mock_market(&mut deps, Decimal::percent(100), 0, 0);
+        observe_at(&mut deps, 100_000).unwrap();
+        mock_market(&mut deps, Decimal::percent(200), 0, 0);
+        observe_at(&mut deps, 182_800).unwrap();
+        mock_market(&mut deps, Decimal::percent(400), 0, 0);
+        observe_at(&mut deps, 186_400).unwrap();
+
+        // Window [104_400, 190_800]: 1.0 for 78_400s, 2.0 for 3_600s, 4.0 for 4_400s
+        let res = query_twap(deps.as_ref(), env_at(190_800)).unwrap();
+        assert_eq!(res.observations, 2);
+        let expected = Decimal::from_ratio(78_400u64 + 7_200 + 17_600, 86_400u64);
+        assert_eq!(res.twap, Some(expected));
+    }
+
+    #[test]
+    fn test_median_ignores_outliers_and_old_observations() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        // Outside the window ending at 190_800
+        mock_market(&mut deps, Decimal::percent(900), 0, 0);
+        observe_at(&mut deps, 100_000).unwrap();
+
+        for (seconds, price) in [(110_000, 200), (120_000, 250), (130_000, 5_000)] {
+            mock_market(&mut deps, Decimal::percent(price), 0, 0);
+            observe_at(&mut deps, seconds).unwrap();
+        }
+        let res = query_median(deps.as_ref(), env_at(190_800)).unwrap();
+        assert_eq!(res.observations, 3);
+        assert_eq!(res.median, Some(Decimal::percent(250)));
+
+        // An even count averages the middle two
+        mock_market(&mut deps, Decimal::percent(160), 0, 0);
+        observe_at(&mut deps, 140_000).unwrap();
+        let res = query_median(deps.as_ref(), env_at(190_800)).unwrap();
+        assert_eq!(res.observations, 4);
+        assert_eq!(res.median, Some(Decimal::percent(225)));
+
+        let obs = query_observations(deps.as_ref(), None, Some(1)).unwrap();
+        assert_eq!(
+            obs.observations[0].observation.height,
+            mock_env().block.height
+        );
+    }
+
+    #[test]
+    fn test_swap_requires_observations_and_spot_near_twap() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_market(&mut deps, Decimal::percent(200), 1_000, 0);
+
+        observe_at(&mut deps, 100_000).unwrap();
+        let err = swap_at(&mut deps, 100_100).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientObservations {
+                required: 2,
+                available: 1
+            }
+        );
+
+        observe_at(&mut deps, 103_600).unwrap();
+
+        // Spot moved 25% against a 5% deviation limit
+        mock_market(&mut deps, Decimal::percent(250), 1_000, 0);
+        let err = swap_at(&mut deps, 104_000).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TwapDeviation {
+                spot: Decimal::percent(250),
+                twap: Decimal::percent(200),
+                max_deviation: Decimal::percent(5),
+            }
+        );
+
+        mock_market(&mut deps, Decimal::percent(200), 0, 0);
+        let err = swap_at(&mut deps, 104_000).unwrap_err();
+        assert_eq!(err, ContractError::NothingToSpend);
+    }
+
+    #[test]
+    fn test_swap_requires_twap_near_median() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        // One skewed observation standing for most of the window
+        for (seconds, price) in [(100_000, 200), (103_600, 400), (179_000, 200)] {
+            mock_market(&mut deps, Decimal::percent(price), 1_000, 0);
+            observe_at(&mut deps, seconds).unwrap();
+        }
+
+        // 2.0 for 3_600s, 4.0 for 75_400s, 2.0 for 1_000s
+        let twap = Decimal::from_ratio(7_200u64 + 301_600 + 2_000, 80_000u64);
+        let err = swap_at(&mut deps, 180_000).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MedianDeviation {
+                twap,
+                median: Decimal::percent(200),
+                max_deviation: Decimal::percent(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_swap_and_burn_enforce_min_return() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_market(&mut deps, Decimal::percent(200), 1_000, 0);
+        observe_at(&mut deps, 100_000).unwrap();
+        observe_at(&mut deps, 103_600).unwrap();
+
+        let res = swap_at(&mut deps, 104_000).unwrap();
+        assert_eq!(res.messages.len(), 2);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, UST1);
+                match from_json(msg).unwrap() {
+                    Cw20ExecuteMsg::Send {
+                        contract,
+                        amount,
+                        msg,
+                    } => {
+                        assert_eq!(contract, PAIR);
+                        assert_eq!(amount, Uint128::new(1_000));
+                        let PairCw20HookMsg::Swap {
+                            belief_price,
+                            max_spread,
+                            ..
+                        } = from_json(msg).unwrap();
+                        assert_eq!(belief_price, Some(Decimal::percent(200)));
+                        assert_eq!(max_spread, Some(Decimal::percent(2)));
+                    }
+                    other => panic!("unexpected msg: {:?}", other),
+                }
+            }
+            other => panic!("unexpected msg: {:?}", other),
+        }
+        // 1,000 UST1 at 2.0 less 2% slippage
+        let pending = PENDING_SWAP.load(&deps.storage).unwrap();
+        assert_eq!(pending.min_return, Uint128::new(490));
+
+        // Pair delivered too little
+        mock_market(&mut deps, Decimal::percent(200), 0, 480);
+        let err = execute(
+            deps.as_mut(),
+            env_at(104_000),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::BurnCallback {},
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::SlippageExceeded {
+                received: Uint128::new(480),
+                minimum: Uint128::new(490),
+            }
+        );
+
+        mock_market(&mut deps, Decimal::percent(200), 0, 495);
+        let res = execute(
+            deps.as_mut(),
+            env_at(104_000),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::BurnCallback {},
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, USTR);
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Burn {
+                        amount: Uint128::new(495)
+                    }
+                );
+            }
+            other => panic!("unexpected msg: {:?}", other),
+        }
+
+        let stats = query_stats(deps.as_ref()).unwrap();
+        assert_eq!(stats.total_ust1_spent, Uint128::new(1_000));
+        assert_eq!(stats.total_ustr_burned, Uint128::new(495));
+        assert_eq!(stats.burn_count, 1);
+        assert_eq!(stats.last_burn, Some(Timestamp::from_seconds(104_000)));
+    }
+
+    #[test]
+    fn test_swap_is_capped_and_priced_from_twap() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ust1_token: UST1.to_string(),
+            ustr_token: USTR.to_string(),
+            keeper: KEEPER.to_string(),
+            pair: PAIR.to_string(),
+            rolling_pool: None,
+            params: Some(BurnParams {
+                max_swap_amount: Uint128::new(600),
+                ..params()
+            }),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        mock_market(&mut deps, Decimal::percent(200), 1_000, 0);
+        observe_at(&mut deps, 100_000).unwrap();
+        observe_at(&mut deps, 103_600).unwrap();
+
+        // Spot pushed 4% up, inside the deviation limit
+        mock_market(&mut deps, Decimal::percent(208), 1_000, 0);
+        let res = swap_at(&mut deps, 104_000).unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_json(msg).unwrap() {
+                Cw20ExecuteMsg::Send { amount, msg, .. } => {
+                    assert_eq!(amount, Uint128::new(600));
+                    let PairCw20HookMsg::Swap { belief_price, .. } = from_json(msg).unwrap();
+                    assert_eq!(belief_price, Some(Decimal::percent(200)));
+                }
+                other => panic!("unexpected msg: {:?}", other),
+            },
+            other => panic!("unexpected msg: {:?}", other),
+        }
+        // 600 UST1 at the TWAP of 2.0 less 2% slippage
+        let pending = PENDING_SWAP.load(&deps.storage).unwrap();
+        assert_eq!(pending.ust1_amount, Uint128::new(600));
+        assert_eq!(pending.min_return, Uint128::new(294));
+    }
+
+    #[test]
+    fn test_callbacks_and_config_are_restricted() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        for msg in [ExecuteMsg::SwapCallback {}, ExecuteMsg::BurnCallback {}] {
+            let err =
+                execute(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized);
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::UpdateConfig {
+                keeper: None,
+                pair: None,
+                rolling_pool: None,
+                params: Some(BurnParams {
+                    max_slippage: Decimal::percent(25),
+                    ..params()
+                }),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+        // The keeper must get at least one interval to observe
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::UpdateConfig {
+                keeper: None,
+                pair: None,
+                rolling_pool: None,
+                params: Some(BurnParams {
+                    keeper_timeout: 3_599,
+                    ..params()
+                }),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+        // Observations must come from different blocks
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::UpdateConfig {
+                keeper: None,
+                pair: None,
+                rolling_pool: None,
+                params: Some(BurnParams {
+                    observation_interval: 0,
+                    ..params()
+                }),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::BuyAndBurn {},
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{
+        to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+        Uint128,
+    };
+    use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+    use cw_multi_test::{App, ContractWrapper, Executor};
+    use cw_storage_plus::Item;
+
+    use crate::msg::{
+        ExecuteMsg, InstantiateMsg, PairQueryMsg, PairSimulationResponse, QueryMsg, StatsResponse,
+    };
+    use crate::state::BurnParams;
+
+    const GOVERNANCE: &str = "governance";
+
+    /// A tenth of the rolling pool's 5-year period
+    const TENTH: u64 = 15_768_000;
+
+    /// Constant-price pair: pays out USTR at 2 UST1 each from its own balance
+    mod mock_pair {
+        use super::*;
+
+        #[cw_serde]
+        pub struct InstantiateMsg {
+            pub ustr_token: String,
+        }
+
+        #[cw_serde]
+        pub enum ExecuteMsg {
+            Receive(Cw20ReceiveMsg),
+        }
+
+        const USTR: Item<Addr> = Item::new("ustr");
+
+        pub fn instantiate(
+            deps: DepsMut,
+            _env: Env,
+            _info: MessageInfo,
+            msg: InstantiateMsg,
+        ) -> StdResult<Response> {
+            USTR.save(deps.storage, &Addr::unchecked(msg.ustr_token))?;
+            Ok(Response::new())
+        }
+
+        pub fn execute(
+            deps: DepsMut,
+            _env: Env,
+            _info: MessageInfo,
+            msg: ExecuteMsg,
+        ) -> StdResult<Response> {
+            let ExecuteMsg::Receive(receive) = msg;
+            Ok(Response::new().add_message(cosmwasm_std::WasmMsg::Execute {
+                contract_addr: USTR.load(deps.storage)?.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: receive.sender,
+                    amount: receive.amount / Uint128::new(2),
+                })?,
+                funds: vec![],
+            }))
+        }
+
+        pub fn query(_deps: Deps, _env: Env, msg: PairQueryMsg) -> StdResult<Binary> {
+            let PairQueryMsg::Simulation { offer_asset } = msg;
+            to_json_binary(&PairSimulationResponse {
+                return_amount: offer_asset.amount / Uint128::new(2),
+                spread_amount: Uint128::zero(),
+                commission_amount: Uint128::zero(),
+            })
+        }
+    }
+
+    fn pair_contract() -> Box<dyn cw_multi_test::Contract<Empty>> {
+        let contract =
+            ContractWrapper::new(mock_pair::execute, mock_pair::instantiate, mock_pair::query);
+        Box::new(contract)
+    }
+
+    #[test]
+    fn test_drips_pool_swaps_and_burns() {
+        let mut app = App::default();
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let cw20_code = app.store_code(cw20_contract());
+        let pool_code = app.store_code(contract_wrapper!(rolling_pool));
+        let burner_code = app.store_code(contract_wrapper!(crate));
+        let pair_code = app.store_code(pair_contract());
+
+        let ust1 = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTONE",
+            vec![Cw20Coin {
+                address: GOVERNANCE.to_string(),
+                amount: Uint128::new(1_000_000_000),
+            }],
+        );
+        let ustr = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTR",
+            vec![Cw20Coin {
+                address: GOVERNANCE.to_string(),
+                amount: Uint128::new(1_000_000_000),
+            }],
+        );
+        let pair = app
+            .instantiate_contract(
+                pair_code,
+                gov.clone(),
+                &mock_pair::InstantiateMsg {
+                    ustr_token: ustr.to_string(),
+                },
+                &[],
+                "pair",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            ustr.clone(),
+            &Cw20ExecuteMsg::Transfer {
+                recipient: pair.to_string(),
+                amount: Uint128::new(1_000_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let pool = app
+            .instantiate_contract(
+                pool_code,
+                gov.clone(),
+                &rolling_pool::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    beneficiary: None,
+                },
+                &[],
+                "rolling-pool",
+                None,
+            )
+            .unwrap();
+        let burner = app
+            .instantiate_contract(
+                burner_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    ustr_token: ustr.to_string(),
+                    keeper: GOVERNANCE.to_string(),
+                    pair: pair.to_string(),
+                    rolling_pool: Some(pool.to_string()),
+                    params: Some(BurnParams {
+                        min_observations: 2,
+                        ..BurnParams::default_params()
+                    }),
+                },
+                &[],
+                "buy-and-burn",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::SetBeneficiary {
+                beneficiary: Some(rolling_pool::msg::BeneficiaryMsg {
+                    address: burner.to_string(),
+                    hook_msg: None,
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            ust1.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: pool.to_string(),
+                amount: Uint128::new(1_000_000_000),
+                msg: to_json_binary(&rolling_pool::msg::ReceiveMsg::Deposit {}).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // The keeper never showed up, so anyone's observations an hour apart
+        // build the TWAP
+        app.update_block(|block| block.time = block.time.plus_seconds(TENTH));
+        for _ in 0..2 {
+            app.execute_contract(
+                Addr::unchecked("anyone"),
+                burner.clone(),
+                &ExecuteMsg::Observe {},
+                &[],
+            )
+            .unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(3_600));
+        }
+
+        let ustr_supply_before = app
+            .wrap()
+            .query_wasm_smart::<cw20::TokenInfoResponse>(&ustr, &Cw20QueryMsg::TokenInfo {})
+            .unwrap()
+            .total_supply;
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            burner.clone(),
+            &ExecuteMsg::BuyAndBurn {},
+            &[],
+        )
+        .unwrap();
+
+        let stats: StatsResponse = app
+            .wrap()
+            .query_wasm_smart(&burner, &QueryMsg::Stats {})
+            .unwrap();
+        // A tenth of the period plus the two observation hours was dripped
+        let dripped = Uint128::new(1_000_000_000).multiply_ratio(TENTH + 7_200, TENTH * 10);
+        assert_eq!(stats.total_ust1_spent, dripped);
+        assert_eq!(stats.total_ustr_burned, dripped / Uint128::new(2));
+        assert_eq!(stats.burn_count, 1);
+
+        assert_eq!(balance(&app, &ust1, &burner), Uint128::zero());
+        assert_eq!(balance(&app, &ustr, &burner), Uint128::zero());
+        assert_eq!(balance(&app, &ust1, &pair), dripped);
+        let ustr_supply_after = app
+            .wrap()
+            .query_wasm_smart::<cw20::TokenInfoResponse>(&ustr, &Cw20QueryMsg::TokenInfo {})
+            .unwrap()
+            .total_supply;
+        assert_eq!(
+            ustr_supply_before - ustr_supply_after,
+            stats.total_ustr_burned
+        );
+
+        // Dripped UST1 is gone, so a second run has nothing to spend
+        let err = app
+            .execute_contract(
+                Addr::unchecked("anyone"),
+                burner.clone(),
+                &ExecuteMsg::BuyAndBurn {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            crate::ContractError::NothingToSpend.to_string()
+        );
+    }
+}
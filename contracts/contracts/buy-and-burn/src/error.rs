@@ -0,0 +1,46 @@
+use cosmwasm_std::{Decimal, StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("No UST1 to spend")]
+    NothingToSpend,
+
+    #[error("Observation too soon: next allowed in {remaining_seconds} seconds")]
+    ObservationTooSoon { remaining_seconds: u64 },
+
+    #[error("Insufficient observations in window: {available} of {required}")]
+    InsufficientObservations { required: u32, available: u32 },
+
+    #[error("Spot price {spot} deviates from TWAP {twap} by more than {max_deviation}")]
+    TwapDeviation {
+        spot: Decimal,
+        twap: Decimal,
+        max_deviation: Decimal,
+    },
+
+    #[error("TWAP {twap} deviates from median {median} by more than {max_deviation}")]
+    MedianDeviation {
+        twap: Decimal,
+        median: Decimal,
+        max_deviation: Decimal,
+    },
+
+    #[error("Slippage exceeded: received {received}, minimum {minimum}")]
+    SlippageExceeded { received: Uint128, minimum: Uint128 },
+
+    #[error("Invalid parameter: {reason}")]
+    InvalidParameter { reason: String },
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
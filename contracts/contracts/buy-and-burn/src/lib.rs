@@ -0,0 +1,36 @@
+//! # Buy-and-Burn Contract
+//!
+//! Spends UST1 from its rolling pool on market purchases of USTR and burns
+//! the USTR bought.
+//!
+//! ## Execution
+//!
+//! `BuyAndBurn` is permissionless and meant to be called daily by a relayer.
+//! It runs as a chain of messages in one transaction:
+//!
+//! 1. `Drip` the rolling pool into this contract
+//! 2. Check the TWAP against the median of recent observations and the
+//!    pair's spot price against the TWAP, then swap up to `max_swap_amount`
+//!    of UST1 on the configured Astroport/Terraswap-style pair, with the
+//!    belief price and minimum return taken from the TWAP
+//! 3. Verify the USTR received against the slippage limit and burn it
+//!
+//! ## TWAP
+//!
+//! Pair interfaces differ in how (and whether) they expose cumulative
+//! prices, so the contract keeps its own: the keeper calls `Observe` once
+//! per observation interval to record the pair's spot price. Observations
+//! are at least an interval apart, so each comes from a different block.
+//! The TWAP weights each observation by how long it stood within the
+//! window; the median of the same observations must agree with it, so a
+//! few skewed observations cannot carry the TWAP.
+//!
+//! If the keeper stops, anyone may call `Observe` once it has been silent
+//! for `keeper_timeout`, so `BuyAndBurn` keeps working without it.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
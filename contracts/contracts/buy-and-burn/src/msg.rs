@@ -0,0 +1,181 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+
+use crate::state::{BurnParams, Observation};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub ust1_token: String,
+    pub ustr_token: String,
+    pub keeper: String,
+    pub pair: String,
+    pub rolling_pool: Option<String>,
+    /// Defaults to `BurnParams::default_params()` if not provided
+    pub params: Option<BurnParams>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Drips the rolling pool, swaps UST1 up to the per-call cap for USTR and
+    /// burns it (permissionless)
+    BuyAndBurn {},
+
+    /// Swap step of `BuyAndBurn` (contract-only)
+    SwapCallback {},
+
+    /// Burn step of `BuyAndBurn` (contract-only)
+    BurnCallback {},
+
+    /// Records the pair's spot price for the TWAP and median (rate-limited;
+    /// keeper-only until the keeper has been silent for `keeper_timeout`)
+    Observe {},
+
+    /// Updates the keeper, pair, rolling pool and parameters (governance-only)
+    UpdateConfig {
+        keeper: Option<String>,
+        pair: Option<String>,
+        rolling_pool: Option<String>,
+        params: Option<BurnParams>,
+    },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    /// Cumulative UST1 spent and USTR burned
+    #[returns(StatsResponse)]
+    Stats {},
+
+    /// TWAP over the configured window as of now
+    #[returns(TwapResponse)]
+    Twap {},
+
+    /// Median of the observations in the configured window as of now
+    #[returns(MedianResponse)]
+    Median {},
+
+    /// Recorded observations, newest first
+    #[returns(ObservationsResponse)]
+    Observations {
+        /// Sequence number to start before (exclusive)
+        start_before: Option<u64>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub ustr_token: Addr,
+    pub keeper: Addr,
+    pub pair: Addr,
+    pub rolling_pool: Option<Addr>,
+    pub params: BurnParams,
+}
+
+#[cw_serde]
+pub struct StatsResponse {
+    pub total_ust1_spent: Uint128,
+    pub total_ustr_burned: Uint128,
+    pub burn_count: u64,
+    pub last_burn: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct TwapResponse {
+    /// UST1 per USTR; None if there are no observations
+    pub twap: Option<Decimal>,
+    /// Observations within the window
+    pub observations: u32,
+    pub window: u64,
+}
+
+#[cw_serde]
+pub struct MedianResponse {
+    /// UST1 per USTR; None if there are no observations in the window
+    pub median: Option<Decimal>,
+    /// Observations within the window
+    pub observations: u32,
+    pub window: u64,
+}
+
+#[cw_serde]
+pub struct ObservationEntry {
+    pub seq: u64,
+    pub observation: Observation,
+}
+
+#[cw_serde]
+pub struct ObservationsResponse {
+    pub observations: Vec<ObservationEntry>,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+/// Drip message sent to the rolling pool
+#[cw_serde]
+pub enum RollingPoolExecuteMsg {
+    Drip {},
+}
+
+/// Asset info in the Astroport/Terraswap pair format
+#[cw_serde]
+pub enum PairAssetInfo {
+    Token { contract_addr: String },
+    NativeToken { denom: String },
+}
+
+#[cw_serde]
+pub struct PairAsset {
+    pub info: PairAssetInfo,
+    pub amount: Uint128,
+}
+
+/// Queries sent to the pair
+#[cw_serde]
+pub enum PairQueryMsg {
+    Simulation { offer_asset: PairAsset },
+}
+
+/// Mirrors the pair's SimulationResponse
+#[cw_serde]
+pub struct PairSimulationResponse {
+    pub return_amount: Uint128,
+    pub spread_amount: Uint128,
+    pub commission_amount: Uint128,
+}
+
+/// Swap hook sent with UST1 to the pair
+#[cw_serde]
+pub enum PairCw20HookMsg {
+    Swap {
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+        to: Option<String>,
+    },
+}
@@ -0,0 +1,120 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:buy-and-burn";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// Default page size for list queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for list queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+/// Observations kept; older ones are pruned
+pub const MAX_OBSERVATIONS: u64 = 96;
+
+/// Upper bound for `max_slippage` and `max_deviation`
+pub fn max_price_tolerance() -> Decimal {
+    Decimal::percent(20)
+}
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub ustr_token: Addr,
+    /// Records price observations; anyone may once it has been silent for
+    /// `keeper_timeout`
+    pub keeper: Addr,
+    /// UST1/USTR pair (Astroport/Terraswap-style interface)
+    pub pair: Addr,
+    /// Rolling pool dripped at the start of each execution
+    pub rolling_pool: Option<Addr>,
+    pub params: BurnParams,
+}
+
+#[cw_serde]
+pub struct BurnParams {
+    /// Maximum shortfall of USTR received versus the TWAP, fees included
+    pub max_slippage: Decimal,
+    /// Maximum relative difference of the spot price from the TWAP, and of
+    /// the TWAP from the median
+    pub max_deviation: Decimal,
+    /// TWAP and median window in seconds
+    pub window: u64,
+    /// Minimum observations within the window for the TWAP to be usable
+    pub min_observations: u32,
+    /// Minimum seconds between observations; non-zero, so every observation
+    /// comes from a different block
+    pub observation_interval: u64,
+    /// UST1 amount simulated to read the spot price
+    pub probe_amount: Uint128,
+    /// Most UST1 swapped per execution; the rest waits for the next one
+    pub max_swap_amount: Uint128,
+    /// Seconds without a keeper observation after which anyone may observe;
+    /// at least `observation_interval`
+    pub keeper_timeout: u64,
+}
+
+impl BurnParams {
+    /// 2% slippage, 5% deviation, 24h window with at least 6 hourly
+    /// observations, at most 10,000 UST1 per execution, anyone may observe
+    /// after a day without the keeper
+    pub fn default_params() -> Self {
+        BurnParams {
+            max_slippage: Decimal::percent(2),
+            max_deviation: Decimal::percent(5),
+            window: 86_400,
+            min_observations: 6,
+            observation_interval: 3_600,
+            probe_amount: Uint128::new(1_000_000),
+            max_swap_amount: Uint128::new(10_000_000_000_000_000_000_000),
+            keeper_timeout: 86_400,
+        }
+    }
+}
+
+/// Spot price of USTR in UST1 recorded by `Observe`
+#[cw_serde]
+pub struct Observation {
+    pub timestamp: Timestamp,
+    pub height: u64,
+    pub price: Decimal,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct BurnStats {
+    pub total_ust1_spent: Uint128,
+    pub total_ustr_burned: Uint128,
+    pub burn_count: u64,
+    pub last_burn: Option<Timestamp>,
+}
+
+/// Swap parameters held between the swap and burn steps
+#[cw_serde]
+pub struct PendingSwap {
+    pub ust1_amount: Uint128,
+    pub ustr_balance_before: Uint128,
+    pub min_return: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const BURN_STATS: Item<BurnStats> = Item::new("burn_stats");
+pub const PENDING_SWAP: Item<PendingSwap> = Item::new("pending_swap");
+/// Observations by sequence number
+pub const OBSERVATIONS: Map<u64, Observation> = Map::new("observations");
+/// Sequence number of the next observation
+pub const NEXT_OBSERVATION: Item<u64> = Item::new("next_observation");
+/// Last keeper observation, or when the keeper was set if it has not observed since
+pub const KEEPER_LAST_SEEN: Item<Timestamp> = Item::new("keeper_last_seen");
@@ -0,0 +1,1248 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{
+    BallotResponse, ConfigResponse, Cw721QueryMsg, DelegatorsResponse, ExecuteMsg, InstantiateMsg,
+    MigrateMsg, NumTokensResponse, ProposalResponse, ProposalsResponse, QueryMsg, TokensResponse,
+    VotingPowerResponse,
+};
+use crate::state::{
+    Ballot, Config, Proposal, ProposalStatus, Tally, VoteOption, BALLOTS, CONFIG, CONTRACT_NAME,
+    CONTRACT_VERSION, DEFAULT_QUERY_LIMIT, DELEGATIONS, DELEGATORS, MAX_DELEGATORS, MAX_PERIOD,
+    MAX_QUERY_LIMIT, MAX_TITLE_LENGTH, MIN_PERIOD, NEXT_PROPOSAL_ID, PROPOSALS, TOKEN_PAGE_LIMIT,
+    TOKEN_VOTES,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        nft_contract: deps.api.addr_validate(&msg.nft_contract)?,
+        quorum: msg.quorum,
+        threshold: msg.threshold,
+        voting_period: msg.voting_period,
+        timelock: msg.timelock,
+        execution_period: msg.execution_period,
+        min_proposal_power: msg.min_proposal_power,
+    };
+    validate_config(&config)?;
+    CONFIG.save(deps.storage, &config)?;
+    NEXT_PROPOSAL_ID.save(deps.storage, &1)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("nft_contract", config.nft_contract)
+        .add_attribute("quorum", config.quorum.to_string())
+        .add_attribute("threshold", config.threshold.to_string()))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            msgs,
+        } => execute_propose(deps, env, info, title, description, msgs),
+        ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, proposal_id),
+        ExecuteMsg::Delegate { delegate } => execute_delegate(deps, info, delegate),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, info),
+        ExecuteMsg::UpdateConfig {
+            nft_contract,
+            quorum,
+            threshold,
+            voting_period,
+            timelock,
+            execution_period,
+            min_proposal_power,
+        } => execute_update_config(
+            deps,
+            env,
+            info,
+            nft_contract,
+            quorum,
+            threshold,
+            voting_period,
+            timelock,
+            execution_period,
+            min_proposal_power,
+        ),
+    }
+}
+
+fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if msgs.is_empty() {
+        return Err(ContractError::EmptyProposal);
+    }
+    let title = title.trim().to_string();
+    if title.is_empty() || title.len() > MAX_TITLE_LENGTH {
+        return Err(ContractError::InvalidTitle {
+            reason: format!("must be 1 to {} bytes", MAX_TITLE_LENGTH),
+        });
+    }
+
+    let power = voting_tokens(deps.as_ref(), &config, &info.sender)?.len() as u64;
+    if power < config.min_proposal_power {
+        return Err(ContractError::InsufficientProposalPower {
+            power,
+            required: config.min_proposal_power,
+        });
+    }
+
+    let supply: NumTokensResponse = deps
+        .querier
+        .query_wasm_smart(&config.nft_contract, &Cw721QueryMsg::NumTokens {})?;
+
+    let id = NEXT_PROPOSAL_ID.load(deps.storage)?;
+    NEXT_PROPOSAL_ID.save(deps.storage, &(id + 1))?;
+    let voting_end = env.block.time.plus_seconds(config.voting_period);
+    let executable_after = voting_end.plus_seconds(config.timelock);
+    let proposal = Proposal {
+        proposer: info.sender,
+        title,
+        description,
+        msgs,
+        total_power: supply.count,
+        quorum: config.quorum,
+        threshold: config.threshold,
+        voting_end,
+        executable_after,
+        expires_at: executable_after.plus_seconds(config.execution_period),
+        tally: Tally::default(),
+        executed: false,
+    };
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("proposer", proposal.proposer)
+        .add_attribute("total_power", proposal.total_power.to_string())
+        .add_attribute("voting_end", proposal.voting_end.to_string()))
+}
+
+fn execute_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: VoteOption,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut proposal = load_proposal(deps.as_ref(), proposal_id)?;
+    if env.block.time >= proposal.voting_end {
+        return Err(ContractError::VotingClosed { proposal_id });
+    }
+    // A repeat vote only adds nodes that reached the voter since, and must
+    // keep the option already cast
+    let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &info.sender))?;
+    if ballot.as_ref().is_some_and(|b| b.vote != vote) {
+        return Err(ContractError::AlreadyVoted { proposal_id });
+    }
+
+    let mut power = 0u64;
+    for token_id in voting_tokens(deps.as_ref(), &config, &info.sender)? {
+        if TOKEN_VOTES.has(deps.storage, (proposal_id, &token_id)) {
+            continue;
+        }
+        TOKEN_VOTES.save(deps.storage, (proposal_id, &token_id), &info.sender)?;
+        power += 1;
+    }
+    if power == 0 {
+        return Err(ContractError::NoVotingPower);
+    }
+
+    proposal.tally.add(&vote, power);
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    BALLOTS.save(
+        deps.storage,
+        (proposal_id, &info.sender),
+        &Ballot {
+            vote: vote.clone(),
+            power: ballot.map_or(0, |b| b.power) + power,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("vote", format!("{:?}", vote).to_lowercase())
+        .add_attribute("power", power.to_string()))
+}
+
+fn execute_execute(deps: DepsMut, env: Env, proposal_id: u64) -> Result<Response, ContractError> {
+    let mut proposal = load_proposal(deps.as_ref(), proposal_id)?;
+    match proposal.status(env.block.time) {
+        ProposalStatus::Passed => {}
+        ProposalStatus::Executed => return Err(ContractError::AlreadyExecuted { proposal_id }),
+        ProposalStatus::Expired => {
+            return Err(ContractError::ProposalExpired {
+                proposal_id,
+                expires_at: proposal.expires_at,
+            })
+        }
+        ProposalStatus::Open | ProposalStatus::Rejected => {
+            return Err(ContractError::NotPassed { proposal_id })
+        }
+    }
+    if env.block.time < proposal.executable_after {
+        return Err(ContractError::TimelockNotExpired {
+            proposal_id,
+            executable_after: proposal.executable_after,
+        });
+    }
+
+    proposal.executed = true;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_messages(proposal.msgs)
+        .add_attribute("action", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+fn execute_delegate(
+    deps: DepsMut,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if delegate == info.sender {
+        return Err(ContractError::SelfDelegation);
+    }
+
+    if let Some(previous) = DELEGATIONS.may_load(deps.storage, &info.sender)? {
+        DELEGATORS.remove(deps.storage, (&previous, &info.sender));
+    }
+    let delegators = DELEGATORS
+        .prefix(&delegate)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(MAX_DELEGATORS as usize)
+        .count() as u32;
+    if delegators >= MAX_DELEGATORS {
+        return Err(ContractError::TooManyDelegators {
+            delegate: delegate.to_string(),
+            max: MAX_DELEGATORS,
+        });
+    }
+
+    DELEGATIONS.save(deps.storage, &info.sender, &delegate)?;
+    DELEGATORS.save(deps.storage, (&delegate, &info.sender), &())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "delegate")
+        .add_attribute("owner", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+fn execute_undelegate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let delegate = DELEGATIONS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NoDelegation)?;
+    DELEGATIONS.remove(deps.storage, &info.sender);
+    DELEGATORS.remove(deps.storage, (&delegate, &info.sender));
+
+    Ok(Response::new()
+        .add_attribute("action", "undelegate")
+        .add_attribute("owner", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    nft_contract: Option<String>,
+    quorum: Option<Decimal>,
+    threshold: Option<Decimal>,
+    voting_period: Option<u64>,
+    timelock: Option<u64>,
+    execution_period: Option<u64>,
+    min_proposal_power: Option<u64>,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+    let mut config = CONFIG.load(deps.storage)?;
+    if let Some(nft_contract) = nft_contract {
+        config.nft_contract = deps.api.addr_validate(&nft_contract)?;
+    }
+    if let Some(quorum) = quorum {
+        config.quorum = quorum;
+    }
+    if let Some(threshold) = threshold {
+        config.threshold = threshold;
+    }
+    if let Some(voting_period) = voting_period {
+        config.voting_period = voting_period;
+    }
+    if let Some(timelock) = timelock {
+        config.timelock = timelock;
+    }
+    if let Some(execution_period) = execution_period {
+        config.execution_period = execution_period;
+    }
+    if let Some(min_proposal_power) = min_proposal_power {
+        config.min_proposal_power = min_proposal_power;
+    }
+    validate_config(&config)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("nft_contract", config.nft_contract)
+        .add_attribute("quorum", config.quorum.to_string())
+        .add_attribute("threshold", config.threshold.to_string()))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Proposal { proposal_id } => {
+            let proposal = PROPOSALS
+                .may_load(deps.storage, proposal_id)?
+                .ok_or_else(|| StdError::not_found(format!("proposal {}", proposal_id)))?;
+            to_json_binary(&proposal_response(&env, proposal_id, proposal))
+        }
+        QueryMsg::Proposals {
+            start_before,
+            limit,
+        } => to_json_binary(&query_proposals(deps, env, start_before, limit)?),
+        QueryMsg::Ballot { proposal_id, voter } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            to_json_binary(&BallotResponse {
+                ballot: BALLOTS.may_load(deps.storage, (proposal_id, &voter))?,
+            })
+        }
+        QueryMsg::VotingPower { address } => to_json_binary(&query_voting_power(deps, address)?),
+        QueryMsg::Delegators {
+            delegate,
+            start_after,
+            limit,
+        } => to_json_binary(&query_delegators(deps, delegate, start_after, limit)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        nft_contract: config.nft_contract,
+        quorum: config.quorum,
+        threshold: config.threshold,
+        voting_period: config.voting_period,
+        timelock: config.timelock,
+        execution_period: config.execution_period,
+        min_proposal_power: config.min_proposal_power,
+    })
+}
+
+fn query_proposals(
+    deps: Deps,
+    env: Env,
+    start_before: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ProposalsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let end = start_before.map(Bound::exclusive);
+
+    let proposals = PROPOSALS
+        .range(deps.storage, None, end, Order::Descending)
+        .take(limit)
+        .map(|r| r.map(|(id, proposal)| proposal_response(&env, id, proposal)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ProposalsResponse { proposals })
+}
+
+fn query_voting_power(deps: Deps, address: String) -> StdResult<VotingPowerResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let delegate = DELEGATIONS.may_load(deps.storage, &address)?;
+
+    let own = if delegate.is_none() {
+        owned_tokens(deps, &config, &address)?.len() as u64
+    } else {
+        0
+    };
+    let mut delegated = 0u64;
+    for delegator in delegators(deps, &address)? {
+        delegated += owned_tokens(deps, &config, &delegator)?.len() as u64;
+    }
+
+    Ok(VotingPowerResponse {
+        own,
+        delegated,
+        total: own + delegated,
+        delegate,
+    })
+}
+
+fn query_delegators(
+    deps: Deps,
+    delegate: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DelegatorsResponse> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let start = start.as_ref().map(Bound::exclusive);
+
+    let delegators = DELEGATORS
+        .prefix(&delegate)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DelegatorsResponse { delegators })
+}
+
+// ============ HELPERS ============
+
+fn load_proposal(deps: Deps, proposal_id: u64) -> Result<Proposal, ContractError> {
+    PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::ProposalNotFound { proposal_id })
+}
+
+fn proposal_response(env: &Env, id: u64, proposal: Proposal) -> ProposalResponse {
+    ProposalResponse {
+        id,
+        status: proposal.status(env.block.time),
+        proposer: proposal.proposer,
+        title: proposal.title,
+        description: proposal.description,
+        msgs: proposal.msgs,
+        total_power: proposal.total_power,
+        quorum: proposal.quorum,
+        threshold: proposal.threshold,
+        voting_end: proposal.voting_end,
+        executable_after: proposal.executable_after,
+        expires_at: proposal.expires_at,
+        tally: proposal.tally,
+    }
+}
+
+fn delegators(deps: Deps, delegate: &Addr) -> StdResult<Vec<Addr>> {
+    DELEGATORS
+        .prefix(delegate)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Node ids `voter` votes with: its own unless delegated away, plus those of
+/// everyone delegating to it
+fn voting_tokens(deps: Deps, config: &Config, voter: &Addr) -> StdResult<Vec<String>> {
+    let mut tokens = vec![];
+    if !DELEGATIONS.has(deps.storage, voter) {
+        tokens.extend(owned_tokens(deps, config, voter)?);
+    }
+    for delegator in delegators(deps, voter)? {
+        tokens.extend(owned_tokens(deps, config, &delegator)?);
+    }
+    Ok(tokens)
+}
+
+fn owned_tokens(deps: Deps, config: &Config, owner: &Addr) -> StdResult<Vec<String>> {
+    let mut tokens: Vec<String> = vec![];
+    loop {
+        let page: TokensResponse = deps.querier.query_wasm_smart(
+            &config.nft_contract,
+            &Cw721QueryMsg::Tokens {
+                owner: owner.to_string(),
+                start_after: tokens.last().cloned(),
+                limit: Some(TOKEN_PAGE_LIMIT),
+            },
+        )?;
+        let done = page.tokens.len() < TOKEN_PAGE_LIMIT as usize;
+        tokens.extend(page.tokens);
+        if done {
+            return Ok(tokens);
+        }
+    }
+}
+
+fn validate_config(config: &Config) -> Result<(), ContractError> {
+    if config.quorum.is_zero() || config.quorum > Decimal::one() {
+        return Err(ContractError::InvalidParameter {
+            reason: "quorum must be greater than 0 and at most 1".to_string(),
+        });
+    }
+    if config.threshold < Decimal::percent(50) || config.threshold >= Decimal::one() {
+        return Err(ContractError::InvalidParameter {
+            reason: "threshold must be at least 0.5 and below 1".to_string(),
+        });
+    }
+    if config.voting_period < MIN_PERIOD || config.voting_period > MAX_PERIOD {
+        return Err(ContractError::InvalidParameter {
+            reason: format!(
+                "voting_period must be between {} and {} seconds",
+                MIN_PERIOD, MAX_PERIOD
+            ),
+        });
+    }
+    if config.timelock < MIN_PERIOD || config.timelock > MAX_PERIOD {
+        return Err(ContractError::InvalidParameter {
+            reason: format!(
+                "timelock must be between {} and {} seconds",
+                MIN_PERIOD, MAX_PERIOD
+            ),
+        });
+    }
+    if config.execution_period < MIN_PERIOD || config.execution_period > MAX_PERIOD {
+        return Err(ContractError::InvalidParameter {
+            reason: format!(
+                "execution_period must be between {} and {} seconds",
+                MIN_PERIOD, MAX_PERIOD
+            ),
+        });
+    }
+    if config.min_proposal_power == 0 {
+        return Err(ContractError::InvalidParameter {
+            reason: "min_proposal_power must be at least 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+        MOCK_CONTRACT_ADDR,
+    };
+    use cosmwasm_std::{
+        from_json, BankMsg, ContractResult, OwnedDeps, SystemResult, Timestamp, WasmQuery,
+    };
+
+    const NODES: &str = "cl8y_nodes";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+    const CAROL: &str = "carol";
+    const DAY: u64 = 86_400;
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env
+    }
+
+    fn setup_contract(deps: &mut MockDeps) {
+        let msg = InstantiateMsg {
+            nft_contract: NODES.to_string(),
+            quorum: Decimal::percent(40),
+            threshold: Decimal::percent(50),
+            voting_period: 3 * DAY,
+            timelock: 2 * DAY,
+            execution_period: 7 * DAY,
+            min_proposal_power: 1,
+        };
+        instantiate(deps.as_mut(), env_at(0), mock_info(ALICE, &[]), msg).unwrap();
+    }
+
+    /// Node collection of `total` nodes where `holdings` lists (token, owner)
+    /// and the rest belong to an inactive holder
+    fn mock_nodes(deps: &mut MockDeps, holdings: &[(&str, &str)], total: u64) {
+        let holdings: Vec<(String, String)> = holdings
+            .iter()
+            .map(|(t, o)| (t.to_string(), o.to_string()))
+            .collect();
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == NODES => {
+                let res = match from_json(msg).unwrap() {
+                    Cw721QueryMsg::Tokens {
+                        owner,
+                        start_after,
+                        limit,
+                    } => {
+                        let mut tokens: Vec<String> = holdings
+                            .iter()
+                            .filter(|(_, o)| *o == owner)
+                            .map(|(t, _)| t.clone())
+                            .filter(|t| start_after.as_ref().is_none_or(|s| t > s))
+                            .collect();
+                        tokens.sort();
+                        tokens.truncate(limit.unwrap_or(10) as usize);
+                        to_json_binary(&TokensResponse { tokens })
+                    }
+                    Cw721QueryMsg::NumTokens {} => {
+                        to_json_binary(&NumTokensResponse { count: total })
+                    }
+                };
+                SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+            }
+            _ => panic!("unexpected query"),
+        });
+    }
+
+    fn default_holdings() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("a1", ALICE),
+            ("a2", ALICE),
+            ("a3", ALICE),
+            ("b1", BOB),
+            ("b2", BOB),
+            ("c1", CAROL),
+        ]
+    }
+
+    fn payout() -> CosmosMsg {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: vec![],
+        })
+    }
+
+    fn propose(deps: &mut MockDeps, proposer: &str) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(proposer, &[]),
+            ExecuteMsg::Propose {
+                title: "Pay recipient".to_string(),
+                description: String::new(),
+                msgs: vec![payout()],
+            },
+        )
+    }
+
+    fn vote(deps: &mut MockDeps, voter: &str, vote: VoteOption) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env_at(DAY),
+            mock_info(voter, &[]),
+            ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote,
+            },
+        )
+    }
+
+    fn status_at(deps: &MockDeps, seconds: u64) -> ProposalStatus {
+        let proposal = PROPOSALS.load(&deps.storage, 1).unwrap();
+        proposal.status(Timestamp::from_seconds(seconds))
+    }
+
+    #[test]
+    fn test_nodes_vote_once_per_proposal() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_nodes(&mut deps, &default_holdings(), 10);
+
+        let err = propose(&mut deps, "nobody").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientProposalPower {
+                power: 0,
+                required: 1
+            }
+        );
+        propose(&mut deps, ALICE).unwrap();
+
+        vote(&mut deps, ALICE, VoteOption::Yes).unwrap();
+        let err = vote(&mut deps, ALICE, VoteOption::Yes).unwrap_err();
+        assert_eq!(err, ContractError::NoVotingPower);
+
+        // Alice moves a voted node to Bob: it does not vote again
+        let mut moved = default_holdings();
+        moved[0] = ("a1", BOB);
+        mock_nodes(&mut deps, &moved, 10);
+        vote(&mut deps, BOB, VoteOption::No).unwrap();
+
+        let proposal = PROPOSALS.load(&deps.storage, 1).unwrap();
+        assert_eq!(proposal.total_power, 10);
+        assert_eq!(proposal.tally.yes, 3);
+        assert_eq!(proposal.tally.no, 2);
+        let ballot = BALLOTS
+            .load(&deps.storage, (1, &Addr::unchecked(BOB)))
+            .unwrap();
+        assert_eq!(ballot.power, 2);
+    }
+
+    #[test]
+    fn test_delegates_vote_with_delegated_nodes() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_nodes(&mut deps, &default_holdings(), 10);
+
+        let err = execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Delegate {
+                delegate: ALICE.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::SelfDelegation);
+
+        for owner in [ALICE, CAROL] {
+            execute(
+                deps.as_mut(),
+                env_at(0),
+                mock_info(owner, &[]),
+                ExecuteMsg::Delegate {
+                    delegate: BOB.to_string(),
+                },
+            )
+            .unwrap();
+        }
+        let power = query_voting_power(deps.as_ref(), BOB.to_string()).unwrap();
+        assert_eq!((power.own, power.delegated, power.total), (2, 4, 6));
+        let power = query_voting_power(deps.as_ref(), ALICE.to_string()).unwrap();
+        assert_eq!(power.total, 0);
+        assert_eq!(power.delegate, Some(Addr::unchecked(BOB)));
+
+        propose(&mut deps, BOB).unwrap();
+        let err = vote(&mut deps, ALICE, VoteOption::No).unwrap_err();
+        assert_eq!(err, ContractError::NoVotingPower);
+        vote(&mut deps, BOB, VoteOption::Yes).unwrap();
+
+        // Undelegating after the delegate voted frees nothing: the nodes voted
+        execute(
+            deps.as_mut(),
+            env_at(DAY),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Undelegate {},
+        )
+        .unwrap();
+        let err = vote(&mut deps, ALICE, VoteOption::No).unwrap_err();
+        assert_eq!(err, ContractError::NoVotingPower);
+
+        let proposal = PROPOSALS.load(&deps.storage, 1).unwrap();
+        assert_eq!(proposal.tally.yes, 6);
+        let delegators = query_delegators(deps.as_ref(), BOB.to_string(), None, None).unwrap();
+        assert_eq!(delegators.delegators, vec![Addr::unchecked(CAROL)]);
+    }
+
+    #[test]
+    fn test_quorum_threshold_and_timelock() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_nodes(&mut deps, &default_holdings(), 10);
+        propose(&mut deps, ALICE).unwrap();
+
+        // 3 of 10 nodes is below the 40% quorum
+        vote(&mut deps, ALICE, VoteOption::Yes).unwrap();
+        assert_eq!(status_at(&deps, DAY), ProposalStatus::Open);
+        assert_eq!(status_at(&deps, 3 * DAY), ProposalStatus::Rejected);
+
+        // 5 of 10 meets quorum, but a 3-2 split must exceed 50%
+        vote(&mut deps, BOB, VoteOption::No).unwrap();
+        assert_eq!(status_at(&deps, 3 * DAY), ProposalStatus::Passed);
+
+        let err = execute(
+            deps.as_mut(),
+            env_at(DAY),
+            mock_info(CAROL, &[]),
+            ExecuteMsg::Execute { proposal_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NotPassed { proposal_id: 1 });
+        let err = execute(
+            deps.as_mut(),
+            env_at(4 * DAY),
+            mock_info(CAROL, &[]),
+            ExecuteMsg::Execute { proposal_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TimelockNotExpired {
+                proposal_id: 1,
+                executable_after: Timestamp::from_seconds(5 * DAY),
+            }
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env_at(5 * DAY),
+            mock_info(CAROL, &[]),
+            ExecuteMsg::Execute { proposal_id: 1 },
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].msg, payout());
+        assert_eq!(status_at(&deps, 5 * DAY), ProposalStatus::Executed);
+        let err = execute(
+            deps.as_mut(),
+            env_at(5 * DAY),
+            mock_info(CAROL, &[]),
+            ExecuteMsg::Execute { proposal_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::AlreadyExecuted { proposal_id: 1 });
+    }
+
+    #[test]
+    fn test_voter_adds_nodes_delegated_after_voting() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_nodes(&mut deps, &default_holdings(), 10);
+        propose(&mut deps, ALICE).unwrap();
+        vote(&mut deps, BOB, VoteOption::Yes).unwrap();
+
+        execute(
+            deps.as_mut(),
+            env_at(DAY),
+            mock_info(CAROL, &[]),
+            ExecuteMsg::Delegate {
+                delegate: BOB.to_string(),
+            },
+        )
+        .unwrap();
+        let err = vote(&mut deps, BOB, VoteOption::No).unwrap_err();
+        assert_eq!(err, ContractError::AlreadyVoted { proposal_id: 1 });
+        vote(&mut deps, BOB, VoteOption::Yes).unwrap();
+
+        let proposal = PROPOSALS.load(&deps.storage, 1).unwrap();
+        assert_eq!(proposal.tally.yes, 3);
+        let ballot = BALLOTS
+            .load(&deps.storage, (1, &Addr::unchecked(BOB)))
+            .unwrap();
+        assert_eq!(ballot.power, 3);
+    }
+
+    #[test]
+    fn test_passed_proposal_expires() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_nodes(&mut deps, &default_holdings(), 10);
+        propose(&mut deps, ALICE).unwrap();
+        vote(&mut deps, ALICE, VoteOption::Yes).unwrap();
+        vote(&mut deps, BOB, VoteOption::Yes).unwrap();
+
+        // Executable from day 5 until day 12
+        assert_eq!(status_at(&deps, 12 * DAY - 1), ProposalStatus::Passed);
+        assert_eq!(status_at(&deps, 12 * DAY), ProposalStatus::Expired);
+        let err = execute(
+            deps.as_mut(),
+            env_at(12 * DAY),
+            mock_info(CAROL, &[]),
+            ExecuteMsg::Execute { proposal_id: 1 },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ProposalExpired {
+                proposal_id: 1,
+                expires_at: Timestamp::from_seconds(12 * DAY),
+            }
+        );
+    }
+
+    #[test]
+    fn test_tie_does_not_pass() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_nodes(
+            &mut deps,
+            &[("a1", ALICE), ("a2", ALICE), ("b1", BOB), ("b2", BOB)],
+            4,
+        );
+        propose(&mut deps, ALICE).unwrap();
+        vote(&mut deps, ALICE, VoteOption::Yes).unwrap();
+        vote(&mut deps, BOB, VoteOption::No).unwrap();
+        assert_eq!(status_at(&deps, 3 * DAY), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn test_config_changes_only_through_proposals() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let update = |quorum| ExecuteMsg::UpdateConfig {
+            nft_contract: None,
+            quorum: Some(quorum),
+            threshold: None,
+            voting_period: None,
+            timelock: None,
+            execution_period: None,
+            min_proposal_power: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(ALICE, &[]),
+            update(Decimal::percent(20)),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        let err = execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            update(Decimal::zero()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+        execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            update(Decimal::percent(20)),
+        )
+        .unwrap();
+        assert_eq!(
+            query_config(deps.as_ref()).unwrap().quorum,
+            Decimal::percent(20)
+        );
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::advance;
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{
+        to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+        Order, Response, StdResult, WasmMsg,
+    };
+    use cw_multi_test::{App, ContractWrapper, Executor};
+    use cw_storage_plus::Map;
+
+    use crate::msg::{
+        Cw721QueryMsg, ExecuteMsg, InstantiateMsg, NumTokensResponse, ProposalResponse, QueryMsg,
+        TokensResponse,
+    };
+    use crate::state::{ProposalStatus, VoteOption};
+
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+    const CAROL: &str = "carol";
+    const DAY: u64 = 86_400;
+
+    /// Minimal cw721: minting, transfers and the two queries the DAO uses
+    mod mock_cw721 {
+        use super::*;
+
+        #[cw_serde]
+        pub enum ExecuteMsg {
+            Mint { token_id: String, owner: String },
+            TransferNft { recipient: String, token_id: String },
+        }
+
+        const OWNERS: Map<&str, Addr> = Map::new("owners");
+
+        pub fn instantiate(
+            _deps: DepsMut,
+            _env: Env,
+            _info: MessageInfo,
+            _msg: Empty,
+        ) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        pub fn execute(
+            deps: DepsMut,
+            _env: Env,
+            info: MessageInfo,
+            msg: ExecuteMsg,
+        ) -> StdResult<Response> {
+            match msg {
+                ExecuteMsg::Mint { token_id, owner } => {
+                    OWNERS.save(deps.storage, &token_id, &Addr::unchecked(owner))?;
+                }
+                ExecuteMsg::TransferNft {
+                    recipient,
+                    token_id,
+                } => {
+                    let owner = OWNERS.load(deps.storage, &token_id)?;
+                    if owner != info.sender {
+                        return Err(cosmwasm_std::StdError::generic_err("not owner"));
+                    }
+                    OWNERS.save(deps.storage, &token_id, &Addr::unchecked(recipient))?;
+                }
+            }
+            Ok(Response::new())
+        }
+
+        pub fn query(deps: Deps, _env: Env, msg: Cw721QueryMsg) -> StdResult<Binary> {
+            match msg {
+                Cw721QueryMsg::Tokens {
+                    owner,
+                    start_after,
+                    limit,
+                } => {
+                    let start = start_after
+                        .as_deref()
+                        .map(cw_storage_plus::Bound::exclusive);
+                    let tokens = OWNERS
+                        .range(deps.storage, start, None, Order::Ascending)
+                        .filter(|r| r.as_ref().map_or(true, |(_, o)| o.as_str() == owner))
+                        .take(limit.unwrap_or(10) as usize)
+                        .map(|r| r.map(|(t, _)| t))
+                        .collect::<StdResult<Vec<_>>>()?;
+                    to_json_binary(&TokensResponse { tokens })
+                }
+                Cw721QueryMsg::NumTokens {} => to_json_binary(&NumTokensResponse {
+                    count: OWNERS
+                        .keys(deps.storage, None, None, Order::Ascending)
+                        .count() as u64,
+                }),
+            }
+        }
+    }
+
+    fn cw721_contract() -> Box<dyn cw_multi_test::Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            mock_cw721::execute,
+            mock_cw721::instantiate,
+            mock_cw721::query,
+        );
+        Box::new(contract)
+    }
+
+    fn call(contract: &Addr, msg: &treasury::msg::ExecuteMsg) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(msg).unwrap(),
+            funds: vec![],
+        })
+    }
+
+    /// Proposes, votes yes with Alice and Bob, and waits out voting and timelock
+    fn pass(app: &mut App, dao: &Addr, msgs: Vec<CosmosMsg>) -> u64 {
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            dao.clone(),
+            &ExecuteMsg::Propose {
+                title: "Treasury change".to_string(),
+                description: String::new(),
+                msgs,
+            },
+            &[],
+        )
+        .unwrap();
+        let latest: crate::msg::ProposalsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                dao,
+                &QueryMsg::Proposals {
+                    start_before: None,
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        let proposal_id = latest.proposals[0].id;
+        for voter in [ALICE, BOB] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                dao.clone(),
+                &ExecuteMsg::Vote {
+                    proposal_id,
+                    vote: VoteOption::Yes,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        advance(app, 3 * DAY + 2 * DAY);
+        proposal_id
+    }
+
+    #[test]
+    fn test_dao_governs_treasury() {
+        let mut app = App::default();
+        let alice = Addr::unchecked(ALICE);
+
+        let dao_code = app.store_code(contract_wrapper!(crate));
+        let nft_code = app.store_code(cw721_contract());
+        let treasury_code = app.store_code(contract_wrapper!(treasury));
+
+        let nodes = app
+            .instantiate_contract(nft_code, alice.clone(), &Empty {}, &[], "nodes", None)
+            .unwrap();
+        for (token_id, owner) in [("n1", ALICE), ("n2", ALICE), ("n3", BOB), ("n4", CAROL)] {
+            app.execute_contract(
+                alice.clone(),
+                nodes.clone(),
+                &mock_cw721::ExecuteMsg::Mint {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let dao = app
+            .instantiate_contract(
+                dao_code,
+                alice.clone(),
+                &InstantiateMsg {
+                    nft_contract: nodes.to_string(),
+                    quorum: Decimal::percent(50),
+                    threshold: Decimal::percent(50),
+                    voting_period: 3 * DAY,
+                    timelock: 2 * DAY,
+                    execution_period: 7 * DAY,
+                    min_proposal_power: 1,
+                },
+                &[],
+                "cl8y-dao",
+                None,
+            )
+            .unwrap();
+        let treasury = app
+            .instantiate_contract(
+                treasury_code,
+                alice.clone(),
+                &treasury::msg::InstantiateMsg {
+                    governance: ALICE.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+
+        // Carol delegates; Bob then votes with two nodes
+        app.execute_contract(
+            Addr::unchecked(CAROL),
+            dao.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: BOB.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // The admin hands treasury governance to the DAO, which accepts by vote
+        app.execute_contract(
+            alice.clone(),
+            treasury.clone(),
+            &treasury::msg::ExecuteMsg::ProposeGovernanceTransfer {
+                new_governance: dao.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        advance(&mut app, 7 * DAY);
+        let accept = pass(
+            &mut app,
+            &dao,
+            vec![call(
+                &treasury,
+                &treasury::msg::ExecuteMsg::AcceptGovernanceTransfer {},
+            )],
+        );
+        let proposal: ProposalResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &dao,
+                &QueryMsg::Proposal {
+                    proposal_id: accept,
+                },
+            )
+            .unwrap();
+        assert_eq!(proposal.status, ProposalStatus::Passed);
+        assert_eq!(proposal.tally.yes, 4);
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            dao.clone(),
+            &ExecuteMsg::Execute {
+                proposal_id: accept,
+            },
+            &[],
+        )
+        .unwrap();
+        let config: treasury::msg::ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&treasury, &treasury::msg::QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(config.governance, dao);
+
+        // Passed proposals act on the treasury with the DAO as governance
+        let add = pass(
+            &mut app,
+            &dao,
+            vec![call(
+                &treasury,
+                &treasury::msg::ExecuteMsg::AddCw20 {
+                    contract_addr: "node_token".to_string(),
+                },
+            )],
+        );
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            dao.clone(),
+            &ExecuteMsg::Execute { proposal_id: add },
+            &[],
+        )
+        .unwrap();
+        let whitelist: treasury::msg::Cw20WhitelistResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &treasury,
+                &treasury::msg::QueryMsg::Cw20Whitelist {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(whitelist.addresses, vec![Addr::unchecked("node_token")]);
+    }
+}
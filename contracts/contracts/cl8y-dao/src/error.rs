@@ -0,0 +1,62 @@
+use cosmwasm_std::Timestamp;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] cosmwasm_std::StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Proposal {proposal_id} not found")]
+    ProposalNotFound { proposal_id: u64 },
+
+    #[error("Voting on proposal {proposal_id} has ended")]
+    VotingClosed { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} has not passed")]
+    NotPassed { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} has already been executed")]
+    AlreadyExecuted { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} is not executable until {executable_after}")]
+    TimelockNotExpired {
+        proposal_id: u64,
+        executable_after: Timestamp,
+    },
+
+    #[error("Proposal {proposal_id} expired at {expires_at}")]
+    ProposalExpired {
+        proposal_id: u64,
+        expires_at: Timestamp,
+    },
+
+    #[error("Already voted differently on proposal {proposal_id}")]
+    AlreadyVoted { proposal_id: u64 },
+
+    #[error("No voting power left for this proposal")]
+    NoVotingPower,
+
+    #[error("Insufficient voting power to propose: have {power}, need {required}")]
+    InsufficientProposalPower { power: u64, required: u64 },
+
+    #[error("Proposal must contain at least one message")]
+    EmptyProposal,
+
+    #[error("Invalid title: {reason}")]
+    InvalidTitle { reason: String },
+
+    #[error("Cannot delegate to self")]
+    SelfDelegation,
+
+    #[error("Delegate {delegate} already has the maximum of {max} delegators")]
+    TooManyDelegators { delegate: String, max: u32 },
+
+    #[error("No delegation to remove")]
+    NoDelegation,
+
+    #[error("Invalid parameter: {reason}")]
+    InvalidParameter { reason: String },
+}
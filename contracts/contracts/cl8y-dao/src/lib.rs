@@ -0,0 +1,34 @@
+//! # CL8Y DAO Contract
+//!
+//! Phase 3 governance: proposals voted on by CL8Y node holders, where each
+//! cw721 node is one vote. The DAO is meant to hold `governance` on the
+//! treasury and wrap-mapper and `admin` on ustc-swap, so passed proposals
+//! are lists of `CosmosMsg`s executed with the DAO as sender.
+//!
+//! ## Voting Power
+//!
+//! Ownership is read live from the node collection. Each node can vote once
+//! per proposal, whoever holds it, so moving a node after it has voted does
+//! not add weight. Holders may delegate to another address, which then votes
+//! with their nodes alongside its own. A voter may vote again with the same
+//! option to add nodes that reached them after their first vote.
+//!
+//! ## Lifecycle
+//!
+//! A proposal is open for `voting_period`. It passes if turnout reaches the
+//! quorum (a fraction of all nodes at creation) and yes votes exceed the
+//! threshold share of yes + no. A passed proposal becomes executable after
+//! the post-vote `timelock` and expires if not executed within
+//! `execution_period` after that.
+//!
+//! ## Configuration
+//!
+//! `UpdateConfig` is only accepted from the contract itself, so parameter
+//! changes are themselves proposals.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
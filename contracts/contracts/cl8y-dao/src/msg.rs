@@ -0,0 +1,161 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Timestamp};
+
+use crate::state::{Ballot, ProposalStatus, Tally, VoteOption};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub nft_contract: String,
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub voting_period: u64,
+    pub timelock: u64,
+    pub execution_period: u64,
+    pub min_proposal_power: u64,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Opens a proposal (requires `min_proposal_power`)
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg>,
+    },
+
+    /// Votes with every node the sender holds or is delegated that has not
+    /// yet voted on this proposal. A voter can vote again, with the same
+    /// option, to add nodes received or delegated since their last vote.
+    Vote { proposal_id: u64, vote: VoteOption },
+
+    /// Executes a passed proposal after its timelock and before it expires
+    /// (permissionless)
+    Execute { proposal_id: u64 },
+
+    /// Delegates the sender's nodes to another address
+    Delegate { delegate: String },
+
+    /// Removes the sender's delegation
+    Undelegate {},
+
+    /// Replaces DAO parameters (only via an executed proposal)
+    UpdateConfig {
+        nft_contract: Option<String>,
+        quorum: Option<Decimal>,
+        threshold: Option<Decimal>,
+        voting_period: Option<u64>,
+        timelock: Option<u64>,
+        execution_period: Option<u64>,
+        min_proposal_power: Option<u64>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    #[returns(ProposalResponse)]
+    Proposal { proposal_id: u64 },
+
+    /// Proposals ordered by id, newest first
+    #[returns(ProposalsResponse)]
+    Proposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    #[returns(BallotResponse)]
+    Ballot { proposal_id: u64, voter: String },
+
+    /// Live node count an address would vote with
+    #[returns(VotingPowerResponse)]
+    VotingPower { address: String },
+
+    /// Addresses delegating to `delegate`, ordered by address
+    #[returns(DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub nft_contract: Addr,
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub voting_period: u64,
+    pub timelock: u64,
+    pub execution_period: u64,
+    pub min_proposal_power: u64,
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub proposer: Addr,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg>,
+    pub total_power: u64,
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub voting_end: Timestamp,
+    pub executable_after: Timestamp,
+    pub expires_at: Timestamp,
+    pub tally: Tally,
+    pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+#[cw_serde]
+pub struct BallotResponse {
+    pub ballot: Option<Ballot>,
+}
+
+#[cw_serde]
+pub struct VotingPowerResponse {
+    /// Nodes held, counted only if not delegated away
+    pub own: u64,
+    /// Nodes held by delegators
+    pub delegated: u64,
+    pub total: u64,
+    /// Where the address's own nodes are delegated, if anywhere
+    pub delegate: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct DelegatorsResponse {
+    pub delegators: Vec<Addr>,
+}
+
+/// Subset of the cw721 query interface used to weigh votes
+#[cw_serde]
+pub enum Cw721QueryMsg {
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    NumTokens {},
+}
+
+#[cw_serde]
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
+}
+
+#[cw_serde]
+pub struct NumTokensResponse {
+    pub count: u64,
+}
@@ -0,0 +1,152 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:cl8y-dao";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 1 day in seconds
+pub const MIN_PERIOD: u64 = 86_400;
+
+/// 30 days in seconds
+pub const MAX_PERIOD: u64 = 2_592_000;
+
+/// Maximum number of addresses delegating to one delegate, bounding vote gas
+pub const MAX_DELEGATORS: u32 = 50;
+
+/// Page size when enumerating an owner's nodes
+pub const TOKEN_PAGE_LIMIT: u32 = 100;
+
+/// Maximum title length in bytes
+pub const MAX_TITLE_LENGTH: usize = 128;
+
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    /// CL8Y node collection (cw721)
+    pub nft_contract: Addr,
+    /// Share of all nodes that must vote for a result to count
+    pub quorum: Decimal,
+    /// Share of yes + no that yes must exceed
+    pub threshold: Decimal,
+    /// Seconds a proposal is open for voting
+    pub voting_period: u64,
+    /// Seconds between the end of voting and earliest execution
+    pub timelock: u64,
+    /// Seconds after the timelock during which a passed proposal can be executed
+    pub execution_period: u64,
+    /// Nodes (own plus delegated) needed to create a proposal
+    pub min_proposal_power: u64,
+}
+
+#[cw_serde]
+pub enum VoteOption {
+    Yes,
+    No,
+    Abstain,
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    /// Voting in progress
+    Open,
+    /// Voting ended without quorum or threshold
+    Rejected,
+    /// Voting ended with quorum and threshold; executable after the timelock
+    Passed,
+    /// Passed but not executed before the execution deadline
+    Expired,
+    Executed,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Tally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+impl Tally {
+    pub fn add(&mut self, vote: &VoteOption, power: u64) {
+        match vote {
+            VoteOption::Yes => self.yes += power,
+            VoteOption::No => self.no += power,
+            VoteOption::Abstain => self.abstain += power,
+        }
+    }
+
+    pub fn turnout(&self) -> u64 {
+        self.yes + self.no + self.abstain
+    }
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub proposer: Addr,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg>,
+    /// Node supply when the proposal was created; the quorum base
+    pub total_power: u64,
+    /// Quorum and threshold in force when the proposal was created
+    pub quorum: Decimal,
+    pub threshold: Decimal,
+    pub voting_end: Timestamp,
+    pub executable_after: Timestamp,
+    /// Execution deadline (exclusive)
+    pub expires_at: Timestamp,
+    pub tally: Tally,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub fn status(&self, now: Timestamp) -> ProposalStatus {
+        if self.executed {
+            ProposalStatus::Executed
+        } else if now < self.voting_end {
+            ProposalStatus::Open
+        } else if self.passed() {
+            if now >= self.expires_at {
+                ProposalStatus::Expired
+            } else {
+                ProposalStatus::Passed
+            }
+        } else {
+            ProposalStatus::Rejected
+        }
+    }
+
+    fn passed(&self) -> bool {
+        let tally = &self.tally;
+        let quorum_met =
+            Decimal::from_ratio(tally.turnout(), self.total_power.max(1)) >= self.quorum;
+        let decisive = tally.yes + tally.no;
+        quorum_met && decisive > 0 && Decimal::from_ratio(tally.yes, decisive) > self.threshold
+    }
+}
+
+#[cw_serde]
+pub struct Ballot {
+    pub vote: VoteOption,
+    pub power: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const NEXT_PROPOSAL_ID: Item<u64> = Item::new("next_proposal_id");
+
+/// (proposal_id, voter) -> ballot; `power` grows if the voter votes again
+/// with nodes that came to them later
+pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+
+/// (proposal_id, token_id) -> voter; each node votes once per proposal
+pub const TOKEN_VOTES: Map<(u64, &str), Addr> = Map::new("token_votes");
+
+/// owner -> delegate
+pub const DELEGATIONS: Map<&Addr, Addr> = Map::new("delegations");
+
+/// (delegate, owner) -> (); reverse index of `DELEGATIONS`
+pub const DELEGATORS: Map<(&Addr, &Addr), ()> = Map::new("delegators");
@@ -0,0 +1,1466 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError,
+    StdResult, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
+use cw_storage_plus::Bound;
+
+use common::AssetInfo;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ConfigResponse, CrResponse, CrSnapshotResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    OraclePriceResponse, OracleQueryMsg, PendingGovernanceResponse, PendingPriceEntry,
+    PendingPriceSourceResponse, PendingPricesResponse, PriceEntry, PricesResponse, QueryMsg,
+    TierResponse, TreasuryAllBalancesResponse, TreasuryQueryMsg,
+};
+use crate::state::{
+    asset_key, Config, CrSnapshot, FixedPrice, PendingGovernance, PendingPrice, PendingPriceSource,
+    PriceSource, TierThresholds, CONFIG, CONTRACT_NAME, CONTRACT_VERSION, DEFAULT_QUERY_LIMIT,
+    FIXED_PRICES, GOVERNANCE_TIMELOCK, LAST_SNAPSHOT, MAX_QUERY_LIMIT, PENDING_GOVERNANCE,
+    PENDING_PRICES, PENDING_PRICE_SOURCE,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let governance = deps.api.addr_validate(&msg.governance)?;
+    let treasury = deps.api.addr_validate(&msg.treasury)?;
+    let ust1_token = deps.api.addr_validate(&msg.ust1_token)?;
+    let price_source = validate_price_source(deps.as_ref(), msg.price_source)?;
+
+    let thresholds = msg
+        .thresholds
+        .unwrap_or_else(TierThresholds::default_thresholds);
+    if !thresholds.is_valid() {
+        return Err(ContractError::InvalidThresholds);
+    }
+
+    for entry in msg.prices {
+        if entry.price.is_zero() {
+            return Err(ContractError::ZeroPrice);
+        }
+        FIXED_PRICES.save(
+            deps.storage,
+            &asset_key(&entry.asset),
+            &FixedPrice {
+                asset: entry.asset,
+                price: entry.price,
+            },
+        )?;
+    }
+
+    let config = Config {
+        governance: governance.clone(),
+        treasury: treasury.clone(),
+        ust1_token: ust1_token.clone(),
+        price_source,
+        thresholds,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", governance)
+        .add_attribute("treasury", treasury)
+        .add_attribute("ust1_token", ust1_token))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::RecalculateCr {} => execute_recalculate_cr(deps, env),
+        ExecuteMsg::SetThresholds { thresholds } => execute_set_thresholds(deps, info, thresholds),
+        ExecuteMsg::ProposePriceSource { price_source } => {
+            execute_propose_price_source(deps, env, info, price_source)
+        }
+        ExecuteMsg::AcceptPriceSource {} => execute_accept_price_source(deps, env, info),
+        ExecuteMsg::CancelPriceSource {} => execute_cancel_price_source(deps, info),
+        ExecuteMsg::ProposePrice { asset, price } => {
+            execute_propose_price(deps, env, info, asset, price)
+        }
+        ExecuteMsg::AcceptPrice { asset } => execute_accept_price(deps, env, info, asset),
+        ExecuteMsg::CancelPrice { asset } => execute_cancel_price(deps, info, asset),
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_recalculate_cr(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let current = compute_cr(deps.as_ref(), &config)?;
+
+    let snapshot = CrSnapshot {
+        collateral_value: current.collateral_value,
+        ust1_supply: current.ust1_supply,
+        cr: current.cr,
+        tier: current.tier,
+        timestamp: env.block.time,
+    };
+    LAST_SNAPSHOT.save(deps.storage, &snapshot)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "recalculate_cr")
+        .add_attribute("collateral_value", snapshot.collateral_value)
+        .add_attribute("ust1_supply", snapshot.ust1_supply)
+        .add_attribute("cr", format_cr(snapshot.cr))
+        .add_attribute("tier", snapshot.tier.to_string())
+        .add_attribute("timestamp", snapshot.timestamp.seconds().to_string()))
+}
+
+fn execute_set_thresholds(
+    deps: DepsMut,
+    info: MessageInfo,
+    thresholds: TierThresholds,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+    if !thresholds.is_valid() {
+        return Err(ContractError::InvalidThresholds);
+    }
+
+    config.thresholds = thresholds;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_thresholds")
+        .add_attribute("yellow", config.thresholds.yellow.to_string())
+        .add_attribute("green", config.thresholds.green.to_string())
+        .add_attribute("blue", config.thresholds.blue.to_string()))
+}
+
+fn execute_propose_price_source(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    price_source: PriceSource,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let pending = PendingPriceSource {
+        price_source: validate_price_source(deps.as_ref(), price_source)?,
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_PRICE_SOURCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_price_source")
+        .add_attribute("price_source", price_source_label(&pending.price_source))
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_price_source(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let pending = PENDING_PRICE_SOURCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingPriceSource)?;
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    config.price_source = pending.price_source;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_PRICE_SOURCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_price_source")
+        .add_attribute("price_source", price_source_label(&config.price_source)))
+}
+
+fn execute_cancel_price_source(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_PRICE_SOURCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingPriceSource);
+    }
+    PENDING_PRICE_SOURCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_price_source"))
+}
+
+fn execute_propose_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset: AssetInfo,
+    price: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let key = asset_key(&asset);
+    match price {
+        Some(price) if price.is_zero() => return Err(ContractError::ZeroPrice),
+        None if !FIXED_PRICES.has(deps.storage, &key) => return Err(ContractError::NoFixedPrice),
+        _ => {}
+    }
+
+    let pending = PendingPrice {
+        asset,
+        price,
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_PRICES.save(deps.storage, &key, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_price")
+        .add_attribute("asset", key)
+        .add_attribute("price", format_price(price))
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset: AssetInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let key = asset_key(&asset);
+    let pending = PENDING_PRICES
+        .may_load(deps.storage, &key)?
+        .ok_or(ContractError::NoPendingPrice)?;
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    match pending.price {
+        Some(price) => FIXED_PRICES.save(
+            deps.storage,
+            &key,
+            &FixedPrice {
+                asset: pending.asset,
+                price,
+            },
+        )?,
+        None => FIXED_PRICES.remove(deps.storage, &key),
+    }
+    PENDING_PRICES.remove(deps.storage, &key);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_price")
+        .add_attribute("asset", key)
+        .add_attribute("price", format_price(pending.price)))
+}
+
+fn execute_cancel_price(
+    deps: DepsMut,
+    info: MessageInfo,
+    asset: AssetInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let key = asset_key(&asset);
+    if !PENDING_PRICES.has(deps.storage, &key) {
+        return Err(ContractError::NoPendingPrice);
+    }
+    PENDING_PRICES.remove(deps.storage, &key);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_price")
+        .add_attribute("asset", key))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::CurrentCr {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_json_binary(&compute_cr(deps, &config)?)
+        }
+        QueryMsg::CurrentTier {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let current = compute_cr(deps, &config)?;
+            to_json_binary(&TierResponse {
+                tier: current.tier,
+                cr: current.cr,
+            })
+        }
+        QueryMsg::Thresholds {} => to_json_binary(&CONFIG.load(deps.storage)?.thresholds),
+        QueryMsg::LastSnapshot {} => to_json_binary(&query_last_snapshot(deps)?),
+        QueryMsg::Prices { start_after, limit } => {
+            to_json_binary(&query_prices(deps, start_after, limit)?)
+        }
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+        QueryMsg::PendingPriceSource {} => to_json_binary(&query_pending_price_source(deps)?),
+        QueryMsg::PendingPrices { start_after, limit } => {
+            to_json_binary(&query_pending_prices(deps, start_after, limit)?)
+        }
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        treasury: config.treasury,
+        ust1_token: config.ust1_token,
+        price_source: config.price_source,
+        thresholds: config.thresholds,
+    })
+}
+
+fn query_last_snapshot(deps: Deps) -> StdResult<Option<CrSnapshotResponse>> {
+    Ok(LAST_SNAPSHOT
+        .may_load(deps.storage)?
+        .map(|s| CrSnapshotResponse {
+            collateral_value: s.collateral_value,
+            ust1_supply: s.ust1_supply,
+            cr: s.cr,
+            tier: s.tier,
+            timestamp: s.timestamp,
+        }))
+}
+
+fn query_prices(
+    deps: Deps,
+    start_after: Option<AssetInfo>,
+    limit: Option<u32>,
+) -> StdResult<PricesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_key = start_after.as_ref().map(asset_key);
+    let start = start_key.as_deref().map(Bound::exclusive);
+
+    let prices = FIXED_PRICES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| {
+            r.map(|(_, p)| PriceEntry {
+                asset: p.asset,
+                price: p.price,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PricesResponse { prices })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+fn query_pending_price_source(deps: Deps) -> StdResult<PendingPriceSourceResponse> {
+    let pending = PENDING_PRICE_SOURCE.may_load(deps.storage)?;
+    Ok(PendingPriceSourceResponse {
+        price_source: pending.as_ref().map(|p| p.price_source.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+fn query_pending_prices(
+    deps: Deps,
+    start_after: Option<AssetInfo>,
+    limit: Option<u32>,
+) -> StdResult<PendingPricesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_key = start_after.as_ref().map(asset_key);
+    let start = start_key.as_deref().map(Bound::exclusive);
+
+    let prices = PENDING_PRICES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| {
+            r.map(|(_, p)| PendingPriceEntry {
+                asset: p.asset,
+                price: p.price,
+                execute_after: p.execute_after,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingPricesResponse { prices })
+}
+
+// ============ HELPERS ============
+
+fn validate_price_source(deps: Deps, price_source: PriceSource) -> StdResult<PriceSource> {
+    Ok(match price_source {
+        PriceSource::Fixed {} => PriceSource::Fixed {},
+        PriceSource::Oracle { contract } => PriceSource::Oracle {
+            contract: deps.api.addr_validate(contract.as_str())?,
+        },
+    })
+}
+
+/// Values treasury holdings and divides by UST1 supply
+fn compute_cr(deps: Deps, config: &Config) -> StdResult<CrResponse> {
+    let holdings: TreasuryAllBalancesResponse = deps
+        .querier
+        .query_wasm_smart(&config.treasury, &TreasuryQueryMsg::AllBalances {})?;
+
+    let mut collateral_value = Uint128::zero();
+    let mut unpriced_assets = vec![];
+    for balance in holdings.balances {
+        if balance.amount.is_zero() {
+            continue;
+        }
+        match asset_price(deps, config, &balance.asset)? {
+            Some(price) => {
+                let value = balance
+                    .amount
+                    .checked_mul_floor(price)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                collateral_value = collateral_value.checked_add(value)?;
+            }
+            None => unpriced_assets.push(balance.asset),
+        }
+    }
+
+    let token_info: TokenInfoResponse = deps
+        .querier
+        .query_wasm_smart(&config.ust1_token, &Cw20QueryMsg::TokenInfo {})?;
+    let ust1_supply = token_info.total_supply;
+
+    // A CR too large to represent is saturated; it is BLUE either way
+    let cr = if ust1_supply.is_zero() {
+        None
+    } else {
+        Some(Decimal::checked_from_ratio(collateral_value, ust1_supply).unwrap_or(Decimal::MAX))
+    };
+
+    Ok(CrResponse {
+        collateral_value,
+        ust1_supply,
+        cr,
+        tier: config.thresholds.tier_for(cr),
+        unpriced_assets,
+    })
+}
+
+fn asset_price(deps: Deps, config: &Config, asset: &AssetInfo) -> StdResult<Option<Decimal>> {
+    match &config.price_source {
+        PriceSource::Fixed {} => Ok(FIXED_PRICES
+            .may_load(deps.storage, &asset_key(asset))?
+            .map(|p| p.price)),
+        // A failed price query values the asset at zero, as an unset fixed
+        // price does, so one unpriceable asset cannot block tier lookups
+        PriceSource::Oracle { contract } => Ok(deps
+            .querier
+            .query_wasm_smart::<OraclePriceResponse>(
+                contract,
+                &OracleQueryMsg::Price {
+                    asset: asset.clone(),
+                },
+            )
+            .ok()
+            .map(|res| res.price)),
+    }
+}
+
+fn price_source_label(price_source: &PriceSource) -> String {
+    match price_source {
+        PriceSource::Fixed {} => "fixed".to_string(),
+        PriceSource::Oracle { contract } => contract.to_string(),
+    }
+}
+
+fn format_price(price: Option<Decimal>) -> String {
+    match price {
+        Some(price) => price.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+fn format_cr(cr: Option<Decimal>) -> String {
+    match cr {
+        Some(cr) => cr.to_string(),
+        None => "infinite".to_string(),
+    }
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::CrTier;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        from_json, Addr, ContractResult, OwnedDeps, SystemError, SystemResult, WasmQuery,
+    };
+
+    use crate::msg::TreasuryAssetBalance;
+
+    const GOVERNANCE: &str = "governance";
+    const USER: &str = "user";
+    const TREASURY: &str = "treasury";
+    const UST1: &str = "ust1_token";
+    const ORACLE: &str = "oracle";
+
+    /// 10^10 UST1 units per uusd = $0.01 per USTC
+    const USTC_PRICE: u128 = 10_000_000_000;
+
+    fn uusd() -> AssetInfo {
+        AssetInfo::Native {
+            denom: "uusd".to_string(),
+        }
+    }
+
+    fn uluna() -> AssetInfo {
+        AssetInfo::Native {
+            denom: "uluna".to_string(),
+        }
+    }
+
+    /// Wires treasury AllBalances, UST1 TokenInfo and oracle Price responses
+    fn mock_chain(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        balances: Vec<(AssetInfo, u128)>,
+        ust1_supply: u128,
+        oracle_prices: Vec<(AssetInfo, Decimal)>,
+    ) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                let bin = match contract_addr.as_str() {
+                    TREASURY => to_json_binary(&TreasuryAllBalancesResponse {
+                        balances: balances
+                            .iter()
+                            .map(|(asset, amount)| TreasuryAssetBalance {
+                                asset: asset.clone(),
+                                amount: Uint128::from(*amount),
+                            })
+                            .collect(),
+                    }),
+                    UST1 => to_json_binary(&TokenInfoResponse {
+                        name: "UST1".to_string(),
+                        symbol: "UST1".to_string(),
+                        decimals: 18,
+                        total_supply: Uint128::from(ust1_supply),
+                    }),
+                    ORACLE => {
+                        let OracleQueryMsg::Price { asset } = from_json(msg).unwrap();
+                        match oracle_prices.iter().find(|(a, _)| a == &asset) {
+                            Some((_, price)) => {
+                                to_json_binary(&OraclePriceResponse { price: *price })
+                            }
+                            None => {
+                                return SystemResult::Ok(ContractResult::Err(
+                                    "no price".to_string(),
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return SystemResult::Err(SystemError::NoSuchContract {
+                            addr: contract_addr.clone(),
+                        })
+                    }
+                };
+                SystemResult::Ok(ContractResult::Ok(bin.unwrap()))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "non-smart wasm query".to_string(),
+            }),
+        });
+    }
+
+    fn setup_contract(deps: DepsMut) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            treasury: TREASURY.to_string(),
+            ust1_token: UST1.to_string(),
+            price_source: PriceSource::Fixed {},
+            thresholds: None,
+            prices: vec![],
+        };
+        instantiate(deps, mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    /// Proposes a fixed price and accepts it once the timelock has passed
+    fn set_price(mut deps: DepsMut, asset: AssetInfo, price: Decimal) {
+        let msg = ExecuteMsg::ProposePrice {
+            asset: asset.clone(),
+            price: Some(price),
+        };
+        execute(deps.branch(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK);
+        let msg = ExecuteMsg::AcceptPrice { asset };
+        execute(deps, env, mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    fn current_cr(deps: Deps) -> CrResponse {
+        from_json(query(deps, mock_env(), QueryMsg::CurrentCr {}).unwrap()).unwrap()
+    }
+
+    /// UST1 supply (18 decimals) for a given amount of whole UST1
+    fn ust1(whole: u128) -> u128 {
+        whole * 1_000_000_000_000_000_000
+    }
+
+    // ============ INSTANTIATE TESTS ============
+
+    #[test]
+    fn test_instantiate_default_thresholds() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_json(res).unwrap();
+        assert_eq!(config.governance, Addr::unchecked(GOVERNANCE));
+        assert_eq!(config.treasury, Addr::unchecked(TREASURY));
+        assert_eq!(config.ust1_token, Addr::unchecked(UST1));
+        assert_eq!(config.price_source, PriceSource::Fixed {});
+        assert_eq!(config.thresholds, TierThresholds::default_thresholds());
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Thresholds {}).unwrap();
+        let thresholds: TierThresholds = from_json(res).unwrap();
+        assert_eq!(thresholds, TierThresholds::default_thresholds());
+    }
+
+    #[test]
+    fn test_instantiate_invalid_thresholds() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            treasury: TREASURY.to_string(),
+            ust1_token: UST1.to_string(),
+            price_source: PriceSource::Fixed {},
+            thresholds: Some(TierThresholds {
+                yellow: Decimal::percent(110),
+                green: Decimal::percent(110),
+                blue: Decimal::percent(190),
+            }),
+            prices: vec![],
+        };
+        let err =
+            instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::InvalidThresholds);
+    }
+
+    #[test]
+    fn test_instantiate_initial_prices() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            treasury: TREASURY.to_string(),
+            ust1_token: UST1.to_string(),
+            price_source: PriceSource::Fixed {},
+            thresholds: None,
+            prices: vec![PriceEntry {
+                asset: uusd(),
+                price: Decimal::zero(),
+            }],
+        };
+        let err = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::ZeroPrice);
+
+        let msg = InstantiateMsg {
+            prices: vec![PriceEntry {
+                asset: uusd(),
+                price: Decimal::one(),
+            }],
+            ..msg
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Prices {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: PricesResponse = from_json(res).unwrap();
+        assert_eq!(page.prices, msg.prices);
+    }
+
+    // ============ CR CALCULATION TESTS ============
+
+    #[test]
+    fn test_tier_boundaries() {
+        let t = TierThresholds::default_thresholds();
+        assert_eq!(t.tier_for(Some(Decimal::percent(94))), CrTier::Red);
+        assert_eq!(t.tier_for(Some(Decimal::percent(95))), CrTier::Yellow);
+        assert_eq!(t.tier_for(Some(Decimal::percent(109))), CrTier::Yellow);
+        assert_eq!(t.tier_for(Some(Decimal::percent(110))), CrTier::Green);
+        assert_eq!(t.tier_for(Some(Decimal::percent(190))), CrTier::Green);
+        assert_eq!(t.tier_for(Some(Decimal::percent(191))), CrTier::Blue);
+        assert_eq!(t.tier_for(None), CrTier::Blue);
+    }
+
+    #[test]
+    fn test_current_cr_zero_supply_is_infinite_blue() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        set_price(
+            deps.as_mut(),
+            uusd(),
+            Decimal::from_atomics(USTC_PRICE, 0).unwrap(),
+        );
+        mock_chain(&mut deps, vec![(uusd(), 1_000_000_000)], 0, vec![]);
+
+        let cr = current_cr(deps.as_ref());
+        assert_eq!(cr.cr, None);
+        assert_eq!(cr.tier, CrTier::Blue);
+        // 1000 USTC at $0.01 = $10
+        assert_eq!(cr.collateral_value, Uint128::from(ust1(10)));
+    }
+
+    #[test]
+    fn test_current_cr_fixed_prices_each_tier() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        set_price(
+            deps.as_mut(),
+            uusd(),
+            Decimal::from_atomics(USTC_PRICE, 0).unwrap(),
+        );
+
+        // 10,000 USTC at $0.01 = $100 of collateral
+        let cases = [
+            (ust1(200), CrTier::Red, Decimal::percent(50)),
+            (ust1(100), CrTier::Yellow, Decimal::percent(100)),
+            (ust1(80), CrTier::Green, Decimal::percent(125)),
+            (ust1(50), CrTier::Blue, Decimal::percent(200)),
+        ];
+        for (supply, tier, ratio) in cases {
+            mock_chain(&mut deps, vec![(uusd(), 10_000_000_000)], supply, vec![]);
+            let cr = current_cr(deps.as_ref());
+            assert_eq!(cr.collateral_value, Uint128::from(ust1(100)));
+            assert_eq!(cr.ust1_supply, Uint128::from(supply));
+            assert_eq!(cr.cr, Some(ratio));
+            assert_eq!(cr.tier, tier);
+
+            let res = query(deps.as_ref(), mock_env(), QueryMsg::CurrentTier {}).unwrap();
+            let tier_res: TierResponse = from_json(res).unwrap();
+            assert_eq!(tier_res.tier, tier);
+        }
+    }
+
+    #[test]
+    fn test_current_cr_sums_assets_and_reports_unpriced() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        set_price(
+            deps.as_mut(),
+            uusd(),
+            Decimal::from_atomics(USTC_PRICE, 0).unwrap(),
+        );
+
+        let cw20 = AssetInfo::Cw20 {
+            contract_addr: Addr::unchecked("cw20_token"),
+        };
+        // 18-decimal token at $2
+        set_price(
+            deps.as_mut(),
+            cw20.clone(),
+            Decimal::from_atomics(2u128, 0).unwrap(),
+        );
+
+        mock_chain(
+            &mut deps,
+            vec![
+                (uusd(), 10_000_000_000),
+                (cw20.clone(), ust1(25)),
+                (uluna(), 5_000_000),
+                // Zero balances are neither valued nor reported
+                (
+                    AssetInfo::Native {
+                        denom: "ukrw".to_string(),
+                    },
+                    0,
+                ),
+            ],
+            ust1(100),
+            vec![],
+        );
+
+        let cr = current_cr(deps.as_ref());
+        // $100 USTC + $50 CW20; LUNC unpriced
+        assert_eq!(cr.collateral_value, Uint128::from(ust1(150)));
+        assert_eq!(cr.cr, Some(Decimal::percent(150)));
+        assert_eq!(cr.tier, CrTier::Green);
+        assert_eq!(cr.unpriced_assets, vec![uluna()]);
+    }
+
+    /// Proposes an oracle price source and accepts it once the timelock has passed
+    fn use_oracle(mut deps: DepsMut) {
+        let msg = ExecuteMsg::ProposePriceSource {
+            price_source: PriceSource::Oracle {
+                contract: Addr::unchecked(ORACLE),
+            },
+        };
+        execute(deps.branch(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK);
+        let msg = ExecuteMsg::AcceptPriceSource {};
+        execute(deps, env, mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    #[test]
+    fn test_current_cr_oracle_price_source() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        use_oracle(deps.as_mut());
+
+        mock_chain(
+            &mut deps,
+            vec![(uusd(), 10_000_000_000)],
+            ust1(40),
+            vec![(uusd(), Decimal::from_atomics(USTC_PRICE, 0).unwrap())],
+        );
+        let cr = current_cr(deps.as_ref());
+        assert_eq!(cr.cr, Some(Decimal::percent(250)));
+        assert_eq!(cr.tier, CrTier::Blue);
+        assert!(cr.unpriced_assets.is_empty());
+
+        // An asset the oracle cannot price is valued at zero instead of
+        // failing the whole calculation
+        mock_chain(
+            &mut deps,
+            vec![(uusd(), 10_000_000_000), (uluna(), 1)],
+            ust1(40),
+            vec![(uusd(), Decimal::from_atomics(USTC_PRICE, 0).unwrap())],
+        );
+        let cr = current_cr(deps.as_ref());
+        assert_eq!(cr.cr, Some(Decimal::percent(250)));
+        assert_eq!(cr.unpriced_assets, vec![uluna()]);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::CurrentTier {}).unwrap();
+        let tier: TierResponse = from_json(res).unwrap();
+        assert_eq!(tier.tier, CrTier::Blue);
+    }
+
+    #[test]
+    fn test_price_source_change_is_timelocked() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let oracle = PriceSource::Oracle {
+            contract: Addr::unchecked(ORACLE),
+        };
+        let msg = ExecuteMsg::ProposePriceSource {
+            price_source: oracle.clone(),
+        };
+        let err =
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &[]), msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::PendingPriceSource {}).unwrap();
+        let pending: PendingPriceSourceResponse = from_json(res).unwrap();
+        assert_eq!(pending.price_source, Some(oracle.clone()));
+        assert_eq!(
+            pending.execute_after,
+            Some(mock_env().block.time.plus_seconds(GOVERNANCE_TIMELOCK))
+        );
+
+        // Not applied until the timelock has passed
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::AcceptPriceSource {},
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TimelockNotExpired {
+                remaining_seconds: GOVERNANCE_TIMELOCK
+            }
+        );
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap().price_source,
+            PriceSource::Fixed {}
+        );
+
+        // Cancelled proposals cannot be accepted
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::CancelPriceSource {},
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK);
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::AcceptPriceSource {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoPendingPriceSource);
+
+        use_oracle(deps.as_mut());
+        assert_eq!(CONFIG.load(&deps.storage).unwrap().price_source, oracle);
+        assert!(PENDING_PRICE_SOURCE
+            .may_load(&deps.storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_recalculate_cr_stores_snapshot() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        set_price(
+            deps.as_mut(),
+            uusd(),
+            Decimal::from_atomics(USTC_PRICE, 0).unwrap(),
+        );
+        mock_chain(&mut deps, vec![(uusd(), 10_000_000_000)], ust1(80), vec![]);
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LastSnapshot {}).unwrap();
+        let snapshot: Option<CrSnapshotResponse> = from_json(res).unwrap();
+        assert!(snapshot.is_none());
+
+        // Anyone can recalculate
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(123);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            ExecuteMsg::RecalculateCr {},
+        )
+        .unwrap();
+        assert_eq!(res.attributes[0].value, "recalculate_cr");
+        assert_eq!(res.attributes[3].value, "1.25");
+        assert_eq!(res.attributes[4].value, "green");
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::LastSnapshot {}).unwrap();
+        let snapshot: Option<CrSnapshotResponse> = from_json(res).unwrap();
+        let snapshot = snapshot.unwrap();
+        assert_eq!(snapshot.cr, Some(Decimal::percent(125)));
+        assert_eq!(snapshot.tier, CrTier::Green);
+        assert_eq!(snapshot.collateral_value, Uint128::from(ust1(100)));
+        assert_eq!(snapshot.ust1_supply, Uint128::from(ust1(80)));
+        assert_eq!(snapshot.timestamp, env.block.time);
+    }
+
+    // ============ GOVERNANCE PARAMETER TESTS ============
+
+    #[test]
+    fn test_set_thresholds() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+        set_price(
+            deps.as_mut(),
+            uusd(),
+            Decimal::from_atomics(USTC_PRICE, 0).unwrap(),
+        );
+        mock_chain(&mut deps, vec![(uusd(), 10_000_000_000)], ust1(50), vec![]);
+        assert_eq!(current_cr(deps.as_ref()).tier, CrTier::Blue);
+
+        let thresholds = TierThresholds {
+            yellow: Decimal::percent(100),
+            green: Decimal::percent(150),
+            blue: Decimal::percent(250),
+        };
+
+        let msg = ExecuteMsg::SetThresholds {
+            thresholds: thresholds.clone(),
+        };
+        let err =
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &[]), msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        // 200% is GREEN under the new thresholds
+        assert_eq!(current_cr(deps.as_ref()).tier, CrTier::Green);
+
+        let msg = ExecuteMsg::SetThresholds {
+            thresholds: TierThresholds {
+                yellow: Decimal::zero(),
+                green: Decimal::percent(150),
+                blue: Decimal::percent(250),
+            },
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::InvalidThresholds);
+    }
+
+    #[test]
+    fn test_price_change_is_timelocked() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let msg = ExecuteMsg::ProposePrice {
+            asset: uusd(),
+            price: Some(Decimal::one()),
+        };
+        let err =
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &[]), msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        let zero = ExecuteMsg::ProposePrice {
+            asset: uusd(),
+            price: Some(Decimal::zero()),
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), zero).unwrap_err();
+        assert_eq!(err, ContractError::ZeroPrice);
+
+        let remove = ExecuteMsg::ProposePrice {
+            asset: uusd(),
+            price: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            remove.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoFixedPrice);
+
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PendingPrices {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let pending: PendingPricesResponse = from_json(res).unwrap();
+        assert_eq!(
+            pending.prices,
+            vec![PendingPriceEntry {
+                asset: uusd(),
+                price: Some(Decimal::one()),
+                execute_after: mock_env().block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+            }]
+        );
+
+        // Not applied until the timelock has passed
+        let accept = ExecuteMsg::AcceptPrice { asset: uusd() };
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(100);
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info(GOVERNANCE, &[]),
+            accept.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TimelockNotExpired {
+                remaining_seconds: GOVERNANCE_TIMELOCK - 100
+            }
+        );
+        assert!(FIXED_PRICES
+            .may_load(&deps.storage, &asset_key(&uusd()))
+            .unwrap()
+            .is_none());
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            accept.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(GOVERNANCE, &[]),
+            accept.clone(),
+        )
+        .unwrap();
+        assert!(PENDING_PRICES
+            .may_load(&deps.storage, &asset_key(&uusd()))
+            .unwrap()
+            .is_none());
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info(GOVERNANCE, &[]),
+            accept.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoPendingPrice);
+
+        set_price(deps.as_mut(), uluna(), Decimal::percent(5));
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Prices {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+        let page: PricesResponse = from_json(res).unwrap();
+        assert_eq!(page.prices.len(), 1);
+        assert_eq!(page.prices[0].asset, uluna());
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Prices {
+                start_after: Some(uluna()),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: PricesResponse = from_json(res).unwrap();
+        assert_eq!(
+            page.prices,
+            vec![PriceEntry {
+                asset: uusd(),
+                price: Decimal::one(),
+            }]
+        );
+
+        // Cancelled removals cannot be accepted
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            remove.clone(),
+        )
+        .unwrap();
+        let cancel = ExecuteMsg::CancelPrice { asset: uusd() };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            cancel.clone(),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            cancel,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoPendingPrice);
+
+        // Removal also waits out the timelock
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            remove,
+        )
+        .unwrap();
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK);
+        execute(deps.as_mut(), env, mock_info(GOVERNANCE, &[]), accept).unwrap();
+        assert!(FIXED_PRICES
+            .may_load(&deps.storage, &asset_key(&uusd()))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_governance_transfer() {
+        let mut deps = mock_dependencies();
+        setup_contract(deps.as_mut());
+
+        let msg = ExecuteMsg::ProposeGovernanceTransfer {
+            new_governance: "new_gov".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_gov", &[]),
+            ExecuteMsg::AcceptGovernanceTransfer {},
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TimelockNotExpired {
+                remaining_seconds: GOVERNANCE_TIMELOCK
+            }
+        );
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK);
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("new_gov", &[]),
+            ExecuteMsg::AcceptGovernanceTransfer {},
+        )
+        .unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(config.governance, Addr::unchecked("new_gov"));
+        assert!(PENDING_GOVERNANCE
+            .may_load(&deps.storage)
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{cw20_contract, instantiate_cw20};
+    use common::{AssetInfo, CrTier};
+    use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+    use cw20::Cw20Coin;
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{CrResponse, InstantiateMsg, PriceEntry, QueryMsg};
+    use crate::state::PriceSource;
+
+    const GOVERNANCE: &str = "governance";
+    const HOLDER: &str = "holder";
+
+    #[test]
+    fn test_cr_from_live_treasury_and_ust1_supply() {
+        let mut app = App::default();
+
+        let treasury_code = app.store_code(contract_wrapper!(treasury));
+        let treasury_addr = app
+            .instantiate_contract(
+                treasury_code,
+                Addr::unchecked(GOVERNANCE),
+                &treasury::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+
+        // 10,000 USTC held by the treasury
+        app.init_modules(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &treasury_addr,
+                    vec![Coin::new(10_000_000_000u128, "uusd")],
+                )
+                .unwrap();
+        });
+
+        // 80 UST1 outstanding
+        let cw20_code = app.store_code(cw20_contract());
+        let ust1_addr = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTONE",
+            vec![Cw20Coin {
+                address: HOLDER.to_string(),
+                amount: Uint128::from(80_000_000_000_000_000_000u128),
+            }],
+        );
+
+        let engine_code = app.store_code(contract_wrapper!(crate));
+        let engine_addr = app
+            .instantiate_contract(
+                engine_code,
+                Addr::unchecked(GOVERNANCE),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    treasury: treasury_addr.to_string(),
+                    ust1_token: ust1_addr.to_string(),
+                    price_source: PriceSource::Fixed {},
+                    thresholds: None,
+                    prices: vec![PriceEntry {
+                        asset: AssetInfo::Native {
+                            denom: "uusd".to_string(),
+                        },
+                        price: Decimal::from_atomics(10_000_000_000u128, 0).unwrap(),
+                    }],
+                },
+                &[],
+                "cr-engine",
+                None,
+            )
+            .unwrap();
+
+        let cr: CrResponse = app
+            .wrap()
+            .query_wasm_smart(engine_addr, &QueryMsg::CurrentCr {})
+            .unwrap();
+        assert_eq!(
+            cr.collateral_value,
+            Uint128::from(100_000_000_000_000_000_000u128)
+        );
+        assert_eq!(cr.cr, Some(Decimal::percent(125)));
+        assert_eq!(cr.tier, CrTier::Green);
+    }
+}
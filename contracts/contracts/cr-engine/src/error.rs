@@ -0,0 +1,34 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("No pending price source change")]
+    NoPendingPriceSource,
+
+    #[error("No pending price change for asset")]
+    NoPendingPrice,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+
+    #[error(
+        "Invalid tier thresholds: must be non-zero and strictly increasing (yellow < green < blue)"
+    )]
+    InvalidThresholds,
+
+    #[error("Price must be greater than zero")]
+    ZeroPrice,
+
+    #[error("No fixed price set for asset")]
+    NoFixedPrice,
+}
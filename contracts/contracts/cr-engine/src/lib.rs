@@ -0,0 +1,29 @@
+//! # CR Engine Contract
+//!
+//! Computes the UST1 collateralization ratio (CR) and maps it to a tier.
+//!
+//! ## Calculation
+//!
+//! 1. Query treasury holdings via `AllBalances` (native + whitelisted CW20s)
+//! 2. Value each asset through the configured price source (fixed governance
+//!    prices or an external oracle contract)
+//! 3. Divide total collateral value by UST1 total supply
+//!
+//! With zero UST1 supply the CR is infinite and the tier is BLUE.
+//!
+//! ## Tiers (default thresholds, governance-adjustable)
+//!
+//! - RED: CR < 95%
+//! - YELLOW: 95% <= CR < 110%
+//! - GREEN: 110% <= CR <= 190%
+//! - BLUE: CR > 190%
+//!
+//! `RecalculateCr` is permissionless and stores a timestamped snapshot that
+//! other contracts (UST1 controller, BLUE minter) can rely on.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,211 @@
+use common::{AssetInfo, CrTier};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+
+use crate::state::{PriceSource, TierThresholds};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub treasury: String,
+    pub ust1_token: String,
+    pub price_source: PriceSource,
+    /// Defaults to 95% / 110% / 190% if not provided
+    pub thresholds: Option<TierThresholds>,
+    /// Initial fixed prices; later changes go through `ProposePrice`
+    #[serde(default)]
+    pub prices: Vec<PriceEntry>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Recomputes the CR and stores a timestamped snapshot (permissionless)
+    RecalculateCr {},
+
+    /// Updates tier thresholds (governance-only)
+    SetThresholds { thresholds: TierThresholds },
+
+    /// Proposes switching between fixed prices and an oracle contract with the
+    /// 7-day governance timelock (governance-only). Replaces any pending proposal.
+    ProposePriceSource { price_source: PriceSource },
+
+    /// Applies the pending price source after its timelock (governance-only)
+    AcceptPriceSource {},
+
+    /// Cancels the pending price source change (governance-only)
+    CancelPriceSource {},
+
+    /// Proposes setting (`Some`) or removing (`None`) the fixed price of an
+    /// asset with the 7-day governance timelock (governance-only). Replaces any
+    /// pending change for the same asset.
+    ProposePrice {
+        asset: AssetInfo,
+        price: Option<Decimal>,
+    },
+
+    /// Applies the pending price change for an asset after its timelock (governance-only)
+    AcceptPrice { asset: AssetInfo },
+
+    /// Cancels the pending price change for an asset (governance-only)
+    CancelPrice { asset: AssetInfo },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    /// Live CR computed from current treasury balances, prices and UST1 supply
+    #[returns(CrResponse)]
+    CurrentCr {},
+
+    /// Live tier computed from the current CR
+    #[returns(TierResponse)]
+    CurrentTier {},
+
+    /// Tier thresholds; lets other contracts key off the BLUE threshold
+    #[returns(TierThresholds)]
+    Thresholds {},
+
+    /// Snapshot stored by the last RecalculateCr (None if never called)
+    #[returns(Option<CrSnapshotResponse>)]
+    LastSnapshot {},
+
+    /// Fixed prices, ordered by asset key
+    #[returns(PricesResponse)]
+    Prices {
+        start_after: Option<AssetInfo>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+
+    #[returns(PendingPriceSourceResponse)]
+    PendingPriceSource {},
+
+    /// Fixed price changes waiting out the timelock, ordered by asset key
+    #[returns(PendingPricesResponse)]
+    PendingPrices {
+        start_after: Option<AssetInfo>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub treasury: Addr,
+    pub ust1_token: Addr,
+    pub price_source: PriceSource,
+    pub thresholds: TierThresholds,
+}
+
+#[cw_serde]
+pub struct CrResponse {
+    /// Total collateral value in UST1 base units
+    pub collateral_value: Uint128,
+    pub ust1_supply: Uint128,
+    /// None when UST1 supply is zero (infinite CR)
+    pub cr: Option<Decimal>,
+    pub tier: CrTier,
+    /// Treasury assets with a balance but no fixed price, or that the oracle
+    /// failed to price (valued at zero)
+    pub unpriced_assets: Vec<AssetInfo>,
+}
+
+#[cw_serde]
+pub struct TierResponse {
+    pub tier: CrTier,
+    pub cr: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct CrSnapshotResponse {
+    pub collateral_value: Uint128,
+    pub ust1_supply: Uint128,
+    pub cr: Option<Decimal>,
+    pub tier: CrTier,
+    pub timestamp: Timestamp,
+}
+
+#[cw_serde]
+pub struct PriceEntry {
+    pub asset: AssetInfo,
+    pub price: Decimal,
+}
+
+#[cw_serde]
+pub struct PricesResponse {
+    pub prices: Vec<PriceEntry>,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct PendingPriceSourceResponse {
+    pub price_source: Option<PriceSource>,
+    pub execute_after: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct PendingPriceEntry {
+    pub asset: AssetInfo,
+    /// None if the change removes the fixed price
+    pub price: Option<Decimal>,
+    pub execute_after: Timestamp,
+}
+
+#[cw_serde]
+pub struct PendingPricesResponse {
+    pub prices: Vec<PendingPriceEntry>,
+}
+
+/// Query sent to the treasury for its holdings
+#[cw_serde]
+pub enum TreasuryQueryMsg {
+    AllBalances {},
+}
+
+/// Mirrors the treasury's AllBalancesResponse
+#[cw_serde]
+pub struct TreasuryAllBalancesResponse {
+    pub balances: Vec<TreasuryAssetBalance>,
+}
+
+#[cw_serde]
+pub struct TreasuryAssetBalance {
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+}
+
+/// Query interface expected from an oracle price source
+#[cw_serde]
+pub enum OracleQueryMsg {
+    /// Value of one base unit of `asset` in UST1 base units
+    Price { asset: AssetInfo },
+}
+
+#[cw_serde]
+pub struct OraclePriceResponse {
+    pub price: Decimal,
+}
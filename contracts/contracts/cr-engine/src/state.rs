@@ -0,0 +1,137 @@
+use common::{AssetInfo, CrTier};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:cr-engine";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// Default page size for the Prices and PendingPrices queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for the Prices and PendingPrices queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    /// Treasury queried for `AllBalances`
+    pub treasury: Addr,
+    /// UST1 CW20 token (total supply is the CR denominator)
+    pub ust1_token: Addr,
+    pub price_source: PriceSource,
+    pub thresholds: TierThresholds,
+}
+
+/// Where asset prices come from.
+///
+/// Prices are quoted as the value of one base unit of the asset in UST1 base
+/// units (18 decimals, $1 target). For example USTC at $0.01 is
+/// 0.01 * 10^18 / 10^6 = 10^10 UST1 units per uusd.
+#[cw_serde]
+pub enum PriceSource {
+    /// Governance-set prices stored in this contract; unpriced assets are valued at zero
+    Fixed {},
+    /// External oracle answering `OracleQueryMsg::Price { asset }`; assets the
+    /// oracle fails to price are valued at zero
+    Oracle { contract: Addr },
+}
+
+/// Lower CR bounds of the YELLOW, GREEN and BLUE tiers.
+/// RED: cr < yellow, YELLOW: yellow <= cr < green, GREEN: green <= cr <= blue, BLUE: cr > blue
+#[cw_serde]
+pub struct TierThresholds {
+    pub yellow: Decimal,
+    pub green: Decimal,
+    pub blue: Decimal,
+}
+
+impl TierThresholds {
+    /// 95% / 110% / 190% as specified in PROPOSAL.md
+    pub fn default_thresholds() -> Self {
+        TierThresholds {
+            yellow: Decimal::percent(95),
+            green: Decimal::percent(110),
+            blue: Decimal::percent(190),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.yellow.is_zero() && self.yellow < self.green && self.green < self.blue
+    }
+
+    /// Tier for a CR; `None` means infinite (zero UST1 supply)
+    pub fn tier_for(&self, cr: Option<Decimal>) -> CrTier {
+        match cr {
+            None => CrTier::Blue,
+            Some(cr) if cr < self.yellow => CrTier::Red,
+            Some(cr) if cr < self.green => CrTier::Yellow,
+            Some(cr) if cr <= self.blue => CrTier::Green,
+            Some(_) => CrTier::Blue,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+/// Price source change waiting out GOVERNANCE_TIMELOCK
+#[cw_serde]
+pub struct PendingPriceSource {
+    pub price_source: PriceSource,
+    pub execute_after: Timestamp,
+}
+
+/// CR stored by the last `RecalculateCr`
+#[cw_serde]
+pub struct CrSnapshot {
+    pub collateral_value: Uint128,
+    pub ust1_supply: Uint128,
+    /// None when UST1 supply is zero (infinite CR)
+    pub cr: Option<Decimal>,
+    pub tier: CrTier,
+    pub timestamp: Timestamp,
+}
+
+#[cw_serde]
+pub struct FixedPrice {
+    pub asset: AssetInfo,
+    pub price: Decimal,
+}
+
+/// Fixed price change waiting out GOVERNANCE_TIMELOCK
+#[cw_serde]
+pub struct PendingPrice {
+    pub asset: AssetInfo,
+    /// None removes the fixed price
+    pub price: Option<Decimal>,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const PENDING_PRICE_SOURCE: Item<PendingPriceSource> = Item::new("pending_price_source");
+
+/// Snapshot written by the last RecalculateCr
+pub const LAST_SNAPSHOT: Item<CrSnapshot> = Item::new("last_snapshot");
+
+/// Fixed prices used by `PriceSource::Fixed`
+/// Key: asset key ("native:<denom>" or "cw20:<addr>")
+pub const FIXED_PRICES: Map<&str, FixedPrice> = Map::new("fixed_prices");
+
+/// Proposed fixed price changes, at most one per asset
+/// Key: asset key, as in FIXED_PRICES
+pub const PENDING_PRICES: Map<&str, PendingPrice> = Map::new("pending_prices");
+
+/// Storage key for an asset in FIXED_PRICES and PENDING_PRICES
+pub fn asset_key(asset: &AssetInfo) -> String {
+    match asset {
+        AssetInfo::Native { denom } => format!("native:{}", denom),
+        AssetInfo::Cw20 { contract_addr } => format!("cw20:{}", contract_addr),
+    }
+}
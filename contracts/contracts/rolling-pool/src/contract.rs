@@ -0,0 +1,829 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, CosmosMsg, Decimal256, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+
+use crate::error::ContractError;
+use crate::msg::{
+    BeneficiaryMsg, ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    PendingDistributionResponse, PendingGovernanceResponse, PoolStateResponse, QueryMsg,
+    ReceiveMsg,
+};
+use crate::state::{
+    emission, Beneficiary, Config, PendingGovernance, PoolState, CONFIG, CONTRACT_NAME,
+    CONTRACT_VERSION, DISTRIBUTION_PERIOD, GOVERNANCE_TIMELOCK, PENDING_GOVERNANCE, POOL_STATE,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        ust1_token: deps.api.addr_validate(&msg.ust1_token)?,
+        beneficiary: validate_beneficiary(deps.as_ref(), msg.beneficiary)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    POOL_STATE.save(
+        deps.storage,
+        &PoolState {
+            streaming_balance: Uint128::zero(),
+            accrued: Uint128::zero(),
+            last_update: env.block.time,
+            total_distributed: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("ust1_token", config.ust1_token))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Accrue {} => execute_accrue(deps, env),
+        ExecuteMsg::Drip {} => execute_drip(deps, env),
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, env, info, cw20_msg),
+        ExecuteMsg::SetBeneficiary { beneficiary } => {
+            execute_set_beneficiary(deps, env, info, beneficiary)
+        }
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_accrue(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let (state, emitted) = accrue(deps, &env, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "accrue")
+        .add_attribute("emitted", emitted)
+        .add_attribute("accrued", state.accrued)
+        .add_attribute("streaming_balance", state.streaming_balance))
+}
+
+fn execute_drip(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let beneficiary = config
+        .beneficiary
+        .clone()
+        .ok_or(ContractError::NoBeneficiary)?;
+    let (mut state, emitted) = accrue(deps.branch(), &env, &config)?;
+
+    let amount = state.accrued;
+    let mut response = Response::new()
+        .add_attribute("action", "drip")
+        .add_attribute("beneficiary", &beneficiary.address)
+        .add_attribute("emitted", emitted)
+        .add_attribute("amount", amount)
+        .add_attribute("streaming_balance", state.streaming_balance);
+
+    // Callers such as staking contracts drip on every action; nothing to send is not an error
+    if amount.is_zero() {
+        return Ok(response);
+    }
+
+    state.accrued = Uint128::zero();
+    state.total_distributed = state
+        .total_distributed
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    POOL_STATE.save(deps.storage, &state)?;
+
+    let cw20_msg = match beneficiary.hook_msg {
+        Some(msg) => Cw20ExecuteMsg::Send {
+            contract: beneficiary.address.to_string(),
+            amount,
+            msg,
+        },
+        None => Cw20ExecuteMsg::Transfer {
+            recipient: beneficiary.address.to_string(),
+            amount,
+        },
+    };
+    response = response.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&cw20_msg)?,
+        funds: vec![],
+    }));
+
+    Ok(response)
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.ust1_token {
+        return Err(ContractError::UnauthorizedToken);
+    }
+    if cw20_msg.amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    match from_json(&cw20_msg.msg)? {
+        ReceiveMsg::Deposit {} => {
+            // The token credits the pool before calling the hook, so accrue
+            // picks the deposit up in the new rate
+            let (state, emitted) = accrue(deps, &env, &config)?;
+            Ok(Response::new()
+                .add_attribute("action", "deposit")
+                .add_attribute("depositor", cw20_msg.sender)
+                .add_attribute("amount", cw20_msg.amount)
+                .add_attribute("emitted", emitted)
+                .add_attribute("streaming_balance", state.streaming_balance))
+        }
+    }
+}
+
+fn execute_set_beneficiary(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    beneficiary: Option<BeneficiaryMsg>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    accrue(deps.branch(), &env, &config)?;
+    config.beneficiary = validate_beneficiary(deps.as_ref(), beneficiary)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    let address = config
+        .beneficiary
+        .map(|b| b.address.to_string())
+        .unwrap_or_else(|| "none".to_string());
+    Ok(Response::new()
+        .add_attribute("action", "set_beneficiary")
+        .add_attribute("beneficiary", address))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::PoolState {} => to_json_binary(&query_pool_state(deps)?),
+        QueryMsg::PendingDistribution { start, end } => {
+            to_json_binary(&query_pending_distribution(deps, env, start, end)?)
+        }
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (beneficiary, hook_msg) = match config.beneficiary {
+        Some(b) => (Some(b.address), b.hook_msg),
+        None => (None, None),
+    };
+    Ok(ConfigResponse {
+        governance: config.governance,
+        ust1_token: config.ust1_token,
+        beneficiary,
+        hook_msg,
+    })
+}
+
+fn query_pool_state(deps: Deps) -> StdResult<PoolStateResponse> {
+    let state = POOL_STATE.load(deps.storage)?;
+    Ok(PoolStateResponse {
+        rate_per_second: rate_per_second(state.streaming_balance),
+        streaming_balance: state.streaming_balance,
+        accrued: state.accrued,
+        last_update: state.last_update,
+        total_distributed: state.total_distributed,
+    })
+}
+
+/// Splits the window at `now`: before it the stored rate applies, after it
+/// the rate `Accrue` would set now.
+fn query_pending_distribution(
+    deps: Deps,
+    env: Env,
+    start: Option<u64>,
+    end: u64,
+) -> StdResult<PendingDistributionResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let state = POOL_STATE.load(deps.storage)?;
+    let last = state.last_update.seconds();
+    let now = env.block.time.seconds().max(last);
+
+    let start = start.unwrap_or(now).max(last);
+    if end < start {
+        return Err(StdError::generic_err(format!(
+            "Invalid window: end {} is before start {}",
+            end, start
+        )));
+    }
+
+    // Rate as of now
+    let accrued_now = state.accrued + state.emitted_until(env.block.time);
+    let balance = ust1_balance(deps, &env, &config)?;
+    let live_balance = balance.saturating_sub(accrued_now);
+
+    let past_end = end.min(now);
+    let past_start = start.min(now);
+    let past = emission(state.streaming_balance, past_end - last)
+        - emission(state.streaming_balance, past_start - last);
+    let future_start = start.max(now);
+    let future =
+        emission(live_balance, end.max(now) - now) - emission(live_balance, future_start - now);
+
+    let accrued_before_start = state.accrued
+        + emission(state.streaming_balance, past_start - last)
+        + emission(live_balance, future_start - now);
+
+    Ok(PendingDistributionResponse {
+        start,
+        end,
+        amount: past + future,
+        accrued_before_start,
+        rate_per_second: rate_per_second(live_balance),
+    })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+/// Moves emissions since the last update into `accrued` and recomputes the
+/// streaming balance from the live UST1 balance. Returns the new state and
+/// the amount emitted.
+fn accrue(deps: DepsMut, env: &Env, config: &Config) -> StdResult<(PoolState, Uint128)> {
+    let mut state = POOL_STATE.load(deps.storage)?;
+    let emitted = state.emitted_until(env.block.time);
+    state.accrued = state.accrued.checked_add(emitted)?;
+
+    let balance = ust1_balance(deps.as_ref(), env, config)?;
+    state.streaming_balance = balance.saturating_sub(state.accrued);
+    state.last_update = state.last_update.max(env.block.time);
+    POOL_STATE.save(deps.storage, &state)?;
+
+    Ok((state, emitted))
+}
+
+fn ust1_balance(deps: Deps, env: &Env, config: &Config) -> StdResult<Uint128> {
+    let res: BalanceResponse = deps.querier.query_wasm_smart(
+        &config.ust1_token,
+        &Cw20QueryMsg::Balance {
+            address: env.contract.address.to_string(),
+        },
+    )?;
+    Ok(res.balance)
+}
+
+fn rate_per_second(streaming_balance: Uint128) -> Decimal256 {
+    Decimal256::from_ratio(streaming_balance, DISTRIBUTION_PERIOD)
+}
+
+fn validate_beneficiary(
+    deps: Deps,
+    beneficiary: Option<BeneficiaryMsg>,
+) -> StdResult<Option<Beneficiary>> {
+    beneficiary
+        .map(|b| {
+            Ok(Beneficiary {
+                address: deps.api.addr_validate(&b.address)?,
+                hook_msg: b.hook_msg,
+            })
+        })
+        .transpose()
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{ContractResult, OwnedDeps, SystemResult, WasmQuery};
+
+    const GOVERNANCE: &str = "governance";
+    const UST1: &str = "ust1_token";
+    const STAKING: &str = "staking";
+    const ALICE: &str = "alice";
+
+    /// One year in seconds, a fifth of the distribution period
+    const YEAR: u64 = DISTRIBUTION_PERIOD / 5;
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn setup_contract(deps: &mut MockDeps, beneficiary: Option<BeneficiaryMsg>) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ust1_token: UST1.to_string(),
+            beneficiary,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    fn staking_beneficiary(hook_msg: Option<Binary>) -> Option<BeneficiaryMsg> {
+        Some(BeneficiaryMsg {
+            address: STAKING.to_string(),
+            hook_msg,
+        })
+    }
+
+    fn set_balance(deps: &mut MockDeps, balance: u128) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == UST1 => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&BalanceResponse {
+                        balance: Uint128::new(balance),
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => panic!("unexpected query"),
+        });
+    }
+
+    fn env_at(seconds_after_start: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds_after_start);
+        env
+    }
+
+    fn pool_state(deps: &MockDeps) -> PoolStateResponse {
+        query_pool_state(deps.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_accrue_streams_a_fifth_per_year() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        set_balance(&mut deps, 5_000);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Accrue {},
+        )
+        .unwrap();
+        let state = pool_state(&deps);
+        assert_eq!(state.streaming_balance, Uint128::new(5_000));
+        assert_eq!(
+            state.rate_per_second,
+            Decimal256::from_ratio(5_000u128, DISTRIBUTION_PERIOD)
+        );
+
+        execute(
+            deps.as_mut(),
+            env_at(YEAR),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Accrue {},
+        )
+        .unwrap();
+        let state = pool_state(&deps);
+        assert_eq!(state.accrued, Uint128::new(1_000));
+        assert_eq!(state.streaming_balance, Uint128::new(4_000));
+        assert_eq!(state.last_update, env_at(YEAR).block.time);
+    }
+
+    #[test]
+    fn test_deposit_recomputes_rate() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        set_balance(&mut deps, 5_000);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Accrue {},
+        )
+        .unwrap();
+
+        // The token credits the pool before the hook runs
+        set_balance(&mut deps, 10_000);
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: ALICE.to_string(),
+            amount: Uint128::new(5_000),
+            msg: to_json_binary(&ReceiveMsg::Deposit {}).unwrap(),
+        });
+        execute(
+            deps.as_mut(),
+            env_at(YEAR),
+            mock_info(UST1, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        let state = pool_state(&deps);
+        assert_eq!(state.accrued, Uint128::new(1_000));
+        assert_eq!(state.streaming_balance, Uint128::new(9_000));
+
+        // Only the UST1 token may deposit
+        let err = execute(deps.as_mut(), env_at(YEAR), mock_info(ALICE, &[]), msg).unwrap_err();
+        assert_eq!(err, ContractError::UnauthorizedToken);
+    }
+
+    #[test]
+    fn test_drip_transfers_or_sends_with_hook() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        set_balance(&mut deps, 5_000);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Drip {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoBeneficiary);
+
+        let msg = ExecuteMsg::SetBeneficiary {
+            beneficiary: staking_beneficiary(None),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env_at(YEAR),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Drip {},
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, UST1);
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: STAKING.to_string(),
+                        amount: Uint128::new(1_000),
+                    }
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+        let state = pool_state(&deps);
+        assert_eq!(state.accrued, Uint128::zero());
+        assert_eq!(state.total_distributed, Uint128::new(1_000));
+
+        // Switch to a hook; the transfer has left the pool
+        set_balance(&mut deps, 4_000);
+        let hook = to_json_binary(&"distribute").unwrap();
+        execute(
+            deps.as_mut(),
+            env_at(YEAR),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::SetBeneficiary {
+                beneficiary: staking_beneficiary(Some(hook.clone())),
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env_at(2 * YEAR),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Drip {},
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Send {
+                        contract: STAKING.to_string(),
+                        amount: Uint128::new(800),
+                        msg: hook,
+                    }
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+
+        // Nothing accrued in the same block: no message
+        set_balance(&mut deps, 3_200);
+        let res = execute(
+            deps.as_mut(),
+            env_at(2 * YEAR),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Drip {},
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+    }
+
+    #[test]
+    fn test_pending_distribution_projection() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        set_balance(&mut deps, 5_000);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Accrue {},
+        )
+        .unwrap();
+        let start = mock_env().block.time.seconds();
+
+        // Window entirely in the future at the stored rate
+        let res =
+            query_pending_distribution(deps.as_ref(), mock_env(), None, start + YEAR).unwrap();
+        assert_eq!(res.start, start);
+        assert_eq!(res.amount, Uint128::new(1_000));
+        assert_eq!(res.accrued_before_start, Uint128::zero());
+
+        // A year later with an unaccrued deposit: the past year streams at the
+        // old rate, the next year at the rate accrue would set now
+        set_balance(&mut deps, 11_000);
+        let now = start + YEAR;
+        let res = query_pending_distribution(deps.as_ref(), env_at(YEAR), Some(start), now + YEAR)
+            .unwrap();
+        assert_eq!(res.amount, Uint128::new(1_000 + 2_000));
+        assert_eq!(res.accrued_before_start, Uint128::zero());
+        assert_eq!(
+            res.rate_per_second,
+            Decimal256::from_ratio(10_000u128, DISTRIBUTION_PERIOD)
+        );
+
+        // Window starting in the future
+        let res = query_pending_distribution(
+            deps.as_ref(),
+            env_at(YEAR),
+            Some(now + YEAR),
+            now + 2 * YEAR,
+        )
+        .unwrap();
+        assert_eq!(res.amount, Uint128::new(2_000));
+        assert_eq!(res.accrued_before_start, Uint128::new(1_000 + 2_000));
+
+        let err = query_pending_distribution(deps.as_ref(), env_at(YEAR), Some(now), now - 1)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid window"));
+    }
+
+    #[test]
+    fn test_governance_transfer_timelock() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::ProposeGovernanceTransfer {
+                new_governance: ALICE.to_string(),
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::AcceptGovernanceTransfer {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TimelockNotExpired { .. }));
+
+        execute(
+            deps.as_mut(),
+            env_at(GOVERNANCE_TIMELOCK),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::AcceptGovernanceTransfer {},
+        )
+        .unwrap();
+        assert_eq!(query_config(deps.as_ref()).unwrap().governance, ALICE);
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use cosmwasm_std::{Addr, Uint128};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{BeneficiaryMsg, ExecuteMsg, InstantiateMsg, PoolStateResponse, QueryMsg};
+    use crate::state::DISTRIBUTION_PERIOD;
+
+    const GOVERNANCE: &str = "governance";
+    const STAKING: &str = "staking";
+
+    #[test]
+    fn test_minted_ust1_streams_to_beneficiary() {
+        let mut app = App::default();
+        let cw20_id = app.store_code(cw20_contract());
+        let pool_id = app.store_code(contract_wrapper!(crate));
+
+        let ust1 = instantiate_cw20(
+            &mut app,
+            cw20_id,
+            GOVERNANCE,
+            "USTONE",
+            vec![Cw20Coin {
+                address: GOVERNANCE.to_string(),
+                amount: Uint128::new(1_000_000),
+            }],
+        );
+
+        let pool = app
+            .instantiate_contract(
+                pool_id,
+                Addr::unchecked(GOVERNANCE),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    beneficiary: Some(BeneficiaryMsg {
+                        address: STAKING.to_string(),
+                        hook_msg: None,
+                    }),
+                },
+                &[],
+                "rolling_pool",
+                None,
+            )
+            .unwrap();
+
+        // UST1 minted straight into the pool starts streaming at the next action
+        app.execute_contract(
+            Addr::unchecked(GOVERNANCE),
+            ust1.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: pool.to_string(),
+                amount: Uint128::new(500_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            Addr::unchecked(STAKING),
+            pool.clone(),
+            &ExecuteMsg::Accrue {},
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(DISTRIBUTION_PERIOD / 10);
+        });
+        app.execute_contract(
+            Addr::unchecked(STAKING),
+            pool.clone(),
+            &ExecuteMsg::Drip {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(balance(&app, &ust1, STAKING), Uint128::new(50_000));
+        assert_eq!(balance(&app, &ust1, pool.as_str()), Uint128::new(450_000));
+
+        let state: PoolStateResponse = app
+            .wrap()
+            .query_wasm_smart(&pool, &QueryMsg::PoolState {})
+            .unwrap();
+        assert_eq!(state.streaming_balance, Uint128::new(450_000));
+        assert_eq!(state.accrued, Uint128::zero());
+        assert_eq!(state.total_distributed, Uint128::new(50_000));
+    }
+}
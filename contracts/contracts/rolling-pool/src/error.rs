@@ -0,0 +1,26 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Unauthorized: only the UST1 token can call Receive")]
+    UnauthorizedToken,
+
+    #[error("Zero amount not allowed")]
+    ZeroAmount,
+
+    #[error("No beneficiary set")]
+    NoBeneficiary,
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
@@ -0,0 +1,25 @@
+//! # Rolling Pool Contract
+//!
+//! Streams its UST1 balance to a beneficiary over a rolling five-year window.
+//!
+//! ## Distribution Rate
+//!
+//! The pool emits `balance / 5 years` UST1 per second. The rate is fixed
+//! between actions and recomputed from the live UST1 balance on every action
+//! (accrue, drip, deposit), so UST1 minted or transferred into the pool starts
+//! streaming at the next action. Nothing can take UST1 out of the pool except
+//! drips to the beneficiary.
+//!
+//! ## Beneficiary
+//!
+//! Governance sets the beneficiary, typically a staking contract or the
+//! buy-and-burn contract. Accrued UST1 is delivered by the permissionless
+//! `Drip`, either as a plain transfer or as a CW20 `Send` with a
+//! governance-configured hook message.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
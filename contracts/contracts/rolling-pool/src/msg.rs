@@ -0,0 +1,108 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary, Decimal256, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub ust1_token: String,
+    pub beneficiary: Option<BeneficiaryMsg>,
+}
+
+#[cw_serde]
+pub struct BeneficiaryMsg {
+    pub address: String,
+    /// If set, drips use CW20 `Send` with this message; otherwise `Transfer`
+    pub hook_msg: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Accrues emissions up to now and recomputes the rate (permissionless)
+    Accrue {},
+
+    /// Accrues, then sends everything accrued to the beneficiary (permissionless)
+    Drip {},
+
+    /// CW20 receive hook for UST1; the embedded message is `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
+    /// Sets the beneficiary; undripped UST1 goes to whoever is beneficiary at
+    /// the next drip (governance-only)
+    SetBeneficiary { beneficiary: Option<BeneficiaryMsg> },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+/// Messages embedded in a UST1 `Send` to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Adds the sent UST1 to the pool and recomputes the rate
+    Deposit {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    /// Pool state as of the last action
+    #[returns(PoolStateResponse)]
+    PoolState {},
+
+    /// Projected emission between `start` and `end` (unix seconds) assuming
+    /// no further actions. `start` defaults to now and is clamped to the last
+    /// update; the rate is recomputed from the live balance as of now.
+    #[returns(PendingDistributionResponse)]
+    PendingDistribution { start: Option<u64>, end: u64 },
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub beneficiary: Option<Addr>,
+    pub hook_msg: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct PoolStateResponse {
+    pub streaming_balance: Uint128,
+    pub accrued: Uint128,
+    /// UST1 base units emitted per second until the next action
+    pub rate_per_second: Decimal256,
+    pub last_update: Timestamp,
+    pub total_distributed: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingDistributionResponse {
+    pub start: u64,
+    pub end: u64,
+    /// UST1 emitted within the window
+    pub amount: Uint128,
+    /// Emitted before the window and not yet dripped
+    pub accrued_before_start: Uint128,
+    /// Rate that applies from now, as if `Accrue` were called now
+    pub rate_per_second: Decimal256,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
@@ -0,0 +1,65 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Timestamp, Uint128};
+use cw_storage_plus::Item;
+
+pub const CONTRACT_NAME: &str = "crates.io:rolling-pool";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// 5 years (5 * 365 days) in seconds
+pub const DISTRIBUTION_PERIOD: u64 = 157_680_000;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    /// UST1 CW20 token distributed by this pool
+    pub ust1_token: Addr,
+    pub beneficiary: Option<Beneficiary>,
+}
+
+#[cw_serde]
+pub struct Beneficiary {
+    pub address: Addr,
+    /// If set, drips use CW20 `Send` with this message; otherwise `Transfer`
+    pub hook_msg: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct PoolState {
+    /// Balance being streamed at the current rate (excludes `accrued`)
+    pub streaming_balance: Uint128,
+    /// Emitted but not yet dripped to the beneficiary
+    pub accrued: Uint128,
+    /// Last time the rate was recomputed
+    pub last_update: Timestamp,
+    /// Lifetime UST1 dripped to beneficiaries
+    pub total_distributed: Uint128,
+}
+
+impl PoolState {
+    /// UST1 emitted by `streaming_balance` between `last_update` and `now`
+    pub fn emitted_until(&self, now: Timestamp) -> Uint128 {
+        let elapsed = now.seconds().saturating_sub(self.last_update.seconds());
+        emission(self.streaming_balance, elapsed)
+    }
+}
+
+/// Emission of `balance` over `seconds` at `balance / DISTRIBUTION_PERIOD` per second
+pub fn emission(balance: Uint128, seconds: u64) -> Uint128 {
+    if seconds >= DISTRIBUTION_PERIOD {
+        return balance;
+    }
+    balance.multiply_ratio(seconds, DISTRIBUTION_PERIOD)
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const POOL_STATE: Item<PoolState> = Item::new("pool_state");
@@ -32,6 +32,15 @@ pub enum ContractError {
     #[error("Invalid amount: withdrawal amount must be greater than zero")]
     ZeroWithdrawAmount,
 
+    #[error("Withdrawal batch must contain at least one leg")]
+    EmptyWithdrawBatch,
+
+    #[error("Withdrawal batch has too many legs: {count} (max {max})")]
+    TooManyWithdrawLegs { count: usize, max: usize },
+
+    #[error("Insufficient balance for batch legs: {}", .failures.join("; "))]
+    BatchInsufficientBalance { failures: Vec<String> },
+
     #[error("Swap contract not set")]
     SwapContractNotSet,
 
@@ -58,5 +67,14 @@ pub enum ContractError {
 
     #[error("Wrapping operations are paused")]
     WrappingPaused,
+
+    #[error("Invalid timelock: {reason}")]
+    InvalidTimelock { reason: String },
+
+    #[error("Invalid withdrawal tiers: {reason}")]
+    InvalidWithdrawalTiers { reason: String },
+
+    #[error("No pending action found for ID: {action_id}")]
+    NoPendingAction { action_id: u64 },
 }
 
@@ -5,12 +5,12 @@
 //!
 //! # Features
 //! - Holds native tokens (USTC, LUNC, etc.) and CW20 tokens
-//! - Governance address with 7-day timelock on changes
+//! - Governance address with timelocked changes (7 days by default)
 //! - Two-step governance transfer (propose → accept)
 //! - Unified withdrawal interface for all asset types
 //!
 //! # Security
-//! - Governance changes require 7-day waiting period
+//! - Governance changes and withdrawals require a waiting period (7 days by default)
 //! - Current governance can cancel pending transfers
 //! - All actions emit events for transparency
 
@@ -2,9 +2,11 @@
 
 use common::AssetInfo;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
 
+use crate::state::{TimelockClass, TimelockedAction, Timelocks, WithdrawalTier};
+
 /// Instantiate message
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -19,7 +21,7 @@ pub struct MigrateMsg {}
 /// Execute messages
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Initiates 7-day timelock for governance transfer
+    /// Initiates the governance transfer timelock (7 days by default)
     /// Only callable by current governance
     ProposeGovernanceTransfer { new_governance: String },
 
@@ -31,7 +33,8 @@ pub enum ExecuteMsg {
     /// Only callable by current governance
     CancelGovernanceTransfer { proposed_governance: String },
 
-    /// Proposes a withdrawal with 7-day timelock
+    /// Proposes a withdrawal under the withdrawal timelock (7 days by default),
+    /// or the asset's tier for this amount if withdrawal tiers are set
     /// Only callable by governance
     ProposeWithdraw {
         destination: String,
@@ -39,11 +42,18 @@ pub enum ExecuteMsg {
         amount: Uint128,
     },
 
-    /// Executes a pending withdrawal after timelock expires
+    /// Proposes a batch of withdrawal legs under a single timelock: the longest
+    /// withdrawal timelock of any asset in the batch, with each asset's tier
+    /// picked by its total across legs
+    /// All legs execute atomically via ExecuteWithdraw with the returned withdrawal_id
+    /// Only callable by governance
+    ProposeWithdrawBatch { legs: Vec<WithdrawalLeg> },
+
+    /// Executes a pending withdrawal (single or batch) after timelock expires
     /// Only callable by governance
     ExecuteWithdraw { withdrawal_id: String },
 
-    /// Cancels a specific pending withdrawal
+    /// Cancels a specific pending withdrawal (single or batch)
     /// Only callable by governance
     CancelWithdraw { withdrawal_id: String },
 
@@ -63,7 +73,16 @@ pub enum ExecuteMsg {
     /// Users send USTC directly to Treasury via MsgExecuteContract (no tax)
     /// Treasury notifies swap contract to mint USTR to depositor
     /// Minimum deposit: 1 USTC (1,000,000 uusd)
-    SwapDeposit {},
+    SwapDeposit {
+        /// Optional referral code, forwarded to the swap contract
+        referral_code: Option<String>,
+        /// Optional leaderboard insertion hint, forwarded to the swap contract
+        leaderboard_hint: Option<SwapLeaderboardHint>,
+        /// Optional minimum USTR to the depositor, forwarded to the swap contract
+        min_total_ustr: Option<Uint128>,
+        /// Optional maximum USTC/USTR rate, forwarded to the swap contract
+        max_rate: Option<Decimal>,
+    },
 
     /// CW20 receive hook - accepts direct CW20 token transfers
     /// Called automatically when CW20 tokens are sent to this contract
@@ -87,9 +106,40 @@ pub enum ExecuteMsg {
         amount: Uint128,
     },
 
-    /// Pauses or unpauses wrapping operations (WrapDeposit / InstantWithdraw).
+    /// Sends wrap/unwrap fees the wrapper has accrued for a denom to a
+    /// recipient chosen by wrapper governance (e.g. buy-and-burn).
+    /// Caller must be the registered wrapper for the denom.
+    SweepWrapperFees {
+        recipient: String,
+        denom: String,
+        amount: Uint128,
+    },
+
+    /// Pauses or unpauses wrapping operations (WrapDeposit / InstantWithdraw / SweepWrapperFees).
     /// Only callable by governance.
     SetWrappingPaused { paused: bool },
+
+    /// Sets the timelock for an action class
+    /// Increases apply immediately; decreases are queued behind the longest current timelock
+    /// Only callable by governance
+    SetTimelock { class: TimelockClass, duration: u64 },
+
+    /// Replaces the withdrawal amount tiers for an asset (empty list removes them)
+    /// Applies immediately if no amount gets a shorter timelock; otherwise queued
+    /// behind the longest current timelock
+    /// Only callable by governance
+    SetWithdrawalTiers {
+        asset: AssetInfo,
+        tiers: Vec<WithdrawalTier>,
+    },
+
+    /// Executes a queued action after its timelock expires
+    /// Only callable by governance
+    ExecuteAction { action_id: u64 },
+
+    /// Cancels a queued action
+    /// Only callable by governance
+    CancelAction { action_id: u64 },
 }
 
 /// Query messages
@@ -100,9 +150,14 @@ pub enum QueryMsg {
     #[returns(ConfigResponse)]
     Config {},
 
-    /// Returns all pending governance proposals
+    /// Returns pending governance proposals, ordered by proposed address
     #[returns(PendingGovernanceResponse)]
-    PendingGovernance {},
+    PendingGovernance {
+        /// Proposed address to start after (for cursor-based pagination)
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
 
     /// Returns treasury balance for specified asset
     #[returns(BalanceResponse)]
@@ -114,21 +169,69 @@ pub enum QueryMsg {
 
     /// Returns list of whitelisted CW20 contract addresses
     #[returns(Cw20WhitelistResponse)]
-    Cw20Whitelist {},
+    Cw20Whitelist {
+        /// Contract address to start after (for cursor-based pagination)
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
 
-    /// Returns all pending withdrawal proposals
+    /// Returns pending withdrawal proposals (single and batch), ordered by withdrawal ID
+    /// The limit counts entries scanned (single and batch combined) before filters
+    /// apply, so a filtered page can be short or empty; continue from `next_start_after`
     #[returns(PendingWithdrawalsResponse)]
-    PendingWithdrawals {},
+    PendingWithdrawals {
+        /// Withdrawal ID to start after (for cursor-based pagination)
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+        /// Only withdrawals paying this address (any leg, for batches)
+        destination: Option<String>,
+        /// Only withdrawals of this asset (any leg, for batches)
+        asset: Option<AssetInfo>,
+        /// Only withdrawals whose timelock has (or has not) expired at the current block time
+        status: Option<WithdrawalStatus>,
+    },
 
-    /// Returns all denom->wrapper mappings
+    /// Returns denom->wrapper mappings, ordered by denom
     #[returns(DenomWrappersResponse)]
-    DenomWrappers {},
+    DenomWrappers {
+        /// Denom to start after (for cursor-based pagination)
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// Returns the timelock duration of every action class
+    #[returns(TimelocksResponse)]
+    Timelocks {},
+
+    /// Returns the withdrawal amount tiers for an asset (empty if none)
+    #[returns(WithdrawalTiersResponse)]
+    WithdrawalTiers { asset: AssetInfo },
+
+    /// Returns the timelock a withdrawal of `amount` of `asset` would get now
+    #[returns(WithdrawalTimelockResponse)]
+    WithdrawalTimelock { asset: AssetInfo, amount: Uint128 },
+
+    /// Returns queued timelocked actions, ordered by action ID
+    #[returns(PendingActionsResponse)]
+    PendingActions {
+        /// Action ID to start after (for cursor-based pagination)
+        start_after: Option<u64>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
 }
 
 /// Response for Config query
 #[cw_serde]
 pub struct ConfigResponse {
     pub governance: Addr,
+    /// Governance transfer timelock; see the Timelocks query for every class
+    pub governance_transfer_timelock: u64,
+    /// Deprecated: same value as `governance_transfer_timelock`, kept for
+    /// clients written against the single-timelock config
     pub timelock_duration: u64,
     pub swap_contract: Option<Addr>,
     pub wrapping_paused: bool,
@@ -183,10 +286,30 @@ pub struct PendingWithdrawalEntry {
     pub execute_after: Timestamp,
 }
 
-/// Response for PendingWithdrawals query - returns all pending withdrawals
+/// A single leg of a batch withdrawal entry
+#[cw_serde]
+pub struct WithdrawalLegEntry {
+    pub destination: Addr,
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+}
+
+/// A pending batch withdrawal entry
+#[cw_serde]
+pub struct PendingWithdrawalBatchEntry {
+    pub withdrawal_id: String,
+    pub legs: Vec<WithdrawalLegEntry>,
+    pub execute_after: Timestamp,
+}
+
+/// Response for PendingWithdrawals query - one page of pending withdrawals
 #[cw_serde]
 pub struct PendingWithdrawalsResponse {
     pub withdrawals: Vec<PendingWithdrawalEntry>,
+    pub batches: Vec<PendingWithdrawalBatchEntry>,
+    /// Last withdrawal ID scanned when the page was full; pass as `start_after`
+    /// for the next page. None once the scan reached the end.
+    pub next_start_after: Option<String>,
 }
 
 /// A single denom->wrapper mapping entry
@@ -202,6 +325,67 @@ pub struct DenomWrappersResponse {
     pub wrappers: Vec<DenomWrapperEntry>,
 }
 
+/// Response for Timelocks query
+#[cw_serde]
+pub struct TimelocksResponse {
+    pub timelocks: Timelocks,
+    /// Longest timelock across all classes and withdrawal tiers; the delay
+    /// applied to timelock decreases
+    pub longest: u64,
+}
+
+/// Response for WithdrawalTiers query
+#[cw_serde]
+pub struct WithdrawalTiersResponse {
+    pub asset: AssetInfo,
+    pub tiers: Vec<WithdrawalTier>,
+}
+
+/// Response for WithdrawalTimelock query
+#[cw_serde]
+pub struct WithdrawalTimelockResponse {
+    pub duration: u64,
+}
+
+/// A single queued action entry
+#[cw_serde]
+pub struct PendingActionEntry {
+    pub action_id: u64,
+    pub action: TimelockedAction,
+    pub execute_after: Timestamp,
+}
+
+/// Response for PendingActions query
+#[cw_serde]
+pub struct PendingActionsResponse {
+    pub actions: Vec<PendingActionEntry>,
+}
+
+/// Timelock status filter for the PendingWithdrawals query
+#[cw_serde]
+pub enum WithdrawalStatus {
+    /// Timelock expired; can be executed now
+    Executable,
+    /// Timelock still running
+    Locked,
+}
+
+/// A single leg of a ProposeWithdrawBatch request
+#[cw_serde]
+pub struct WithdrawalLeg {
+    pub destination: String,
+    pub asset: AssetInfo,
+    pub amount: Uint128,
+}
+
+/// Leaderboard insertion hint forwarded to the swap contract on SwapDeposit.
+/// Matches the ustc-swap's LeaderboardHint structure.
+#[cw_serde]
+pub struct SwapLeaderboardHint {
+    /// Code that should be immediately before the depositor's code in the leaderboard
+    pub insert_after: Option<String>,
+}
+
 /// Message sent to wrapper contract to notify of a wrap deposit.
 /// Matches the wrap-mapper's ExecuteMsg::NotifyDeposit variant.
 #[cw_serde]
@@ -1,22 +1,33 @@
 //! State definitions for the Treasury contract
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
+use common::AssetInfo;
 
 /// Contract configuration
 #[cw_serde]
 pub struct Config {
     /// Current governance address (admin/DAO)
     pub governance: Addr,
-    /// Duration of governance change delay in seconds (7 days = 604,800)
-    pub timelock_duration: u64,
     /// Authorized swap contract address for deposit notifications (optional)
     pub swap_contract: Option<Addr>,
     /// Whether wrapping operations (WrapDeposit / InstantWithdraw) are paused
     pub wrapping_paused: bool,
 }
 
+/// `Config` as stored before the per-class timelocks, with the single
+/// `timelock_duration` they replaced. Only read by migrate, which rewrites it
+/// as `Config`; the field is optional so already-migrated configs load too.
+#[cw_serde]
+pub struct LegacyConfig {
+    pub governance: Addr,
+    #[serde(default)]
+    pub timelock_duration: Option<u64>,
+    pub swap_contract: Option<Addr>,
+    pub wrapping_paused: bool,
+}
+
 /// Pending governance change proposal
 #[cw_serde]
 pub struct PendingGovernance {
@@ -34,9 +45,23 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// 7 days in seconds
 pub const DEFAULT_TIMELOCK_DURATION: u64 = 604_800;
 
+/// Upper bound for any configured timelock: 90 days in seconds
+pub const MAX_TIMELOCK_DURATION: u64 = 7_776_000;
+
+/// Maximum number of amount tiers per asset
+pub const MAX_WITHDRAWAL_TIERS: usize = 10;
+
+/// Default page size for paginated queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for paginated queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
 /// Primary config storage
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// `CONFIG` read in its pre-migration layout
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
 /// Pending governance proposals mapping
 /// Key: Proposed new governance address as string
 /// Value: PendingGovernance with execute_after timestamp
@@ -49,9 +74,9 @@ pub struct PendingWithdrawal {
     /// Destination address for the withdrawal
     pub destination: Addr,
     /// Asset to withdraw
-    pub asset: common::AssetInfo,
+    pub asset: AssetInfo,
     /// Amount to withdraw
-    pub amount: cosmwasm_std::Uint128,
+    pub amount: Uint128,
     /// Block time when the withdrawal can be executed
     pub execute_after: Timestamp,
 }
@@ -62,6 +87,36 @@ pub struct PendingWithdrawal {
 /// Multiple withdrawals can exist simultaneously, each with their own timelock.
 pub const PENDING_WITHDRAWALS: Map<&str, PendingWithdrawal> = Map::new("pending_withdrawals");
 
+/// A single leg of a pending batch withdrawal
+#[cw_serde]
+pub struct WithdrawalLeg {
+    /// Destination address for this leg
+    pub destination: Addr,
+    /// Asset to withdraw
+    pub asset: AssetInfo,
+    /// Amount to withdraw
+    pub amount: Uint128,
+}
+
+/// Pending batch withdrawal proposal
+/// All legs share one timelock and execute atomically
+#[cw_serde]
+pub struct PendingWithdrawalBatch {
+    /// Withdrawal legs, executed in order
+    pub legs: Vec<WithdrawalLeg>,
+    /// Block time when the batch can be executed
+    pub execute_after: Timestamp,
+}
+
+/// Maximum number of legs in a single batch withdrawal
+pub const MAX_WITHDRAW_BATCH_LEGS: usize = 20;
+
+/// Pending batch withdrawals mapping
+/// Key: Unique withdrawal ID (shares the ID space with PENDING_WITHDRAWALS)
+/// Value: PendingWithdrawalBatch with execute_after timestamp
+pub const PENDING_WITHDRAWAL_BATCHES: Map<&str, PendingWithdrawalBatch> =
+    Map::new("pending_withdrawal_batches");
+
 /// CW20 token whitelist for balance tracking
 /// Key: CW20 contract address as string
 pub const CW20_WHITELIST: Map<&str, bool> = Map::new("cw20_whitelist");
@@ -70,3 +125,128 @@ pub const CW20_WHITELIST: Map<&str, bool> = Map::new("cw20_whitelist");
 /// Governance-managed. Used by WrapDeposit and InstantWithdraw.
 pub const DENOM_WRAPPERS: Map<&str, Addr> = Map::new("denom_wrappers");
 
+/// Governance action classes with independently configured timelocks
+#[cw_serde]
+#[derive(Copy)]
+pub enum TimelockClass {
+    /// ProposeGovernanceTransfer -> AcceptGovernanceTransfer
+    GovernanceTransfer,
+    /// ProposeWithdraw / ProposeWithdrawBatch -> ExecuteWithdraw (default for
+    /// assets without amount tiers)
+    Withdrawal,
+    /// AddCw20 / RemoveCw20
+    WhitelistChange,
+    /// SetDenomWrapper / RemoveDenomWrapper
+    WrapperRegistration,
+}
+
+/// Timelock duration in seconds per action class. A zero duration applies
+/// the action immediately.
+#[cw_serde]
+pub struct Timelocks {
+    pub governance_transfer: u64,
+    pub withdrawal: u64,
+    pub whitelist_change: u64,
+    pub wrapper_registration: u64,
+}
+
+impl Timelocks {
+    /// Durations in force before per-class timelocks existed: 7 days for
+    /// governance transfers and withdrawals, immediate otherwise
+    pub fn legacy(timelock_duration: u64) -> Self {
+        Timelocks {
+            governance_transfer: timelock_duration,
+            withdrawal: timelock_duration,
+            whitelist_change: 0,
+            wrapper_registration: 0,
+        }
+    }
+
+    pub fn get(&self, class: TimelockClass) -> u64 {
+        match class {
+            TimelockClass::GovernanceTransfer => self.governance_transfer,
+            TimelockClass::Withdrawal => self.withdrawal,
+            TimelockClass::WhitelistChange => self.whitelist_change,
+            TimelockClass::WrapperRegistration => self.wrapper_registration,
+        }
+    }
+
+    pub fn set(&mut self, class: TimelockClass, duration: u64) {
+        match class {
+            TimelockClass::GovernanceTransfer => self.governance_transfer = duration,
+            TimelockClass::Withdrawal => self.withdrawal = duration,
+            TimelockClass::WhitelistChange => self.whitelist_change = duration,
+            TimelockClass::WrapperRegistration => self.wrapper_registration = duration,
+        }
+    }
+
+    pub fn longest(&self) -> u64 {
+        self.governance_transfer
+            .max(self.withdrawal)
+            .max(self.whitelist_change)
+            .max(self.wrapper_registration)
+    }
+}
+
+/// Per-class timelock durations
+pub const TIMELOCKS: Item<Timelocks> = Item::new("timelocks");
+
+/// Withdrawal timelock for amounts of at least `min_amount` of an asset
+#[cw_serde]
+pub struct WithdrawalTier {
+    pub min_amount: Uint128,
+    pub duration: u64,
+}
+
+/// Amount tiers configured for one asset, ordered by `min_amount`
+#[cw_serde]
+pub struct AssetWithdrawalTiers {
+    pub asset: AssetInfo,
+    pub tiers: Vec<WithdrawalTier>,
+}
+
+/// Per-asset withdrawal tiers
+/// Key: "native:<denom>" or "cw20:<address>"
+/// Amounts below the first tier use the Withdrawal class timelock.
+pub const WITHDRAWAL_TIERS: Map<&str, AssetWithdrawalTiers> = Map::new("withdrawal_tiers");
+
+/// Governance action held back by its class timelock
+#[cw_serde]
+pub enum TimelockedAction {
+    SetTimelock {
+        class: TimelockClass,
+        duration: u64,
+    },
+    SetWithdrawalTiers {
+        asset: AssetInfo,
+        tiers: Vec<WithdrawalTier>,
+    },
+    AddCw20 {
+        contract_addr: Addr,
+    },
+    RemoveCw20 {
+        contract_addr: Addr,
+    },
+    SetDenomWrapper {
+        denom: String,
+        wrapper: Addr,
+    },
+    RemoveDenomWrapper {
+        denom: String,
+    },
+}
+
+/// Pending timelocked action
+#[cw_serde]
+pub struct PendingAction {
+    pub action: TimelockedAction,
+    /// Block time when the action can be executed
+    pub execute_after: Timestamp,
+}
+
+/// Pending timelocked actions
+/// Key: sequential action ID
+pub const PENDING_ACTIONS: Map<u64, PendingAction> = Map::new("pending_actions");
+
+/// Next pending action ID
+pub const NEXT_ACTION_ID: Item<u64> = Item::new("next_action_id");
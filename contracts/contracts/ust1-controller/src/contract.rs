@@ -0,0 +1,1432 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Storage, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_storage_plus::Bound;
+
+use common::{Asset, AssetInfo, CrTier};
+
+use crate::error::ContractError;
+use crate::msg::{
+    CollateralEntry, CollateralResponse, ConfigResponse, CrEngineQueryMsg, CrEngineTierResponse,
+    ExecuteMsg, InstantiateMsg, MigrateMsg, MinterResponse, MintersResponse,
+    PendingCrEngineResponse, PendingGovernanceResponse, QueryMsg, ReceiveMsg, StatusResponse,
+    SupplyTotalsResponse,
+};
+use crate::state::{
+    asset_key, CollateralRecord, Config, MinterInfo, MinterRole, PendingCrEngine,
+    PendingGovernance, COLLATERAL, CONFIG, CONTRACT_NAME, CONTRACT_VERSION, DEFAULT_QUERY_LIMIT,
+    GOVERNANCE_TIMELOCK, MAX_QUERY_LIMIT, MINTERS, PENDING_CR_ENGINE, PENDING_GOVERNANCE,
+    SUPPLY_TOTALS,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        ust1_token: deps.api.addr_validate(&msg.ust1_token)?,
+        cr_engine: deps.api.addr_validate(&msg.cr_engine)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    SUPPLY_TOTALS.save(deps.storage, &Default::default())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("ust1_token", config.ust1_token)
+        .add_attribute("cr_engine", config.cr_engine))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Mint {
+            recipient,
+            amount,
+            collateral,
+        } => execute_mint(deps, info, recipient, amount, collateral),
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, info, cw20_msg),
+        ExecuteMsg::AddMinter { address, role, cap } => {
+            execute_add_minter(deps, info, address, role, cap)
+        }
+        ExecuteMsg::UpdateMinterCap { address, cap } => {
+            execute_update_minter_cap(deps, info, address, cap)
+        }
+        ExecuteMsg::RemoveMinter { address } => execute_remove_minter(deps, info, address),
+        ExecuteMsg::ProposeCrEngine { cr_engine } => {
+            execute_propose_cr_engine(deps, env, info, cr_engine)
+        }
+        ExecuteMsg::AcceptCrEngine {} => execute_accept_cr_engine(deps, env, info),
+        ExecuteMsg::CancelCrEngine {} => execute_cancel_cr_engine(deps, info),
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+    collateral: Option<Asset>,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut minter = load_minter(deps.storage, &info.sender)?;
+
+    let tier = current_tier(deps.as_ref(), &config)?;
+    if !mint_enabled(minter.role, tier) {
+        return Err(ContractError::MintingDisabled {
+            role: minter.role,
+            tier,
+        });
+    }
+
+    let minted = minter.minted.checked_add(amount).map_err(StdError::from)?;
+    if let Some(cap) = minter.cap {
+        if minted > cap {
+            return Err(ContractError::MintCapExceeded {
+                cap,
+                minted: minter.minted,
+                requested: amount,
+            });
+        }
+    }
+    minter.minted = minted;
+    MINTERS.save(deps.storage, &info.sender, &minter)?;
+
+    let mut totals = SUPPLY_TOTALS.load(deps.storage)?;
+    totals.total_minted = totals
+        .total_minted
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    SUPPLY_TOTALS.save(deps.storage, &totals)?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let mint_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+            recipient: recipient.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    });
+
+    let mut response = Response::new()
+        .add_message(mint_msg)
+        .add_attribute("action", "mint")
+        .add_attribute("minter", &info.sender)
+        .add_attribute("role", minter.role.to_string())
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", amount)
+        .add_attribute("tier", tier.to_string())
+        .add_attribute("minter_total", minter.minted);
+
+    if let Some(collateral) = collateral {
+        record_collateral(deps.storage, &collateral, true)?;
+        response = response
+            .add_attribute("collateral_asset", asset_key(&collateral.info))
+            .add_attribute("collateral_amount", collateral.amount);
+    }
+
+    Ok(response)
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.ust1_token {
+        return Err(ContractError::UnauthorizedToken);
+    }
+
+    match from_json(&cw20_msg.msg)? {
+        ReceiveMsg::Redeem { collateral } => {
+            let redeemer = deps.api.addr_validate(&cw20_msg.sender)?;
+            execute_redeem(deps, config, redeemer, cw20_msg.amount, collateral)
+        }
+    }
+}
+
+fn execute_redeem(
+    deps: DepsMut,
+    config: Config,
+    redeemer: Addr,
+    amount: Uint128,
+    collateral: Option<Asset>,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    let mut minter = load_minter(deps.storage, &redeemer)?;
+
+    let tier = current_tier(deps.as_ref(), &config)?;
+    if !redeem_enabled(tier) {
+        return Err(ContractError::RedemptionDisabled { tier });
+    }
+
+    minter.redeemed = minter
+        .redeemed
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    MINTERS.save(deps.storage, &redeemer, &minter)?;
+
+    let mut totals = SUPPLY_TOTALS.load(deps.storage)?;
+    totals.total_redeemed = totals
+        .total_redeemed
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    SUPPLY_TOTALS.save(deps.storage, &totals)?;
+
+    let burn_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+        funds: vec![],
+    });
+
+    let mut response = Response::new()
+        .add_message(burn_msg)
+        .add_attribute("action", "redeem")
+        .add_attribute("redeemer", &redeemer)
+        .add_attribute("role", minter.role.to_string())
+        .add_attribute("amount", amount)
+        .add_attribute("tier", tier.to_string());
+
+    if let Some(collateral) = collateral {
+        record_collateral(deps.storage, &collateral, false)?;
+        response = response
+            .add_attribute("collateral_asset", asset_key(&collateral.info))
+            .add_attribute("collateral_amount", collateral.amount);
+    }
+
+    Ok(response)
+}
+
+fn execute_add_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    role: MinterRole,
+    cap: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    if MINTERS.has(deps.storage, &addr) {
+        return Err(ContractError::MinterAlreadyRegistered { address });
+    }
+
+    MINTERS.save(
+        deps.storage,
+        &addr,
+        &MinterInfo {
+            role,
+            cap,
+            minted: Uint128::zero(),
+            redeemed: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_minter")
+        .add_attribute("minter", addr)
+        .add_attribute("role", role.to_string())
+        .add_attribute("cap", format_cap(cap)))
+}
+
+fn execute_update_minter_cap(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    cap: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    let mut minter = load_minter(deps.storage, &addr)?;
+    let old_cap = minter.cap;
+    minter.cap = cap;
+    MINTERS.save(deps.storage, &addr, &minter)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_minter_cap")
+        .add_attribute("minter", addr)
+        .add_attribute("old_cap", format_cap(old_cap))
+        .add_attribute("new_cap", format_cap(cap)))
+}
+
+fn execute_remove_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    load_minter(deps.storage, &addr)?;
+    MINTERS.remove(deps.storage, &addr);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_minter")
+        .add_attribute("minter", addr))
+}
+
+fn execute_propose_cr_engine(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cr_engine: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let pending = PendingCrEngine {
+        cr_engine: deps.api.addr_validate(&cr_engine)?,
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_CR_ENGINE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_cr_engine")
+        .add_attribute("cr_engine", pending.cr_engine)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_cr_engine(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let pending = PENDING_CR_ENGINE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingCrEngine)?;
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    config.cr_engine = pending.cr_engine;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_CR_ENGINE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_cr_engine")
+        .add_attribute("cr_engine", config.cr_engine))
+}
+
+fn execute_cancel_cr_engine(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_CR_ENGINE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingCrEngine);
+    }
+    PENDING_CR_ENGINE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_cr_engine"))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Minter { address } => to_json_binary(&query_minter(deps, address)?),
+        QueryMsg::Minters { start_after, limit } => {
+            to_json_binary(&query_minters(deps, start_after, limit)?)
+        }
+        QueryMsg::Collateral { start_after, limit } => {
+            to_json_binary(&query_collateral(deps, start_after, limit)?)
+        }
+        QueryMsg::SupplyTotals {} => to_json_binary(&query_supply_totals(deps)?),
+        QueryMsg::Status {} => to_json_binary(&query_status(deps)?),
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+        QueryMsg::PendingCrEngine {} => to_json_binary(&query_pending_cr_engine(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        ust1_token: config.ust1_token,
+        cr_engine: config.cr_engine,
+    })
+}
+
+fn query_minter(deps: Deps, address: String) -> StdResult<Option<MinterResponse>> {
+    let addr = deps.api.addr_validate(&address)?;
+    Ok(MINTERS
+        .may_load(deps.storage, &addr)?
+        .map(|info| minter_response(addr, info)))
+}
+
+fn query_minters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<MintersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let minters = MINTERS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(addr, info)| minter_response(addr, info)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(MintersResponse { minters })
+}
+
+fn query_collateral(
+    deps: Deps,
+    start_after: Option<AssetInfo>,
+    limit: Option<u32>,
+) -> StdResult<CollateralResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_key = start_after.as_ref().map(asset_key);
+    let start = start_key.as_deref().map(Bound::exclusive);
+
+    let collateral = COLLATERAL
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|r| {
+            r.map(|(_, record)| CollateralEntry {
+                asset: record.asset,
+                received: record.received,
+                paid_out: record.paid_out,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(CollateralResponse { collateral })
+}
+
+fn query_supply_totals(deps: Deps) -> StdResult<SupplyTotalsResponse> {
+    let totals = SUPPLY_TOTALS.load(deps.storage)?;
+    Ok(SupplyTotalsResponse {
+        total_minted: totals.total_minted,
+        total_redeemed: totals.total_redeemed,
+    })
+}
+
+fn query_status(deps: Deps) -> StdResult<StatusResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let tier = current_tier(deps, &config)?;
+    Ok(StatusResponse {
+        tier,
+        mint_enabled_roles: [
+            MinterRole::Treasury,
+            MinterRole::Auction,
+            MinterRole::RollingPool,
+        ]
+        .into_iter()
+        .filter(|role| mint_enabled(*role, tier))
+        .collect(),
+        redeem_enabled: redeem_enabled(tier),
+    })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+fn query_pending_cr_engine(deps: Deps) -> StdResult<PendingCrEngineResponse> {
+    let pending = PENDING_CR_ENGINE.may_load(deps.storage)?;
+    Ok(PendingCrEngineResponse {
+        cr_engine: pending.as_ref().map(|p| p.cr_engine.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+/// Auctions sell UST1 for collateral in YELLOW and redemption auctions run in
+/// GREEN, so auction minters may mint in every tier but RED. Rolling pool and
+/// treasury mints expand supply without collateral and need BLUE.
+fn mint_enabled(role: MinterRole, tier: CrTier) -> bool {
+    match role {
+        MinterRole::Auction => tier != CrTier::Red,
+        MinterRole::Treasury | MinterRole::RollingPool => tier == CrTier::Blue,
+    }
+}
+
+/// Redemption is locked only in RED
+fn redeem_enabled(tier: CrTier) -> bool {
+    tier != CrTier::Red
+}
+
+fn current_tier(deps: Deps, config: &Config) -> StdResult<CrTier> {
+    let res: CrEngineTierResponse = deps
+        .querier
+        .query_wasm_smart(&config.cr_engine, &CrEngineQueryMsg::CurrentTier {})?;
+    Ok(res.tier)
+}
+
+fn load_minter(storage: &dyn Storage, addr: &Addr) -> Result<MinterInfo, ContractError> {
+    MINTERS
+        .may_load(storage, addr)?
+        .ok_or_else(|| ContractError::NotAMinter {
+            address: addr.to_string(),
+        })
+}
+
+fn record_collateral(
+    storage: &mut dyn Storage,
+    collateral: &Asset,
+    received: bool,
+) -> StdResult<()> {
+    let key = asset_key(&collateral.info);
+    let mut record = COLLATERAL
+        .may_load(storage, &key)?
+        .unwrap_or_else(|| CollateralRecord {
+            asset: collateral.info.clone(),
+            received: Uint128::zero(),
+            paid_out: Uint128::zero(),
+        });
+    if received {
+        record.received = record.received.checked_add(collateral.amount)?;
+    } else {
+        record.paid_out = record.paid_out.checked_add(collateral.amount)?;
+    }
+    COLLATERAL.save(storage, &key, &record)
+}
+
+fn minter_response(address: Addr, info: MinterInfo) -> MinterResponse {
+    MinterResponse {
+        address,
+        role: info.role,
+        cap: info.cap,
+        minted: info.minted,
+        redeemed: info.redeemed,
+        remaining: info.cap.map(|cap| cap.saturating_sub(info.minted)),
+    }
+}
+
+fn format_cap(cap: Option<Uint128>) -> String {
+    match cap {
+        Some(cap) => cap.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{ContractResult, OwnedDeps, SystemError, SystemResult, WasmQuery};
+
+    const GOVERNANCE: &str = "governance";
+    const UST1: &str = "ust1_token";
+    const CR_ENGINE: &str = "cr_engine";
+    const AUCTION: &str = "auction";
+    const POOL: &str = "rolling_pool";
+    const USER: &str = "user";
+
+    fn mock_tier(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>, tier: CrTier) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == CR_ENGINE => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&CrEngineTierResponse { tier, cr: None }).unwrap(),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "unexpected wasm query".to_string(),
+            }),
+        });
+    }
+
+    fn setup_contract(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ust1_token: UST1.to_string(),
+            cr_engine: CR_ENGINE.to_string(),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        add_minter(
+            deps,
+            AUCTION,
+            MinterRole::Auction,
+            Some(Uint128::new(1_000)),
+        );
+        add_minter(deps, POOL, MinterRole::RollingPool, None);
+        mock_tier(deps, CrTier::Blue);
+    }
+
+    fn add_minter(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+        role: MinterRole,
+        cap: Option<Uint128>,
+    ) {
+        let msg = ExecuteMsg::AddMinter {
+            address: address.to_string(),
+            role,
+            cap,
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    fn mint_msg(amount: u128, collateral: Option<Asset>) -> ExecuteMsg {
+        ExecuteMsg::Mint {
+            recipient: USER.to_string(),
+            amount: Uint128::new(amount),
+            collateral,
+        }
+    }
+
+    fn redeem_msg(sender: &str, amount: u128, collateral: Option<Asset>) -> ExecuteMsg {
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_json_binary(&ReceiveMsg::Redeem { collateral }).unwrap(),
+        })
+    }
+
+    fn query_minter_info(deps: Deps, address: &str) -> MinterResponse {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::Minter {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+        let minter: Option<MinterResponse> = from_json(res).unwrap();
+        minter.unwrap()
+    }
+
+    #[test]
+    fn test_mint_in_blue() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(AUCTION, &[]),
+            mint_msg(400, None),
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, UST1);
+                let parsed: Cw20ExecuteMsg = from_json(msg).unwrap();
+                assert_eq!(
+                    parsed,
+                    Cw20ExecuteMsg::Mint {
+                        recipient: USER.to_string(),
+                        amount: Uint128::new(400),
+                    }
+                );
+            }
+            _ => panic!("Expected WasmMsg::Execute"),
+        }
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "role" && a.value == "auction"));
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "tier" && a.value == "blue"));
+
+        let minter = query_minter_info(deps.as_ref(), AUCTION);
+        assert_eq!(minter.minted, Uint128::new(400));
+        assert_eq!(minter.remaining, Some(Uint128::new(600)));
+    }
+
+    #[test]
+    fn test_mint_gated_by_role_and_tier() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        add_minter(&mut deps, "treasury", MinterRole::Treasury, None);
+
+        let cases = [
+            (CrTier::Red, [false, false, false]),
+            (CrTier::Yellow, [false, true, false]),
+            (CrTier::Green, [false, true, false]),
+            (CrTier::Blue, [true, true, true]),
+        ];
+        for (tier, allowed) in cases {
+            mock_tier(&mut deps, tier);
+            let callers = [
+                ("treasury", MinterRole::Treasury),
+                (AUCTION, MinterRole::Auction),
+                (POOL, MinterRole::RollingPool),
+            ];
+            for ((caller, role), allowed) in callers.into_iter().zip(allowed) {
+                let res = execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info(caller, &[]),
+                    mint_msg(1, None),
+                );
+                if allowed {
+                    assert!(res.is_ok(), "{} should mint in {}", role, tier);
+                } else {
+                    assert_eq!(
+                        res.unwrap_err(),
+                        ContractError::MintingDisabled { role, tier }
+                    );
+                }
+            }
+
+            let res = query(deps.as_ref(), mock_env(), QueryMsg::Status {}).unwrap();
+            let status: StatusResponse = from_json(res).unwrap();
+            assert_eq!(status.tier, tier);
+            let expected: Vec<MinterRole> = callers
+                .into_iter()
+                .zip(allowed)
+                .filter(|(_, allowed)| *allowed)
+                .map(|((_, role), _)| role)
+                .collect();
+            assert_eq!(status.mint_enabled_roles, expected);
+            assert_eq!(status.redeem_enabled, tier != CrTier::Red);
+        }
+    }
+
+    #[test]
+    fn test_mint_unregistered_caller() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            mint_msg(1, None),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotAMinter {
+                address: USER.to_string()
+            }
+        );
+
+        // Removed minters lose access
+        let msg = ExecuteMsg::RemoveMinter {
+            address: POOL.to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(POOL, &[]),
+            mint_msg(1, None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAMinter { .. }));
+    }
+
+    #[test]
+    fn test_mint_cap() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(AUCTION, &[]),
+            mint_msg(900, None),
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(AUCTION, &[]),
+            mint_msg(101, None),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MintCapExceeded {
+                cap: Uint128::new(1_000),
+                minted: Uint128::new(900),
+                requested: Uint128::new(101),
+            }
+        );
+
+        // Raising the cap unblocks the caller
+        let msg = ExecuteMsg::UpdateMinterCap {
+            address: AUCTION.to_string(),
+            cap: Some(Uint128::new(2_000)),
+        };
+        let err =
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &[]), msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(AUCTION, &[]),
+            mint_msg(101, None),
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::SupplyTotals {}).unwrap();
+        let totals: SupplyTotalsResponse = from_json(res).unwrap();
+        assert_eq!(totals.total_minted, Uint128::new(1_001));
+    }
+
+    #[test]
+    fn test_redeem_burns_and_is_locked_in_red() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        mock_tier(&mut deps, CrTier::Yellow);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(UST1, &[]),
+            redeem_msg(AUCTION, 250, None),
+        )
+        .unwrap();
+        let parsed: Cw20ExecuteMsg = match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => from_json(msg).unwrap(),
+            _ => panic!("Expected WasmMsg::Execute"),
+        };
+        assert_eq!(
+            parsed,
+            Cw20ExecuteMsg::Burn {
+                amount: Uint128::new(250)
+            }
+        );
+        assert_eq!(
+            query_minter_info(deps.as_ref(), AUCTION).redeemed,
+            Uint128::new(250)
+        );
+
+        mock_tier(&mut deps, CrTier::Red);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(UST1, &[]),
+            redeem_msg(AUCTION, 1, None),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::RedemptionDisabled { tier: CrTier::Red });
+    }
+
+    #[test]
+    fn test_redeem_rejects_wrong_token_and_unregistered_sender() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fake_token", &[]),
+            redeem_msg(AUCTION, 1, None),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::UnauthorizedToken);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(UST1, &[]),
+            redeem_msg(USER, 1, None),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::NotAMinter { .. }));
+    }
+
+    #[test]
+    fn test_collateral_accounting() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let lunc = AssetInfo::native("uluna");
+        let cw20 = AssetInfo::cw20(Addr::unchecked("wbtc"));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(AUCTION, &[]),
+            mint_msg(100, Some(Asset::new(lunc.clone(), 5_000u128))),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(AUCTION, &[]),
+            mint_msg(100, Some(Asset::new(cw20.clone(), 7u128))),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(UST1, &[]),
+            redeem_msg(AUCTION, 50, Some(Asset::new(lunc.clone(), 2_000u128))),
+        )
+        .unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Collateral {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let collateral: CollateralResponse = from_json(res).unwrap();
+        assert_eq!(
+            collateral.collateral,
+            vec![
+                CollateralEntry {
+                    asset: cw20,
+                    received: Uint128::new(7),
+                    paid_out: Uint128::zero(),
+                },
+                CollateralEntry {
+                    asset: lunc.clone(),
+                    received: Uint128::new(5_000),
+                    paid_out: Uint128::new(2_000),
+                },
+            ]
+        );
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Collateral {
+                start_after: Some(lunc),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let collateral: CollateralResponse = from_json(res).unwrap();
+        assert!(collateral.collateral.is_empty());
+    }
+
+    #[test]
+    fn test_add_minter_validation_and_listing() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let msg = ExecuteMsg::AddMinter {
+            address: "treasury".to_string(),
+            role: MinterRole::Treasury,
+            cap: None,
+        };
+        let err =
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &[]), msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        let err = execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::MinterAlreadyRegistered {
+                address: "treasury".to_string()
+            }
+        );
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Minters {
+                start_after: Some(POOL.to_string()),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: MintersResponse = from_json(res).unwrap();
+        assert_eq!(page.minters.len(), 1);
+        assert_eq!(page.minters[0].address, Addr::unchecked("treasury"));
+        assert_eq!(page.minters[0].role, MinterRole::Treasury);
+        assert_eq!(page.minters[0].remaining, None);
+    }
+
+    #[test]
+    fn test_governance_transfer() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let msg = ExecuteMsg::ProposeGovernanceTransfer {
+            new_governance: "new_gov".to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK - 1);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("new_gov", &[]),
+            ExecuteMsg::AcceptGovernanceTransfer {},
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TimelockNotExpired {
+                remaining_seconds: 1
+            }
+        );
+
+        env.block.time = env.block.time.plus_seconds(1);
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("new_gov", &[]),
+            ExecuteMsg::AcceptGovernanceTransfer {},
+        )
+        .unwrap();
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap().governance,
+            Addr::unchecked("new_gov")
+        );
+    }
+
+    #[test]
+    fn test_cr_engine_change_is_timelocked() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let msg = ExecuteMsg::ProposeCrEngine {
+            cr_engine: "new_engine".to_string(),
+        };
+        let err =
+            execute(deps.as_mut(), mock_env(), mock_info(USER, &[]), msg.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::PendingCrEngine {}).unwrap();
+        let pending: PendingCrEngineResponse = from_json(res).unwrap();
+        assert_eq!(pending.cr_engine, Some(Addr::unchecked("new_engine")));
+        assert_eq!(
+            pending.execute_after,
+            Some(mock_env().block.time.plus_seconds(GOVERNANCE_TIMELOCK))
+        );
+
+        // Tier lookups keep using the current engine until the timelock has passed
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(GOVERNANCE_TIMELOCK - 1);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::AcceptCrEngine {},
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TimelockNotExpired {
+                remaining_seconds: 1
+            }
+        );
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap().cr_engine,
+            Addr::unchecked(CR_ENGINE)
+        );
+
+        // Cancelled proposals cannot be accepted
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::CancelCrEngine {},
+        )
+        .unwrap();
+        env.block.time = env.block.time.plus_seconds(1);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::AcceptCrEngine {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoPendingCrEngine);
+
+        execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::AcceptCrEngine {},
+        )
+        .unwrap();
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap().cr_engine,
+            Addr::unchecked("new_engine")
+        );
+        assert!(PENDING_CR_ENGINE.may_load(&deps.storage).unwrap().is_none());
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use common::{AssetInfo, CrTier};
+    use cosmwasm_std::{to_json_binary, Addr, Coin, Decimal, Uint128};
+    use cw20::{Cw20ExecuteMsg, Cw20QueryMsg};
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{ExecuteMsg, InstantiateMsg, ReceiveMsg};
+    use crate::state::MinterRole;
+    use crate::ContractError;
+
+    const GOVERNANCE: &str = "governance";
+    const AUCTION: &str = "auction";
+    const POOL: &str = "rolling_pool";
+    const WINNER: &str = "winner";
+
+    /// Treasury holds 10,000 USTC ($100); controller is the UST1 minter
+    #[test]
+    fn test_mint_and_redeem_follow_live_tier() {
+        let mut app = App::default();
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let treasury_code = app.store_code(contract_wrapper!(treasury));
+        let treasury_addr = app
+            .instantiate_contract(
+                treasury_code,
+                gov.clone(),
+                &treasury::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+        app.init_modules(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &treasury_addr,
+                    vec![Coin::new(10_000_000_000u128, "uusd")],
+                )
+                .unwrap();
+        });
+
+        let cw20_code = app.store_code(cw20_contract());
+        let engine_code = app.store_code(contract_wrapper!(cr_engine));
+        let controller_code = app.store_code(contract_wrapper!(crate));
+
+        // UST1 is instantiated with governance as minter, then handed to the controller
+        let ust1_addr = instantiate_cw20(&mut app, cw20_code, GOVERNANCE, "USTONE", vec![]);
+
+        let engine_addr = app
+            .instantiate_contract(
+                engine_code,
+                gov.clone(),
+                &cr_engine::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    treasury: treasury_addr.to_string(),
+                    ust1_token: ust1_addr.to_string(),
+                    price_source: cr_engine::state::PriceSource::Fixed {},
+                    thresholds: None,
+                    prices: vec![cr_engine::msg::PriceEntry {
+                        asset: AssetInfo::native("uusd"),
+                        price: Decimal::from_atomics(10_000_000_000u128, 0).unwrap(),
+                    }],
+                },
+                &[],
+                "cr-engine",
+                None,
+            )
+            .unwrap();
+
+        let controller_addr = app
+            .instantiate_contract(
+                controller_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1_addr.to_string(),
+                    cr_engine: engine_addr.to_string(),
+                },
+                &[],
+                "ust1-controller",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            ust1_addr.clone(),
+            &Cw20ExecuteMsg::UpdateMinter {
+                new_minter: Some(controller_addr.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            controller_addr.clone(),
+            &ExecuteMsg::AddMinter {
+                address: AUCTION.to_string(),
+                role: MinterRole::Auction,
+                cap: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Zero supply: BLUE, mint 50 UST1 (CR 200%, still BLUE)
+        let fifty = Uint128::new(50_000_000_000_000_000_000);
+        app.execute_contract(
+            Addr::unchecked(AUCTION),
+            controller_addr.clone(),
+            &ExecuteMsg::Mint {
+                recipient: WINNER.to_string(),
+                amount: fifty,
+                collateral: None,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &ust1_addr, WINNER), fifty);
+
+        // Another 30 UST1 is allowed (BLUE at mint time) and drops CR to 125% (GREEN)
+        let thirty = Uint128::new(30_000_000_000_000_000_000);
+        app.execute_contract(
+            Addr::unchecked(AUCTION),
+            controller_addr.clone(),
+            &ExecuteMsg::Mint {
+                recipient: AUCTION.to_string(),
+                amount: thirty,
+                collateral: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // In GREEN the rolling pool can no longer mint, but auctions still can
+        app.execute_contract(
+            gov.clone(),
+            controller_addr.clone(),
+            &ExecuteMsg::AddMinter {
+                address: POOL.to_string(),
+                role: MinterRole::RollingPool,
+                cap: None,
+            },
+            &[],
+        )
+        .unwrap();
+        let err = app
+            .execute_contract(
+                Addr::unchecked(POOL),
+                controller_addr.clone(),
+                &ExecuteMsg::Mint {
+                    recipient: WINNER.to_string(),
+                    amount: Uint128::one(),
+                    collateral: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::MintingDisabled {
+                role: MinterRole::RollingPool,
+                tier: CrTier::Green
+            }
+        );
+        app.execute_contract(
+            Addr::unchecked(AUCTION),
+            controller_addr.clone(),
+            &ExecuteMsg::Mint {
+                recipient: WINNER.to_string(),
+                amount: Uint128::one(),
+                collateral: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Redemption still works in GREEN and burns supply
+        app.execute_contract(
+            Addr::unchecked(AUCTION),
+            ust1_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: controller_addr.to_string(),
+                amount: thirty,
+                msg: to_json_binary(&ReceiveMsg::Redeem { collateral: None }).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &ust1_addr, AUCTION), Uint128::zero());
+
+        let info: cw20::TokenInfoResponse = app
+            .wrap()
+            .query_wasm_smart(&ust1_addr, &Cw20QueryMsg::TokenInfo {})
+            .unwrap();
+        assert_eq!(info.total_supply, fifty + Uint128::one());
+    }
+}
@@ -0,0 +1,48 @@
+use common::CrTier;
+use cosmwasm_std::{StdError, Uint128};
+use thiserror::Error;
+
+use crate::state::MinterRole;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Unauthorized: only the UST1 token can call Receive")]
+    UnauthorizedToken,
+
+    #[error("Unauthorized: {address} is not a registered minter")]
+    NotAMinter { address: String },
+
+    #[error("Zero amount not allowed")]
+    ZeroAmount,
+
+    #[error("Minting disabled for {role} minters in {tier} tier")]
+    MintingDisabled { role: MinterRole, tier: CrTier },
+
+    #[error("Redemption disabled in {tier} tier")]
+    RedemptionDisabled { tier: CrTier },
+
+    #[error("Mint cap exceeded: cap {cap}, already minted {minted}, requested {requested}")]
+    MintCapExceeded {
+        cap: Uint128,
+        minted: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("Minter already registered: {address}")]
+    MinterAlreadyRegistered { address: String },
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("No pending cr-engine change")]
+    NoPendingCrEngine,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
@@ -0,0 +1,28 @@
+//! # UST1 Controller Contract
+//!
+//! Sole minter of the UST1 CW20 token. UST1 itself is a standard CW20
+//! instantiated with this contract as its minter; all supply changes go
+//! through here so they can be gated by the collateralization tier.
+//!
+//! ## Tier Gating (from the cr-engine `CurrentTier` query)
+//!
+//! | Tier   | Auction mint | Pool / treasury mint | Redeem |
+//! |--------|--------------|----------------------|--------|
+//! | RED    | no           | no                   | no     |
+//! | YELLOW | yes          | no                   | yes    |
+//! | GREEN  | yes          | no                   | yes    |
+//! | BLUE   | yes          | yes                  | yes    |
+//!
+//! ## Authorized Callers
+//!
+//! Governance registers each caller with a role (treasury, auction or
+//! rolling pool) and an optional cumulative mint cap. Only registered callers
+//! can mint or redeem. Collateral received for mints and paid out for
+//! redemptions is recorded per `AssetInfo`.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,188 @@
+use common::{Asset, AssetInfo, CrTier};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::state::MinterRole;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub ust1_token: String,
+    pub cr_engine: String,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Mints UST1 to `recipient` (registered minters only; auctions in any tier but RED,
+    /// other roles in BLUE only).
+    /// `collateral` records what was delivered to the treasury for this mint.
+    Mint {
+        recipient: String,
+        amount: Uint128,
+        collateral: Option<Asset>,
+    },
+
+    /// CW20 receive hook for UST1; the embedded message is `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
+    /// Registers a mint caller (governance-only)
+    AddMinter {
+        address: String,
+        role: MinterRole,
+        cap: Option<Uint128>,
+    },
+
+    /// Replaces a minter's cumulative cap; None removes the cap (governance-only)
+    UpdateMinterCap {
+        address: String,
+        cap: Option<Uint128>,
+    },
+
+    /// Deregisters a mint caller (governance-only)
+    RemoveMinter { address: String },
+
+    /// Proposes pointing the controller at a new cr-engine with the 7-day
+    /// governance timelock (governance-only). Replaces any pending proposal.
+    ProposeCrEngine { cr_engine: String },
+
+    /// Applies the pending cr-engine after its timelock (governance-only)
+    AcceptCrEngine {},
+
+    /// Cancels the pending cr-engine change (governance-only)
+    CancelCrEngine {},
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+/// Messages embedded in a UST1 `Send` to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Burns the sent UST1 (registered minters only, not in RED tier).
+    /// `collateral` records what the redeemer paid out for it.
+    Redeem { collateral: Option<Asset> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    /// Registration, cap and usage for one caller
+    #[returns(Option<MinterResponse>)]
+    Minter { address: String },
+
+    #[returns(MintersResponse)]
+    Minters {
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// Collateral received and paid out per asset
+    #[returns(CollateralResponse)]
+    Collateral {
+        start_after: Option<AssetInfo>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// Lifetime UST1 minted and redeemed through this controller
+    #[returns(SupplyTotalsResponse)]
+    SupplyTotals {},
+
+    /// Which minter roles may mint and whether redemption is allowed in the current tier
+    #[returns(StatusResponse)]
+    Status {},
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+
+    #[returns(PendingCrEngineResponse)]
+    PendingCrEngine {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub cr_engine: Addr,
+}
+
+#[cw_serde]
+pub struct MinterResponse {
+    pub address: Addr,
+    pub role: MinterRole,
+    pub cap: Option<Uint128>,
+    pub minted: Uint128,
+    pub redeemed: Uint128,
+    /// Remaining mintable amount under the cap (None if uncapped)
+    pub remaining: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct MintersResponse {
+    pub minters: Vec<MinterResponse>,
+}
+
+#[cw_serde]
+pub struct CollateralEntry {
+    pub asset: AssetInfo,
+    pub received: Uint128,
+    pub paid_out: Uint128,
+}
+
+#[cw_serde]
+pub struct CollateralResponse {
+    pub collateral: Vec<CollateralEntry>,
+}
+
+#[cw_serde]
+pub struct SupplyTotalsResponse {
+    pub total_minted: Uint128,
+    pub total_redeemed: Uint128,
+}
+
+#[cw_serde]
+pub struct StatusResponse {
+    pub tier: CrTier,
+    /// Minter roles allowed to mint in this tier
+    pub mint_enabled_roles: Vec<MinterRole>,
+    pub redeem_enabled: bool,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct PendingCrEngineResponse {
+    pub cr_engine: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+/// Query sent to the cr-engine for the live tier
+#[cw_serde]
+pub enum CrEngineQueryMsg {
+    CurrentTier {},
+}
+
+/// Mirrors the cr-engine's TierResponse
+#[cw_serde]
+pub struct CrEngineTierResponse {
+    pub tier: CrTier,
+    pub cr: Option<Decimal>,
+}
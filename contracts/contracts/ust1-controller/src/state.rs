@@ -0,0 +1,105 @@
+use common::AssetInfo;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:ust1-controller";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// Default page size for list queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for list queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    /// UST1 CW20 token; this contract must be its minter
+    pub ust1_token: Addr,
+    /// cr-engine contract queried for the current tier
+    pub cr_engine: Addr,
+}
+
+/// Kind of contract allowed to mint UST1
+#[cw_serde]
+#[derive(Copy)]
+pub enum MinterRole {
+    Treasury,
+    Auction,
+    RollingPool,
+}
+
+impl std::fmt::Display for MinterRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            MinterRole::Treasury => "treasury",
+            MinterRole::Auction => "auction",
+            MinterRole::RollingPool => "rolling_pool",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cw_serde]
+pub struct MinterInfo {
+    pub role: MinterRole,
+    /// Cumulative mint cap; None means uncapped
+    pub cap: Option<Uint128>,
+    /// Total UST1 minted by this caller
+    pub minted: Uint128,
+    /// Total UST1 redeemed (burned) by this caller
+    pub redeemed: Uint128,
+}
+
+/// Collateral flows recorded against UST1 mints and redemptions
+#[cw_serde]
+pub struct CollateralRecord {
+    pub asset: AssetInfo,
+    /// Collateral delivered to the treasury for minted UST1
+    pub received: Uint128,
+    /// Collateral paid out for redeemed UST1
+    pub paid_out: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct SupplyTotals {
+    pub total_minted: Uint128,
+    pub total_redeemed: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+/// cr-engine change waiting out GOVERNANCE_TIMELOCK
+#[cw_serde]
+pub struct PendingCrEngine {
+    pub cr_engine: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const PENDING_CR_ENGINE: Item<PendingCrEngine> = Item::new("pending_cr_engine");
+
+/// Registered mint callers
+pub const MINTERS: Map<&Addr, MinterInfo> = Map::new("minters");
+
+/// Key: asset key ("native:<denom>" or "cw20:<addr>")
+pub const COLLATERAL: Map<&str, CollateralRecord> = Map::new("collateral");
+
+pub const SUPPLY_TOTALS: Item<SupplyTotals> = Item::new("supply_totals");
+
+/// Storage key for an asset in COLLATERAL
+pub fn asset_key(asset: &AssetInfo) -> String {
+    match asset {
+        AssetInfo::Native { denom } => format!("native:{}", denom),
+        AssetInfo::Cw20 { contract_addr } => format!("cw20:{}", contract_addr),
+    }
+}
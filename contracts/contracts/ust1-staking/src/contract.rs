@@ -0,0 +1,1081 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, CosmosMsg, Decimal256, Deps, DepsMut, Env,
+    MessageInfo, Order, Response, StdError, StdResult, Storage, Uint128, Uint256, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20ReceiveMsg, TokenInfoResponse};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingGovernanceResponse, QueryMsg,
+    ReceiveMsg, RollingPoolConfigResponse, RollingPoolExecuteMsg, RollingPoolPendingResponse,
+    RollingPoolQueryMsg, StakerResponse, StakersResponse, StateResponse,
+};
+use crate::state::{
+    Config, PendingGovernance, ReceiptInfo, StakerInfo, StakingState, CONFIG, CONTRACT_NAME,
+    CONTRACT_VERSION, DEFAULT_QUERY_LIMIT, GOVERNANCE_TIMELOCK, MAX_QUERY_LIMIT,
+    PENDING_GOVERNANCE, STAKERS, STAKING_STATE,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    if let Some(receipt) = &msg.receipt {
+        validate_receipt(receipt)?;
+    }
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        ust1_token: deps.api.addr_validate(&msg.ust1_token)?,
+        rolling_pool: msg
+            .rolling_pool
+            .map(|a| deps.api.addr_validate(&a))
+            .transpose()?,
+        receipt: msg.receipt,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    STAKING_STATE.save(
+        deps.storage,
+        &StakingState {
+            total_staked: Uint128::zero(),
+            reward_per_token: Decimal256::zero(),
+            undistributed: Uint128::zero(),
+            prefunded: Uint128::zero(),
+            total_distributed: Uint128::zero(),
+            total_claimed: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("ust1_token", config.ust1_token)
+        .add_attribute("receipt", config.receipt.is_some().to_string()))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, env, info, cw20_msg),
+        ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::Transfer { recipient, amount } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            let res = transfer_receipt(deps, &env, &info.sender, &recipient, amount)?;
+            Ok(res
+                .add_attribute("action", "transfer")
+                .add_attribute("from", info.sender)
+                .add_attribute("to", recipient)
+                .add_attribute("amount", amount))
+        }
+        ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => {
+            let contract = deps.api.addr_validate(&contract)?;
+            let res = transfer_receipt(deps, &env, &info.sender, &contract, amount)?;
+            let receive_msg = Cw20ReceiveMsg {
+                sender: info.sender.to_string(),
+                amount,
+                msg,
+            }
+            .into_cosmos_msg(contract.to_string())?;
+            Ok(res
+                .add_message(receive_msg)
+                .add_attribute("action", "send")
+                .add_attribute("from", info.sender)
+                .add_attribute("to", contract)
+                .add_attribute("amount", amount))
+        }
+        ExecuteMsg::UpdateConfig { rolling_pool } => {
+            execute_update_config(deps, env, info, rolling_pool)
+        }
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.ust1_token {
+        return Err(ContractError::UnauthorizedToken);
+    }
+    if cw20_msg.amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    let mut state = STAKING_STATE.load(deps.storage)?;
+
+    match from_json(&cw20_msg.msg)? {
+        ReceiveMsg::Stake {} => {
+            let staker_addr = deps.api.addr_validate(&cw20_msg.sender)?;
+            let drip_msg = sync_pool(deps.as_ref(), &env, &config, &mut state)?;
+
+            let mut staker = load_staker(deps.storage, &staker_addr)?;
+            update_rewards(&state, &mut staker)?;
+            staker.staked = staker
+                .staked
+                .checked_add(cw20_msg.amount)
+                .map_err(StdError::from)?;
+            state.total_staked = state
+                .total_staked
+                .checked_add(cw20_msg.amount)
+                .map_err(StdError::from)?;
+            if !state.undistributed.is_zero() {
+                distribute(&mut state, Uint128::zero())?;
+            }
+            STAKERS.save(deps.storage, &staker_addr, &staker)?;
+            STAKING_STATE.save(deps.storage, &state)?;
+
+            Ok(Response::new()
+                .add_messages(drip_msg)
+                .add_attribute("action", "stake")
+                .add_attribute("staker", staker_addr)
+                .add_attribute("amount", cw20_msg.amount)
+                .add_attribute("staked", staker.staked))
+        }
+        ReceiveMsg::DistributeRewards {} => {
+            // Drips already credited by a sync only settle the prefunded amount
+            let covered = cw20_msg.amount.min(state.prefunded);
+            state.prefunded -= covered;
+            distribute(&mut state, cw20_msg.amount - covered)?;
+            STAKING_STATE.save(deps.storage, &state)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "distribute_rewards")
+                .add_attribute("amount", cw20_msg.amount)
+                .add_attribute("prefunded", covered)
+                .add_attribute("reward_per_token", state.reward_per_token.to_string()))
+        }
+    }
+}
+
+fn execute_unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let mut staker = load_staker(deps.storage, &info.sender)?;
+    if amount > staker.staked {
+        return Err(ContractError::InsufficientStake {
+            requested: amount,
+            staked: staker.staked,
+        });
+    }
+
+    let drip_msg = sync_pool(deps.as_ref(), &env, &config, &mut state)?;
+    update_rewards(&state, &mut staker)?;
+    staker.staked -= amount;
+    state.total_staked -= amount;
+    save_staker(deps.storage, &info.sender, &staker)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_message(transfer_msg)
+        .add_attribute("action", "unstake")
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("staked", staker.staked))
+}
+
+fn execute_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let mut staker = load_staker(deps.storage, &info.sender)?;
+
+    let drip_msg = sync_pool(deps.as_ref(), &env, &config, &mut state)?;
+    update_rewards(&state, &mut staker)?;
+    let amount = staker.pending_rewards;
+    if amount.is_zero() {
+        return Err(ContractError::NoRewards);
+    }
+    staker.pending_rewards = Uint128::zero();
+    state.total_claimed = state
+        .total_claimed
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    save_staker(deps.storage, &info.sender, &staker)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    // The drip runs first, so prefunded rewards are in the contract before the transfer
+    let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_message(transfer_msg)
+        .add_attribute("action", "claim")
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount))
+}
+
+/// Moves receipt (stake) between holders after settling both parties' rewards
+fn transfer_receipt(
+    deps: DepsMut,
+    env: &Env,
+    from: &Addr,
+    to: &Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.receipt.is_none() {
+        return Err(ContractError::ReceiptDisabled);
+    }
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    if from == to {
+        return Err(ContractError::SelfTransfer);
+    }
+
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let mut sender = load_staker(deps.storage, from)?;
+    if amount > sender.staked {
+        return Err(ContractError::InsufficientStake {
+            requested: amount,
+            staked: sender.staked,
+        });
+    }
+    let mut recipient = load_staker(deps.storage, to)?;
+
+    let drip_msg = sync_pool(deps.as_ref(), env, &config, &mut state)?;
+    update_rewards(&state, &mut sender)?;
+    update_rewards(&state, &mut recipient)?;
+    sender.staked -= amount;
+    recipient.staked = recipient
+        .staked
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    save_staker(deps.storage, from, &sender)?;
+    save_staker(deps.storage, to, &recipient)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new().add_messages(drip_msg))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    rolling_pool: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Settle the old pool before switching
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let drip_msg = sync_pool(deps.as_ref(), &env, &config, &mut state)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    if let Some(rolling_pool) = rolling_pool {
+        config.rolling_pool = Some(deps.api.addr_validate(&rolling_pool)?);
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_attribute("action", "update_config"))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::State {} => to_json_binary(&query_state(deps)?),
+        QueryMsg::Staker { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&query_staker(deps, &env, address)?)
+        }
+        QueryMsg::Stakers { start_after, limit } => {
+            to_json_binary(&query_stakers(deps, env, start_after, limit)?)
+        }
+        QueryMsg::Balance { address } => to_json_binary(&query_balance(deps, address)?),
+        QueryMsg::TokenInfo {} => to_json_binary(&query_token_info(deps)?),
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        ust1_token: config.ust1_token,
+        rolling_pool: config.rolling_pool,
+        receipt: config.receipt,
+    })
+}
+
+fn query_state(deps: Deps) -> StdResult<StateResponse> {
+    let state = STAKING_STATE.load(deps.storage)?;
+    Ok(StateResponse {
+        total_staked: state.total_staked,
+        reward_per_token: state.reward_per_token,
+        undistributed: state.undistributed,
+        prefunded: state.prefunded,
+        total_distributed: state.total_distributed,
+        total_claimed: state.total_claimed,
+    })
+}
+
+fn query_staker(deps: Deps, env: &Env, address: Addr) -> StdResult<StakerResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    sync_pool(deps, env, &config, &mut state)?;
+
+    let mut staker = load_staker(deps.storage, &address)?;
+    update_rewards(&state, &mut staker)?;
+    Ok(StakerResponse {
+        address,
+        staked: staker.staked,
+        pending_rewards: staker.pending_rewards,
+    })
+}
+
+fn query_stakers(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<StakersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let addresses = STAKERS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let stakers = addresses
+        .into_iter()
+        .map(|address| query_staker(deps, &env, address))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(StakersResponse { stakers })
+}
+
+fn query_balance(deps: Deps, address: String) -> StdResult<BalanceResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let staker = load_staker(deps.storage, &address)?;
+    Ok(BalanceResponse {
+        balance: staker.staked,
+    })
+}
+
+fn query_token_info(deps: Deps) -> StdResult<TokenInfoResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let receipt = config
+        .receipt
+        .ok_or_else(|| StdError::generic_err(ContractError::ReceiptDisabled.to_string()))?;
+    let state = STAKING_STATE.load(deps.storage)?;
+    Ok(TokenInfoResponse {
+        name: receipt.name,
+        symbol: receipt.symbol,
+        decimals: receipt.decimals,
+        total_supply: state.total_staked,
+    })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+/// Credits what the rolling pool has accrued up to now and returns the drip
+/// that delivers it. Pools not dripping to this contract with the
+/// `DistributeRewards` hook are skipped, since their drips would never
+/// settle the prefunded amount.
+fn sync_pool(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    state: &mut StakingState,
+) -> StdResult<Option<CosmosMsg>> {
+    let Some(pool) = &config.rolling_pool else {
+        return Ok(None);
+    };
+
+    let pool_config: RollingPoolConfigResponse = deps
+        .querier
+        .query_wasm_smart(pool, &RollingPoolQueryMsg::Config {})?;
+    let hook = to_json_binary(&ReceiveMsg::DistributeRewards {})?;
+    if pool_config.beneficiary.as_ref() != Some(&env.contract.address)
+        || pool_config.hook_msg != Some(hook)
+    {
+        return Ok(None);
+    }
+
+    let pending: RollingPoolPendingResponse = deps.querier.query_wasm_smart(
+        pool,
+        &RollingPoolQueryMsg::PendingDistribution {
+            start: None,
+            end: env.block.time.seconds(),
+        },
+    )?;
+    let amount = pending.accrued_before_start;
+    if amount.is_zero() {
+        return Ok(None);
+    }
+
+    distribute(state, amount)?;
+    state.prefunded = state.prefunded.checked_add(amount)?;
+
+    Ok(Some(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: pool.to_string(),
+        msg: to_json_binary(&RollingPoolExecuteMsg::Drip {})?,
+        funds: vec![],
+    })))
+}
+
+/// Credits `amount` (plus anything held back) to the reward index, or holds
+/// it back if nothing is staked
+fn distribute(state: &mut StakingState, amount: Uint128) -> StdResult<()> {
+    state.total_distributed = state.total_distributed.checked_add(amount)?;
+    let amount = state.undistributed.checked_add(amount)?;
+    if state.total_staked.is_zero() {
+        state.undistributed = amount;
+        return Ok(());
+    }
+    state.reward_per_token += Decimal256::from_ratio(amount, state.total_staked);
+    state.undistributed = Uint128::zero();
+    Ok(())
+}
+
+/// Moves rewards earned since the staker's last update into `pending_rewards`
+fn update_rewards(state: &StakingState, staker: &mut StakerInfo) -> StdResult<()> {
+    let earned =
+        Uint256::from(staker.staked).mul_floor(state.reward_per_token - staker.reward_index);
+    let earned =
+        Uint128::try_from(earned).map_err(|e| StdError::ConversionOverflow { source: e })?;
+    staker.pending_rewards = staker.pending_rewards.checked_add(earned)?;
+    staker.reward_index = state.reward_per_token;
+    Ok(())
+}
+
+fn load_staker(storage: &dyn Storage, address: &Addr) -> StdResult<StakerInfo> {
+    Ok(STAKERS.may_load(storage, address)?.unwrap_or_default())
+}
+
+/// Saves a staker, dropping the entry once nothing is staked or owed
+fn save_staker(storage: &mut dyn Storage, address: &Addr, staker: &StakerInfo) -> StdResult<()> {
+    if staker.staked.is_zero() && staker.pending_rewards.is_zero() {
+        STAKERS.remove(storage, address);
+        return Ok(());
+    }
+    STAKERS.save(storage, address, staker)
+}
+
+/// Same rules as cw20-base token info
+fn validate_receipt(receipt: &ReceiptInfo) -> Result<(), ContractError> {
+    if receipt.name.len() < 3 || receipt.name.len() > 50 {
+        return Err(ContractError::InvalidReceipt {
+            reason: "name must be 3-50 characters".to_string(),
+        });
+    }
+    let symbol_ok = (3..=12).contains(&receipt.symbol.len())
+        && receipt
+            .symbol
+            .bytes()
+            .all(|c| c.is_ascii_alphabetic() || c == b'-');
+    if !symbol_ok {
+        return Err(ContractError::InvalidReceipt {
+            reason: "symbol must be 3-12 characters of [a-zA-Z-]".to_string(),
+        });
+    }
+    if receipt.decimals > 18 {
+        return Err(ContractError::InvalidReceipt {
+            reason: "decimals must not exceed 18".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::OwnedDeps;
+
+    const GOVERNANCE: &str = "governance";
+    const UST1: &str = "ust1_token";
+    const POOL: &str = "rolling_pool";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn receipt_info() -> ReceiptInfo {
+        ReceiptInfo {
+            name: "Staked UST1".to_string(),
+            symbol: "sUSTONE".to_string(),
+            decimals: 18,
+        }
+    }
+
+    fn setup_contract(deps: &mut MockDeps, receipt: Option<ReceiptInfo>) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ust1_token: UST1.to_string(),
+            rolling_pool: None,
+            receipt,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+    }
+
+    fn receive(
+        deps: &mut MockDeps,
+        sender: &str,
+        amount: u128,
+        msg: ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_json_binary(&msg).unwrap(),
+        });
+        execute(deps.as_mut(), mock_env(), mock_info(UST1, &[]), msg)
+    }
+
+    fn stake(deps: &mut MockDeps, staker: &str, amount: u128) {
+        receive(deps, staker, amount, ReceiveMsg::Stake {}).unwrap();
+    }
+
+    fn distribute(deps: &mut MockDeps, amount: u128) {
+        receive(deps, POOL, amount, ReceiveMsg::DistributeRewards {}).unwrap();
+    }
+
+    fn staker(deps: &MockDeps, address: &str) -> StakerResponse {
+        query_staker(deps.as_ref(), &mock_env(), Addr::unchecked(address)).unwrap()
+    }
+
+    #[test]
+    fn test_partial_unstake_keeps_earned_rewards() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        stake(&mut deps, ALICE, 100);
+        stake(&mut deps, BOB, 100);
+        distribute(&mut deps, 200);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Unstake {
+                amount: Uint128::new(60),
+            },
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, UST1);
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: ALICE.to_string(),
+                        amount: Uint128::new(60),
+                    }
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+
+        // 40 vs 100 staked for the next distribution
+        distribute(&mut deps, 140);
+        let alice = staker(&deps, ALICE);
+        assert_eq!(alice.staked, Uint128::new(40));
+        assert_eq!(alice.pending_rewards, Uint128::new(140));
+        assert_eq!(staker(&deps, BOB).pending_rewards, Uint128::new(200));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Unstake {
+                amount: Uint128::new(41),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientStake {
+                requested: Uint128::new(41),
+                staked: Uint128::new(40),
+            }
+        );
+    }
+
+    #[test]
+    fn test_claim_sends_rewards_once() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        stake(&mut deps, ALICE, 100);
+        distribute(&mut deps, 70);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Claim {},
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Claim {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoRewards);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: ALICE.to_string(),
+                amount: Uint128::new(1),
+                msg: to_json_binary(&ReceiveMsg::Stake {}).unwrap(),
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::UnauthorizedToken);
+    }
+
+    #[test]
+    fn test_receipt_transfer_settles_both_parties() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, Some(receipt_info()));
+        stake(&mut deps, ALICE, 100);
+        stake(&mut deps, BOB, 100);
+        distribute(&mut deps, 200);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Transfer {
+                recipient: BOB.to_string(),
+                amount: Uint128::new(50),
+            },
+        )
+        .unwrap();
+        distribute(&mut deps, 300);
+
+        // Rewards before the transfer stay with the sender
+        let alice = staker(&deps, ALICE);
+        assert_eq!(alice.staked, Uint128::new(50));
+        assert_eq!(alice.pending_rewards, Uint128::new(100 + 75));
+        let bob = staker(&deps, BOB);
+        assert_eq!(bob.staked, Uint128::new(150));
+        assert_eq!(bob.pending_rewards, Uint128::new(100 + 225));
+
+        assert_eq!(
+            query_balance(deps.as_ref(), BOB.to_string())
+                .unwrap()
+                .balance,
+            Uint128::new(150)
+        );
+        let info = query_token_info(deps.as_ref()).unwrap();
+        assert_eq!(info.symbol, "sUSTONE");
+        assert_eq!(info.total_supply, Uint128::new(200));
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BOB, &[]),
+            ExecuteMsg::Send {
+                contract: "vault".to_string(),
+                amount: Uint128::new(10),
+                msg: to_json_binary(&"deposit").unwrap(),
+            },
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                assert_eq!(contract_addr, "vault")
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+        assert_eq!(staker(&deps, "vault").staked, Uint128::new(10));
+    }
+
+    #[test]
+    fn test_receipt_disabled_or_invalid() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps, None);
+        stake(&mut deps, ALICE, 100);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Transfer {
+                recipient: BOB.to_string(),
+                amount: Uint128::new(50),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::ReceiptDisabled);
+        assert!(query_token_info(deps.as_ref()).is_err());
+
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ust1_token: UST1.to_string(),
+            rolling_pool: None,
+            receipt: Some(ReceiptInfo {
+                symbol: "S1".to_string(),
+                ..receipt_info()
+            }),
+        };
+        let err =
+            instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidReceipt { .. }));
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use cosmwasm_std::{to_json_binary, Addr, Uint128};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{
+        ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg, StakerResponse, StateResponse,
+    };
+    use crate::state::ReceiptInfo;
+
+    const GOVERNANCE: &str = "governance";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+    const CAROL: &str = "carol";
+
+    /// A tenth of the rolling pool's 5-year period
+    const TENTH: u64 = 15_768_000;
+
+    fn staker(app: &App, staking: &Addr, address: &str) -> StakerResponse {
+        app.wrap()
+            .query_wasm_smart(
+                staking,
+                &QueryMsg::Staker {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_actions_drip_pool_and_settle_rewards() {
+        let mut app = App::default();
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let cw20_code = app.store_code(cw20_contract());
+        let pool_code = app.store_code(contract_wrapper!(rolling_pool));
+        let staking_code = app.store_code(contract_wrapper!(crate));
+
+        let ust1 = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTONE",
+            vec![
+                Cw20Coin {
+                    address: ALICE.to_string(),
+                    amount: Uint128::new(1_000),
+                },
+                Cw20Coin {
+                    address: BOB.to_string(),
+                    amount: Uint128::new(1_000),
+                },
+            ],
+        );
+
+        let pool = app
+            .instantiate_contract(
+                pool_code,
+                gov.clone(),
+                &rolling_pool::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    beneficiary: None,
+                },
+                &[],
+                "rolling-pool",
+                None,
+            )
+            .unwrap();
+        let staking = app
+            .instantiate_contract(
+                staking_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    rolling_pool: Some(pool.to_string()),
+                    receipt: Some(ReceiptInfo {
+                        name: "Staked UST1".to_string(),
+                        symbol: "sUSTONE".to_string(),
+                        decimals: 18,
+                    }),
+                },
+                &[],
+                "ust1-staking",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::SetBeneficiary {
+                beneficiary: Some(rolling_pool::msg::BeneficiaryMsg {
+                    address: staking.to_string(),
+                    hook_msg: Some(to_json_binary(&ReceiveMsg::DistributeRewards {}).unwrap()),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            ust1.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: pool.to_string(),
+                amount: Uint128::new(100_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::Accrue {},
+            &[],
+        )
+        .unwrap();
+
+        let stake = |app: &mut App, staker: &str| {
+            app.execute_contract(
+                Addr::unchecked(staker),
+                ust1.clone(),
+                &Cw20ExecuteMsg::Send {
+                    contract: staking.to_string(),
+                    amount: Uint128::new(1_000),
+                    msg: to_json_binary(&ReceiveMsg::Stake {}).unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+        };
+
+        stake(&mut app, ALICE);
+        app.update_block(|block| block.time = block.time.plus_seconds(TENTH));
+
+        // Bob's stake first drips the 10,000 Alice earned alone
+        stake(&mut app, BOB);
+        assert_eq!(
+            staker(&app, &staking, ALICE).pending_rewards,
+            Uint128::new(10_000)
+        );
+        assert_eq!(staker(&app, &staking, BOB).pending_rewards, Uint128::zero());
+        assert_eq!(balance(&app, &ust1, staking.as_str()), Uint128::new(12_000));
+
+        // 9,000 more accrues (a tenth of the remaining 90,000), split evenly;
+        // the receipt transfer settles it before the stake moves
+        app.update_block(|block| block.time = block.time.plus_seconds(TENTH));
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            staking.clone(),
+            &ExecuteMsg::Transfer {
+                recipient: CAROL.to_string(),
+                amount: Uint128::new(500),
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            staker(&app, &staking, ALICE).pending_rewards,
+            Uint128::new(14_500)
+        );
+        assert_eq!(
+            staker(&app, &staking, BOB).pending_rewards,
+            Uint128::new(4_500)
+        );
+        assert_eq!(staker(&app, &staking, CAROL).staked, Uint128::new(500));
+
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            staking.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &ust1, ALICE), Uint128::new(14_500));
+
+        let state: StateResponse = app
+            .wrap()
+            .query_wasm_smart(&staking, &QueryMsg::State {})
+            .unwrap();
+        assert_eq!(state.prefunded, Uint128::zero());
+        assert_eq!(state.total_distributed, Uint128::new(19_000));
+        assert_eq!(balance(&app, &ust1, staking.as_str()), Uint128::new(6_500));
+    }
+}
@@ -0,0 +1,38 @@
+use cosmwasm_std::{StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Unauthorized: only the UST1 token can call Receive")]
+    UnauthorizedToken,
+
+    #[error("Zero amount not allowed")]
+    ZeroAmount,
+
+    #[error("Insufficient stake: requested {requested}, staked {staked}")]
+    InsufficientStake { requested: Uint128, staked: Uint128 },
+
+    #[error("No rewards to claim")]
+    NoRewards,
+
+    #[error("Liquid receipt token is not enabled")]
+    ReceiptDisabled,
+
+    #[error("Cannot transfer receipt to self")]
+    SelfTransfer,
+
+    #[error("Invalid receipt token info: {reason}")]
+    InvalidReceipt { reason: String },
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
@@ -0,0 +1,26 @@
+//! # UST1 Staking Contract
+//!
+//! Stakes UST1 and distributes UST1 rewards streamed from a rolling pool.
+//!
+//! ## Rewards
+//!
+//! Rewards use a reward-per-token index, as in USTR staking. Before every
+//! stake, unstake, claim or receipt transfer the contract credits whatever the
+//! rolling pool has accrued so far and dispatches the pool's `Drip`, so the
+//! pool's emission rate is recomputed on each of these actions and rewards
+//! are always settled against the stake that earned them. The credited
+//! amount is tracked as `prefunded` until the drip arrives.
+//!
+//! ## Liquid Receipt
+//!
+//! If configured with receipt token info, the contract also acts as a CW20
+//! receipt token (`Transfer`, `Send`, `Balance`, `TokenInfo`) whose balance is
+//! the holder's stake. A transfer settles rewards for both parties before the
+//! stake moves, so rewards earned so far stay with the sender.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,163 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary, Decimal256, Timestamp, Uint128};
+use cw20::{BalanceResponse, Cw20ReceiveMsg, TokenInfoResponse};
+
+use crate::state::ReceiptInfo;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub ust1_token: String,
+    pub rolling_pool: Option<String>,
+    /// Enables the liquid receipt token if set
+    pub receipt: Option<ReceiptInfo>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// UST1 receive hook; the embedded message is `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
+    /// Unstakes part or all of the caller's UST1
+    Unstake { amount: Uint128 },
+
+    /// Sends the caller's pending UST1 rewards
+    Claim {},
+
+    /// Transfers receipt (stake) to another address (receipt only)
+    Transfer { recipient: String, amount: Uint128 },
+
+    /// Transfers receipt to a contract and calls its CW20 receive hook (receipt only)
+    Send {
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+
+    /// Sets the rolling pool (governance-only)
+    UpdateConfig { rolling_pool: Option<String> },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+/// Messages embedded in a UST1 `Send` to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Stakes the sent UST1 for the sender
+    Stake {},
+
+    /// Distributes the sent UST1 to stakers (the rolling pool's hook message)
+    DistributeRewards {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    #[returns(StateResponse)]
+    State {},
+
+    /// Stake and rewards, including rewards the rolling pool has accrued but
+    /// not yet dripped
+    #[returns(StakerResponse)]
+    Staker { address: String },
+
+    /// Stakers ordered by address
+    #[returns(StakersResponse)]
+    Stakers {
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// CW20-compatible receipt balance (equal to the stake)
+    #[returns(BalanceResponse)]
+    Balance { address: String },
+
+    /// CW20-compatible receipt token info (receipt only)
+    #[returns(TokenInfoResponse)]
+    TokenInfo {},
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub rolling_pool: Option<Addr>,
+    pub receipt: Option<ReceiptInfo>,
+}
+
+#[cw_serde]
+pub struct StateResponse {
+    pub total_staked: Uint128,
+    pub reward_per_token: Decimal256,
+    pub undistributed: Uint128,
+    pub prefunded: Uint128,
+    pub total_distributed: Uint128,
+    pub total_claimed: Uint128,
+}
+
+#[cw_serde]
+pub struct StakerResponse {
+    pub address: Addr,
+    pub staked: Uint128,
+    pub pending_rewards: Uint128,
+}
+
+#[cw_serde]
+pub struct StakersResponse {
+    pub stakers: Vec<StakerResponse>,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+/// Queries sent to the rolling pool
+#[cw_serde]
+pub enum RollingPoolQueryMsg {
+    Config {},
+    PendingDistribution { start: Option<u64>, end: u64 },
+}
+
+/// Mirrors the rolling pool's ConfigResponse
+#[cw_serde]
+pub struct RollingPoolConfigResponse {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub beneficiary: Option<Addr>,
+    pub hook_msg: Option<Binary>,
+}
+
+/// Mirrors the rolling pool's PendingDistributionResponse
+#[cw_serde]
+pub struct RollingPoolPendingResponse {
+    pub start: u64,
+    pub end: u64,
+    pub amount: Uint128,
+    pub accrued_before_start: Uint128,
+    pub rate_per_second: Decimal256,
+}
+
+/// Drip message sent to the rolling pool
+#[cw_serde]
+pub enum RollingPoolExecuteMsg {
+    Drip {},
+}
@@ -0,0 +1,65 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal256, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:ust1-staking";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// Default page size for list queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for list queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    /// Rolling pool dripping into this contract; synced on every action
+    pub rolling_pool: Option<Addr>,
+    /// Liquid receipt token info; None disables receipt transfers
+    pub receipt: Option<ReceiptInfo>,
+}
+
+#[cw_serde]
+pub struct ReceiptInfo {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+#[cw_serde]
+pub struct StakingState {
+    pub total_staked: Uint128,
+    /// Cumulative UST1 rewarded per staked UST1 base unit
+    pub reward_per_token: Decimal256,
+    /// Rewards received while nothing was staked
+    pub undistributed: Uint128,
+    /// Rewards credited from the rolling pool whose drip has not arrived yet
+    pub prefunded: Uint128,
+    pub total_distributed: Uint128,
+    pub total_claimed: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct StakerInfo {
+    pub staked: Uint128,
+    /// `reward_per_token` at the staker's last update
+    pub reward_index: Decimal256,
+    /// Rewards earned up to the last update and not yet claimed
+    pub pending_rewards: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const STAKING_STATE: Item<StakingState> = Item::new("staking_state");
+pub const STAKERS: Map<&Addr, StakerInfo> = Map::new("stakers");
@@ -3,25 +3,32 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
-    QueryRequest, Response, StdError, StdResult, Timestamp, Uint128, WasmMsg, WasmQuery,
+    attr, to_json_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, Order, QuerierWrapper, QueryRequest, Response, StdError, StdResult,
+    Timestamp, Uint128, WasmMsg, WasmQuery,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+use cw_storage_plus::Bound;
 
 use crate::error::ContractError;
 use crate::msg::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, LeaderboardEntry, MigrateMsg, PendingAdminResponse,
-    QueryMsg, RateResponse, ReferralCodeStatsResponse, ReferralLeaderboardResponse,
-    SimulationResponse, StatsResponse, StatusResponse,
+    QueryMsg, RateResponse, RateScheduleResponse, ReferralCodeStatsResponse,
+    ReferralLeaderboardResponse, SimulationResponse, StatsResponse, StatusResponse,
+    SwapReceiptResponse, SwapReceiptsResponse, UserStatsResponse,
 };
 use crate::state::{
-    Config, LeaderboardLink, PendingAdmin, ReferralCodeStats, Stats, ADMIN_TIMELOCK_DURATION,
-    CONFIG, CONTRACT_NAME, CONTRACT_VERSION, DECIMAL_ADJUSTMENT, DEFAULT_LEADERBOARD_LIMIT,
-    DEFAULT_SWAP_DURATION, LEADERBOARD_HEAD, LEADERBOARD_LINKS, LEADERBOARD_SIZE, LEADERBOARD_TAIL,
-    MAX_LEADERBOARD_LIMIT, MAX_LEADERBOARD_SIZE, MIN_SWAP_AMOUNT, MINT_SAFETY_LIMIT_DENOMINATOR,
-    MINT_SAFETY_LIMIT_NUMERATOR, PENDING_ADMIN, REFERRAL_BONUS_DENOMINATOR, REFERRAL_BONUS_NUMERATOR,
-    REFERRAL_CODE_STATS, STATS, USTC_DENOM,
+    Config, LeaderboardLink, PendingAdmin, RatePoint, RateSchedule, ReferralCodeStats, Stats,
+    SwapReceipt, UserSwapStats, ADMIN_TIMELOCK_DURATION, CONFIG, CONTRACT_NAME, CONTRACT_VERSION,
+    DECIMAL_ADJUSTMENT, DEFAULT_BACKFILL_LIMIT, DEFAULT_LEADERBOARD_LIMIT, DEFAULT_RATE_SAMPLES,
+    DEFAULT_RECEIPT_LIMIT, DEFAULT_SWAP_DURATION, LEADERBOARD_HEAD, LEADERBOARD_LINKS,
+    LEADERBOARD_SIZE, LEADERBOARD_TAIL, MAX_BACKFILL_LIMIT, MAX_LEADERBOARD_LIMIT,
+    MAX_LEADERBOARD_SIZE, MAX_RATE_BREAKPOINTS, MAX_RATE_SAMPLES, MAX_RECEIPT_LIMIT,
+    MINT_SAFETY_LIMIT_DENOMINATOR, MINT_SAFETY_LIMIT_NUMERATOR, MIN_SWAP_AMOUNT, NEXT_SWAP_ID,
+    PENDING_ADMIN, REFERRAL_BACKFILL_CURSOR, REFERRAL_BONUS_DENOMINATOR, REFERRAL_BONUS_NUMERATOR,
+    REFERRAL_CODE_STATS, REFERRAL_RANKING, REFERRAL_REWARD_COUNTS, STATS, SWAP_RECEIPTS,
+    SWAP_RECEIPTS_BY_TIME, USER_SWAP_RECEIPTS, USER_SWAP_STATS, USTC_DENOM,
 };
 use common::AssetInfo;
 
@@ -59,6 +66,15 @@ pub enum LeaderboardAction {
     NoChange,
 }
 
+/// Optional slippage bounds supplied with a swap or deposit
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SwapLimits {
+    /// Minimum USTR to the user (base + user bonus)
+    pub min_total_ustr: Option<Uint128>,
+    /// Maximum USTC per USTR rate
+    pub max_rate: Option<Decimal>,
+}
+
 /// Expected USTR decimals (18 decimals like most ERC20-style tokens)
 const EXPECTED_USTR_DECIMALS: u8 = 18;
 
@@ -113,7 +129,9 @@ pub fn instantiate(
         end_rate: msg.end_rate,
         admin: admin.clone(),
         paused: false,
+        rate_schedule: msg.rate_schedule.unwrap_or_default(),
     };
+    validate_rate_schedule(&config)?;
 
     let stats = Stats {
         total_ustc_received: Uint128::zero(),
@@ -144,7 +162,7 @@ pub fn instantiate(
 // ============ MIGRATE ============
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     // Verify we're migrating from the same contract
     let ver = get_contract_version(deps.storage)?;
     if ver.contract != CONTRACT_NAME {
@@ -154,13 +172,89 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         ))));
     }
 
+    // Start the referral backfill; `BackfillReferralCodes` finishes it if there
+    // are more codes than one page
+    let backfill = backfill_referral_codes(deps.branch(), None, DEFAULT_BACKFILL_LIMIT)?;
+
     // Update contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::new()
         .add_attribute("action", "migrate")
         .add_attribute("from_version", ver.version)
-        .add_attribute("to_version", CONTRACT_VERSION))
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_attributes(backfill.attributes()))
+}
+
+/// Outcome of one referral backfill page
+struct ReferralBackfill {
+    processed: u32,
+    /// Codes whose owner lookup failed; they keep no cached owner until their
+    /// next swap or `RefreshReferralOwner`
+    failed_owner_lookups: Vec<String>,
+    complete: bool,
+}
+
+impl ReferralBackfill {
+    fn attributes(&self) -> Vec<Attribute> {
+        let mut attributes = vec![
+            attr("referral_codes_processed", self.processed.to_string()),
+            attr("referral_backfill_complete", self.complete.to_string()),
+        ];
+        if !self.failed_owner_lookups.is_empty() {
+            attributes.push(attr(
+                "referral_owner_lookups_failed",
+                self.failed_owner_lookups.join(","),
+            ));
+        }
+        attributes
+    }
+}
+
+/// Rank codes used before the full ranking existed and cache owners of codes
+/// used before owner caching, for up to `limit` codes after `start_after`
+/// Idempotent; saves the cursor while codes remain
+fn backfill_referral_codes(
+    deps: DepsMut,
+    start_after: Option<String>,
+    limit: u32,
+) -> Result<ReferralBackfill, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let mut page = REFERRAL_CODE_STATS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit as usize + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let complete = page.len() <= limit as usize;
+    page.truncate(limit as usize);
+
+    let mut failed_owner_lookups = vec![];
+    for (code, stats) in page.iter_mut() {
+        // No-op for codes already ranked, so re-running a page is safe
+        let rewards = stats.total_rewards_earned;
+        update_referral_ranking(deps.storage, code, rewards, rewards)?;
+        if stats.owner.is_none() {
+            // A failed lookup must not block the ranking or the remaining codes
+            match query_referral_code(&deps.querier, &config, code) {
+                Ok(response) => {
+                    stats.owner = response.owner;
+                    REFERRAL_CODE_STATS.save(deps.storage, code, stats)?;
+                }
+                Err(_) => failed_owner_lookups.push(code.clone()),
+            }
+        }
+    }
+
+    match page.last() {
+        Some((code, _)) if !complete => REFERRAL_BACKFILL_CURSOR.save(deps.storage, code)?,
+        _ => REFERRAL_BACKFILL_CURSOR.remove(deps.storage),
+    }
+
+    Ok(ReferralBackfill {
+        processed: page.len() as u32,
+        failed_owner_lookups,
+        complete,
+    })
 }
 
 // ============ EXECUTE ============
@@ -176,7 +270,39 @@ pub fn execute(
         ExecuteMsg::Swap {
             referral_code,
             leaderboard_hint,
-        } => execute_swap(deps, env, info, referral_code, leaderboard_hint),
+            min_total_ustr,
+            max_rate,
+        } => execute_swap(
+            deps,
+            env,
+            info,
+            referral_code,
+            leaderboard_hint,
+            SwapLimits {
+                min_total_ustr,
+                max_rate,
+            },
+        ),
+        ExecuteMsg::NotifyDeposit {
+            depositor,
+            amount,
+            referral_code,
+            leaderboard_hint,
+            min_total_ustr,
+            max_rate,
+        } => execute_notify_deposit(
+            deps,
+            env,
+            info,
+            depositor,
+            amount,
+            referral_code,
+            leaderboard_hint,
+            SwapLimits {
+                min_total_ustr,
+                max_rate,
+            },
+        ),
         ExecuteMsg::EmergencyPause {} => execute_emergency_pause(deps, info),
         ExecuteMsg::EmergencyResume {} => execute_emergency_resume(deps, info),
         ExecuteMsg::ProposeAdmin { new_admin } => execute_propose_admin(deps, env, info, new_admin),
@@ -187,6 +313,8 @@ pub fn execute(
             amount,
             recipient,
         } => execute_recover_asset(deps, env, info, asset, amount, recipient),
+        ExecuteMsg::RefreshReferralOwner { code } => execute_refresh_referral_owner(deps, code),
+        ExecuteMsg::BackfillReferralCodes { limit } => execute_backfill_referral_codes(deps, limit),
     }
 }
 
@@ -198,23 +326,11 @@ fn execute_swap(
     info: MessageInfo,
     referral_code: Option<String>,
     leaderboard_hint: Option<crate::msg::LeaderboardHint>,
+    limits: SwapLimits,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    // Check if paused
-    if config.paused {
-        return Err(ContractError::SwapPaused);
-    }
-
-    // Check if swap period has started
-    if env.block.time < config.start_time {
-        return Err(ContractError::SwapNotStarted);
-    }
-
-    // Check if swap period has ended
-    if env.block.time >= config.end_time {
-        return Err(ContractError::SwapEnded);
-    }
+    check_swap_active(&config, &env)?;
 
     // Validate funds sent
     if info.funds.is_empty() {
@@ -234,6 +350,125 @@ fn execute_swap(
         return Err(ContractError::BelowMinimumSwap);
     }
 
+    process_swap(
+        deps,
+        env,
+        config,
+        info.sender,
+        ustc_amount,
+        referral_code,
+        leaderboard_hint,
+        limits,
+        true,
+    )
+}
+
+/// Handle deposit notification from Treasury (tax-optimized SwapDeposit path)
+/// The USTC is already held by the Treasury, so only USTR minting happens here
+#[allow(clippy::too_many_arguments)]
+fn execute_notify_deposit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    depositor: String,
+    amount: Uint128,
+    referral_code: Option<String>,
+    leaderboard_hint: Option<crate::msg::LeaderboardHint>,
+    limits: SwapLimits,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the configured Treasury can report deposits
+    if info.sender != config.treasury {
+        return Err(ContractError::UnauthorizedTreasury);
+    }
+
+    check_swap_active(&config, &env)?;
+
+    // Treasury enforces the same minimum, but re-check to keep invariants local
+    if amount < Uint128::from(MIN_SWAP_AMOUNT) {
+        return Err(ContractError::BelowMinimumSwap);
+    }
+
+    let depositor_addr = deps.api.addr_validate(&depositor)?;
+
+    process_swap(
+        deps,
+        env,
+        config,
+        depositor_addr,
+        amount,
+        referral_code,
+        leaderboard_hint,
+        limits,
+        false,
+    )
+}
+
+/// Ensure the swap is not paused and the current time is within the swap period
+fn check_swap_active(config: &Config, env: &Env) -> Result<(), ContractError> {
+    // Check if paused
+    if config.paused {
+        return Err(ContractError::SwapPaused);
+    }
+
+    // Check if swap period has started
+    if env.block.time < config.start_time {
+        return Err(ContractError::SwapNotStarted);
+    }
+
+    // Check if swap period has ended
+    if env.block.time >= config.end_time {
+        return Err(ContractError::SwapEnded);
+    }
+
+    Ok(())
+}
+
+/// Reject a swap whose rate or USTR output is worse than the caller's bounds.
+/// The rate only drifts slowly, but a swap can sit in the mempool across a
+/// pause/resume or the start of the period, so callers may pin what they saw.
+fn check_swap_limits(
+    limits: &SwapLimits,
+    rate: Decimal,
+    total_ustr_to_user: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(max_rate) = limits.max_rate {
+        if rate > max_rate {
+            return Err(ContractError::RateAboveMax {
+                rate: rate.to_string(),
+                max_rate: max_rate.to_string(),
+            });
+        }
+    }
+    if let Some(min_total_ustr) = limits.min_total_ustr {
+        if total_ustr_to_user < min_total_ustr {
+            return Err(ContractError::SlippageExceeded {
+                total_ustr: total_ustr_to_user.to_string(),
+                min_total_ustr: min_total_ustr.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Shared swap logic for direct swaps and Treasury-notified deposits
+/// Calculates USTR at the current rate, applies referral bonuses, checks the
+/// caller's slippage bounds, updates stats and the leaderboard, enforces the
+/// 5% mint safety limit and builds mint messages.
+/// When `forward_ustc` is true, the received USTC is forwarded to the Treasury.
+#[allow(clippy::too_many_arguments)]
+fn process_swap(
+    deps: DepsMut,
+    env: Env,
+    config: Config,
+    user: Addr,
+    ustc_amount: Uint128,
+    referral_code: Option<String>,
+    leaderboard_hint: Option<crate::msg::LeaderboardHint>,
+    limits: SwapLimits,
+    forward_ustc: bool,
+) -> Result<Response, ContractError> {
     // Calculate current rate
     let rate = calculate_current_rate(&config, env.block.time);
 
@@ -291,6 +526,8 @@ fn execute_swap(
     let total_ustr_to_user = base_ustr + user_bonus;
     let total_ustr_minted = total_ustr_to_user + referrer_bonus;
 
+    check_swap_limits(&limits, rate, total_ustr_to_user)?;
+
     // Safety check: ensure mint amount doesn't exceed 5% of total supply
     // This prevents catastrophic minting bugs from draining value
     let token_info: TokenInfoResponse = deps.querier.query(
@@ -334,18 +571,30 @@ fn execute_swap(
                             total_rewards_earned: Uint128::zero(),
                             total_user_bonuses: Uint128::zero(),
                             total_swaps: 0,
+                            owner: None,
                         },
                         true,
                     ),
                 };
 
             // Update per-code stats
+            let previous_rewards = code_stats.total_rewards_earned;
             code_stats.total_rewards_earned += referrer_bonus;
             code_stats.total_user_bonuses += user_bonus;
             code_stats.total_swaps += 1;
+            // Cache the owner for queries, refreshed on every use
+            code_stats.owner = referrer_addr.clone();
 
             REFERRAL_CODE_STATS.save(deps.storage, &normalized_code, &code_stats)?;
 
+            // Move the code within the full ranking (bounded, unlike the top-50 list)
+            update_referral_ranking(
+                deps.storage,
+                &normalized_code,
+                previous_rewards,
+                code_stats.total_rewards_earned,
+            )?;
+
             // Update unique codes counter if this is a new code
             if is_new_code {
                 stats.unique_referral_codes_used += 1;
@@ -362,24 +611,44 @@ fn execute_swap(
     }
     STATS.save(deps.storage, &stats)?;
 
+    // Per-user totals and receipt
+    let swap_id = record_swap(
+        deps.storage,
+        SwapReceipt {
+            user: user.clone(),
+            ustc_amount,
+            rate,
+            base_ustr,
+            user_bonus,
+            referral_code: referrer_addr
+                .as_ref()
+                .and(referral_code.as_deref())
+                .map(str::to_lowercase),
+            timestamp: env.block.time,
+        },
+    )?;
+
     // Build response with messages
     let mut response = Response::new();
 
     // Forward USTC to Treasury (user pays 0.5% burn tax)
-    let forward_ustc = BankMsg::Send {
-        to_address: config.treasury.to_string(),
-        amount: vec![Coin {
-            denom: USTC_DENOM.to_string(),
-            amount: ustc_amount,
-        }],
-    };
-    response = response.add_message(forward_ustc);
+    // Skipped for Treasury-notified deposits: the USTC is already there
+    if forward_ustc {
+        let forward_ustc = BankMsg::Send {
+            to_address: config.treasury.to_string(),
+            amount: vec![Coin {
+                denom: USTC_DENOM.to_string(),
+                amount: ustc_amount,
+            }],
+        };
+        response = response.add_message(forward_ustc);
+    }
 
     // Mint USTR to user
     let mint_to_user = WasmMsg::Execute {
         contract_addr: config.ustr_token.to_string(),
         msg: to_json_binary(&Cw20ExecuteMsg::Mint {
-            recipient: info.sender.to_string(),
+            recipient: user.to_string(),
             amount: total_ustr_to_user,
         })?,
         funds: vec![],
@@ -401,9 +670,14 @@ fn execute_swap(
         }
     }
 
+    let action = if forward_ustc {
+        "swap"
+    } else {
+        "notify_deposit"
+    };
     response = response
-        .add_attribute("action", "swap")
-        .add_attribute("user", &info.sender)
+        .add_attribute("action", action)
+        .add_attribute("user", &user)
         .add_attribute("ustc_amount", ustc_amount)
         .add_attribute("rate", rate.to_string())
         .add_attribute("base_ustr", base_ustr)
@@ -430,7 +704,7 @@ fn execute_swap(
         }
     }
 
-    Ok(response)
+    Ok(response.add_attribute("swap_id", swap_id.to_string()))
 }
 
 fn execute_emergency_pause(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
@@ -592,21 +866,190 @@ fn execute_recover_asset(
         .add_attribute("amount", amount))
 }
 
+/// Refresh a used code's cached owner from the Referral contract
+/// Swaps refresh it too, so this is only needed for codes that changed owner
+/// and have not been used since
+fn execute_refresh_referral_owner(deps: DepsMut, code: String) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let normalized_code = code.to_lowercase();
+
+    let mut code_stats = REFERRAL_CODE_STATS
+        .may_load(deps.storage, &normalized_code)?
+        .ok_or_else(|| ContractError::ReferralCodeNotUsed { code: code.clone() })?;
+
+    let owner = query_referral_code(&deps.querier, &config, &normalized_code)?
+        .owner
+        .ok_or(ContractError::ReferralCodeNotRegistered { code })?;
+
+    code_stats.owner = Some(owner.clone());
+    REFERRAL_CODE_STATS.save(deps.storage, &normalized_code, &code_stats)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "refresh_referral_owner")
+        .add_attribute("code", normalized_code)
+        .add_attribute("owner", owner))
+}
+
+/// Continue the referral backfill from where the migration or the previous call stopped
+fn execute_backfill_referral_codes(
+    deps: DepsMut,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let start_after = REFERRAL_BACKFILL_CURSOR
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoReferralBackfillPending {})?;
+    let limit = limit
+        .unwrap_or(DEFAULT_BACKFILL_LIMIT)
+        .clamp(1, MAX_BACKFILL_LIMIT);
+
+    let backfill = backfill_referral_codes(deps, Some(start_after), limit)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "backfill_referral_codes")
+        .add_attributes(backfill.attributes()))
+}
+
 // ============ HELPERS ============
 
-/// Calculate the current exchange rate based on elapsed time
+/// Calculate the exchange rate at a point in time following the configured schedule
 fn calculate_current_rate(config: &Config, current_time: Timestamp) -> Decimal {
-    let total_seconds = config.end_time.seconds() - config.start_time.seconds();
-    let elapsed_seconds = current_time.seconds().saturating_sub(config.start_time.seconds());
+    let start = config.start_time.seconds();
+    let end = config.end_time.seconds();
+
+    // Clamp to the swap period (shouldn't happen for swaps, but queries may ask)
+    let now = current_time.seconds().max(start).min(end);
+    if now == end {
+        return config.end_rate;
+    }
+
+    match &config.rate_schedule {
+        RateSchedule::Linear {} => {
+            interpolate_rate((start, config.start_rate), (end, config.end_rate), now)
+        }
+        RateSchedule::Stepwise { breakpoints } => breakpoints
+            .iter()
+            .rev()
+            .find(|p| p.timestamp <= now)
+            .map_or(config.start_rate, |p| p.rate),
+        RateSchedule::Exponential {
+            growth_per_period,
+            period_seconds,
+        } => {
+            // Overflow means the rate is far past end_rate
+            let periods = u32::try_from((now - start) / period_seconds).unwrap_or(u32::MAX);
+            (Decimal::one() + growth_per_period)
+                .checked_pow(periods)
+                .ok()
+                .and_then(|factor| config.start_rate.checked_mul(factor).ok())
+                .map_or(config.end_rate, |rate| rate.min(config.end_rate))
+        }
+        RateSchedule::PiecewiseLinear { breakpoints } => {
+            let mut from = (start, config.start_rate);
+            for point in breakpoints {
+                if now < point.timestamp {
+                    return interpolate_rate(from, (point.timestamp, point.rate), now);
+                }
+                from = (point.timestamp, point.rate);
+            }
+            interpolate_rate(from, (end, config.end_rate), now)
+        }
+    }
+}
+
+/// Linear interpolation between two (timestamp, rate) points, `from.0 <= now < to.0`
+/// rate(t) = from_rate + ((to_rate - from_rate) * (t - from_time) / (to_time - from_time))
+fn interpolate_rate(from: (u64, Decimal), to: (u64, Decimal), now: u64) -> Decimal {
+    let progress = Decimal::from_ratio(now - from.0, to.0 - from.0);
+    from.1 + (to.1 - from.1) * progress
+}
+
+/// Schedules must keep the rate within start_rate..=end_rate and never let it
+/// fall, so a later swap is never cheaper than an earlier one
+fn validate_rate_schedule(config: &Config) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidRateSchedule {
+        reason: reason.to_string(),
+    };
+
+    if config.end_time <= config.start_time {
+        return Err(invalid("swap duration must be positive"));
+    }
+    if config.start_rate.is_zero() {
+        return Err(invalid("start rate must be positive"));
+    }
+    if config.start_rate > config.end_rate {
+        return Err(invalid("end rate must not be below start rate"));
+    }
 
-    // Clamp elapsed to total (shouldn't happen if called correctly, but be safe)
-    let elapsed_seconds = elapsed_seconds.min(total_seconds);
+    match &config.rate_schedule {
+        RateSchedule::Linear {} => {}
+        RateSchedule::Exponential {
+            growth_per_period,
+            period_seconds,
+        } => {
+            if growth_per_period.is_zero() || *period_seconds == 0 {
+                return Err(invalid("growth and period must be positive"));
+            }
+        }
+        RateSchedule::Stepwise { breakpoints } | RateSchedule::PiecewiseLinear { breakpoints } => {
+            if breakpoints.is_empty() || breakpoints.len() > MAX_RATE_BREAKPOINTS {
+                return Err(ContractError::InvalidRateSchedule {
+                    reason: format!(
+                        "between 1 and {} breakpoints required",
+                        MAX_RATE_BREAKPOINTS
+                    ),
+                });
+            }
+            let mut prev = (config.start_time.seconds(), config.start_rate);
+            for point in breakpoints {
+                if point.timestamp <= prev.0 || point.timestamp >= config.end_time.seconds() {
+                    return Err(invalid(
+                        "breakpoint timestamps must be strictly increasing and inside the swap period",
+                    ));
+                }
+                if point.rate < prev.1 || point.rate > config.end_rate {
+                    return Err(invalid(
+                        "breakpoint rates must be non-decreasing and within start and end rate",
+                    ));
+                }
+                prev = (point.timestamp, point.rate);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Add a swap to the user's totals and store its indexed receipt, returning the swap ID
+fn record_swap(storage: &mut dyn cosmwasm_std::Storage, receipt: SwapReceipt) -> StdResult<u64> {
+    let ustr_received = receipt.base_ustr + receipt.user_bonus;
+    let user_stats = match USER_SWAP_STATS.may_load(storage, &receipt.user)? {
+        Some(mut existing) => {
+            existing.total_ustc_swapped += receipt.ustc_amount;
+            existing.total_ustr_received += ustr_received;
+            existing.total_bonus_received += receipt.user_bonus;
+            existing.swap_count += 1;
+            existing.last_swap_time = receipt.timestamp;
+            existing
+        }
+        None => UserSwapStats {
+            total_ustc_swapped: receipt.ustc_amount,
+            total_ustr_received: ustr_received,
+            total_bonus_received: receipt.user_bonus,
+            swap_count: 1,
+            first_swap_time: receipt.timestamp,
+            last_swap_time: receipt.timestamp,
+        },
+    };
+    USER_SWAP_STATS.save(storage, &receipt.user, &user_stats)?;
 
-    // rate(t) = start_rate + ((end_rate - start_rate) * elapsed_seconds / total_seconds)
-    let rate_diff = config.end_rate - config.start_rate;
-    let progress = Decimal::from_ratio(elapsed_seconds, total_seconds);
+    // Contracts instantiated before receipts existed start at ID 0 on first use
+    let swap_id = NEXT_SWAP_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_SWAP_ID.save(storage, &(swap_id + 1))?;
 
-    config.start_rate + rate_diff * progress
+    USER_SWAP_RECEIPTS.save(storage, (&receipt.user, swap_id), &())?;
+    SWAP_RECEIPTS_BY_TIME.save(storage, (receipt.timestamp.seconds(), swap_id), &())?;
+    SWAP_RECEIPTS.save(storage, swap_id, &receipt)?;
+
+    Ok(swap_id)
 }
 
 /// Update the top-50 leaderboard after a code's rewards change
@@ -687,7 +1130,7 @@ fn update_leaderboard(
 fn get_leaderboard_position(
     storage: &dyn cosmwasm_std::Storage,
     target_code: &str,
-) -> Result<Option<u32>, ContractError> {
+) -> StdResult<Option<u32>> {
     let head = LEADERBOARD_HEAD.load(storage)?;
     let mut current = head;
     let mut position = 1u32;
@@ -704,6 +1147,118 @@ fn get_leaderboard_position(
     Ok(None)
 }
 
+/// Key of a code in REFERRAL_RANKING
+fn ranking_key(rewards: Uint128, code: &str) -> (u128, &str) {
+    (u128::MAX - rewards.u128(), code)
+}
+
+/// Move a code from `previous` to `rewards` in REFERRAL_RANKING and
+/// REFERRAL_REWARD_COUNTS
+/// A code without a ranking entry at `previous` (new, or used before the
+/// ranking existed and not yet backfilled) is added instead
+fn update_referral_ranking(
+    storage: &mut dyn cosmwasm_std::Storage,
+    code: &str,
+    previous: Uint128,
+    rewards: Uint128,
+) -> StdResult<()> {
+    let previous_key = ranking_key(previous, code);
+    let previous = if REFERRAL_RANKING.has(storage, previous_key) {
+        REFERRAL_RANKING.remove(storage, previous_key);
+        reward_count_path(previous)
+    } else {
+        vec![]
+    };
+    REFERRAL_RANKING.save(storage, ranking_key(rewards, code), &())?;
+
+    // Nodes shared by both paths keep their count
+    let current = reward_count_path(rewards);
+    for node in previous.iter().filter(|node| !current.contains(node)) {
+        match REFERRAL_REWARD_COUNTS.load(storage, *node)? {
+            1 => REFERRAL_REWARD_COUNTS.remove(storage, *node),
+            count => REFERRAL_REWARD_COUNTS.save(storage, *node, &(count - 1))?,
+        }
+    }
+    for node in current.iter().filter(|node| !previous.contains(node)) {
+        let count = REFERRAL_REWARD_COUNTS
+            .may_load(storage, *node)?
+            .unwrap_or(0);
+        REFERRAL_REWARD_COUNTS.save(storage, *node, &(count + 1))?;
+    }
+    Ok(())
+}
+
+/// Keys of the REFERRAL_REWARD_COUNTS nodes counting `rewards`, from the
+/// digit count (depth 0) down to the exact value
+fn reward_count_path(rewards: Uint128) -> Vec<(u8, u8, u128)> {
+    let rewards = rewards.u128();
+    let digits = (128 - rewards.leading_zeros()).div_ceil(4) as u8;
+    (0..=digits)
+        .map(|depth| {
+            let prefix = rewards
+                .checked_shr(4 * u32::from(digits - depth))
+                .unwrap_or(0);
+            (digits, depth, prefix)
+        })
+        .collect()
+}
+
+/// Number of ranked codes with more rewards than `rewards`
+fn count_codes_above(storage: &dyn cosmwasm_std::Storage, rewards: Uint128) -> StdResult<u32> {
+    let path = reward_count_path(rewards);
+    let digits = path[0].0;
+
+    // Every code with more digits has more rewards
+    let mut above = 0u32;
+    for longer in digits + 1..=32 {
+        above += REFERRAL_REWARD_COUNTS
+            .may_load(storage, (longer, 0, 0))?
+            .unwrap_or(0);
+    }
+
+    // Then, digit by digit, the codes sharing the prefix so far whose next digit is higher
+    for depth in 1..=digits {
+        let (_, _, parent) = path[usize::from(depth) - 1];
+        let (_, _, prefix) = path[usize::from(depth)];
+        let last_sibling = parent * 16 + 15;
+        if prefix == last_sibling {
+            continue;
+        }
+        for count in REFERRAL_REWARD_COUNTS.range(
+            storage,
+            Some(Bound::inclusive((digits, depth, prefix + 1))),
+            Some(Bound::inclusive((digits, depth, last_sibling))),
+            Order::Ascending,
+        ) {
+            above += count?.1;
+        }
+    }
+    Ok(above)
+}
+
+/// Assigns ranks to codes visited in leaderboard order: each distinct rewards
+/// value ranks after all codes with more, so only the first needs counting
+struct LeaderboardRanker {
+    previous: Option<(Uint128, u32)>,
+}
+
+impl LeaderboardRanker {
+    fn rank(&mut self, storage: &dyn cosmwasm_std::Storage, rewards: Uint128) -> StdResult<u32> {
+        let rank = match self.previous {
+            Some((previous, rank)) if previous == rewards => rank,
+            Some((previous, rank)) => {
+                let exact = *reward_count_path(previous).last().unwrap();
+                rank + REFERRAL_REWARD_COUNTS
+                    .may_load(storage, exact)?
+                    .unwrap_or(0)
+            }
+            None => count_codes_above(storage, rewards)? + 1,
+        };
+        self.previous = Some((rewards, rank));
+        Ok(rank)
+    }
+}
+
 /// Try to insert a code into the top-50 leaderboard
 /// Only inserts if the leaderboard has room or the code beats the current tail
 /// 
@@ -1141,10 +1696,23 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::CurrentRate {} => to_json_binary(&query_current_rate(deps, env)?),
+        QueryMsg::RateAt { timestamp } => to_json_binary(&query_rate_at(deps, timestamp)?),
+        QueryMsg::RateSchedule { samples } => to_json_binary(&query_rate_schedule(deps, samples)?),
         QueryMsg::SwapSimulation {
             ustc_amount,
             referral_code,
-        } => to_json_binary(&query_swap_simulation(deps, env, ustc_amount, referral_code)?),
+            min_total_ustr,
+            max_rate,
+        } => to_json_binary(&query_swap_simulation(
+            deps,
+            env,
+            ustc_amount,
+            referral_code,
+            SwapLimits {
+                min_total_ustr,
+                max_rate,
+            },
+        )?),
         QueryMsg::Status {} => to_json_binary(&query_status(deps, env)?),
         QueryMsg::Stats {} => to_json_binary(&query_stats(deps)?),
         QueryMsg::PendingAdmin {} => to_json_binary(&query_pending_admin(deps)?),
@@ -1154,6 +1722,29 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ReferralLeaderboard { start_after, limit } => {
             to_json_binary(&query_referral_leaderboard(deps, start_after, limit)?)
         }
+        QueryMsg::UserStats { address } => to_json_binary(&query_user_stats(deps, address)?),
+        QueryMsg::UserSwapReceipts {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_user_swap_receipts(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::SwapReceipts {
+            from_time,
+            to_time,
+            start_after,
+            limit,
+        } => to_json_binary(&query_swap_receipts(
+            deps,
+            from_time,
+            to_time,
+            start_after,
+            limit,
+        )?),
     }
 }
 
@@ -1169,26 +1760,56 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         end_rate: config.end_rate,
         admin: config.admin,
         paused: config.paused,
+        rate_schedule: config.rate_schedule,
     })
 }
 
 fn query_current_rate(deps: Deps, env: Env) -> StdResult<RateResponse> {
     let config = CONFIG.load(deps.storage)?;
+    Ok(rate_response(&config, env.block.time))
+}
+
+fn query_rate_at(deps: Deps, timestamp: u64) -> StdResult<RateResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(rate_response(&config, Timestamp::from_seconds(timestamp)))
+}
+
+fn query_rate_schedule(deps: Deps, samples: Option<u32>) -> StdResult<RateScheduleResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let samples = samples
+        .unwrap_or(DEFAULT_RATE_SAMPLES)
+        .clamp(2, MAX_RATE_SAMPLES) as u64;
+
+    let start = config.start_time.seconds();
+    let total_seconds = config.end_time.seconds() - start;
+    let points = (0..samples)
+        .map(|i| {
+            let timestamp = start + total_seconds * i / (samples - 1);
+            RatePoint {
+                timestamp,
+                rate: calculate_current_rate(&config, Timestamp::from_seconds(timestamp)),
+            }
+        })
+        .collect();
+
+    Ok(RateScheduleResponse {
+        schedule: config.rate_schedule,
+        points,
+    })
+}
+
+fn rate_response(config: &Config, time: Timestamp) -> RateResponse {
     let total_seconds = config.end_time.seconds() - config.start_time.seconds();
-    let elapsed_seconds = env
-        .block
-        .time
+    let elapsed_seconds = time
         .seconds()
         .saturating_sub(config.start_time.seconds())
         .min(total_seconds);
 
-    let rate = calculate_current_rate(&config, env.block.time);
-
-    Ok(RateResponse {
-        rate,
+    RateResponse {
+        rate: calculate_current_rate(config, time),
         elapsed_seconds,
         total_seconds,
-    })
+    }
 }
 
 fn query_swap_simulation(
@@ -1196,6 +1817,7 @@ fn query_swap_simulation(
     env: Env,
     ustc_amount: Uint128,
     referral_code: Option<String>,
+    limits: SwapLimits,
 ) -> StdResult<SimulationResponse> {
     let config = CONFIG.load(deps.storage)?;
     let rate = calculate_current_rate(&config, env.block.time);
@@ -1246,6 +1868,7 @@ fn query_swap_simulation(
     };
 
     let total_ustr_to_user = base_ustr_amount + user_bonus;
+    let within_limits = check_swap_limits(&limits, rate, total_ustr_to_user).is_ok();
 
     Ok(SimulationResponse {
         ustc_amount,
@@ -1255,6 +1878,7 @@ fn query_swap_simulation(
         total_ustr_to_user,
         rate,
         referral_valid,
+        within_limits,
     })
 }
 
@@ -1307,39 +1931,45 @@ fn query_pending_admin(deps: Deps) -> StdResult<Option<PendingAdminResponse>> {
 }
 
 fn query_referral_code_stats(deps: Deps, code: String) -> StdResult<ReferralCodeStatsResponse> {
-    let config = CONFIG.load(deps.storage)?;
     let normalized_code = code.to_lowercase();
 
-    // Query the referral contract to get the owner
-    let validate_response: ReferralValidateResponse = deps.querier.query(
-        &QueryRequest::Wasm(WasmQuery::Smart {
-            contract_addr: config.referral.to_string(),
-            msg: to_json_binary(&ReferralQueryMsg::ValidateCode {
-                code: normalized_code.clone(),
-            })?,
-        }),
-    )?;
+    // Load per-code stats (may not exist if code was never used in a swap)
+    let code_stats = REFERRAL_CODE_STATS.may_load(deps.storage, &normalized_code)?;
 
-    // If code is not registered, return error
-    if !validate_response.is_registered {
-        return Err(cosmwasm_std::StdError::generic_err(format!(
-            "Referral code '{}' is not registered",
-            code
-        )));
-    }
+    // Used codes carry their owner; only never-used codes ask the Referral contract
+    let owner = match code_stats.as_ref().and_then(|stats| stats.owner.clone()) {
+        Some(owner) => owner,
+        None => {
+            let config = CONFIG.load(deps.storage)?;
+            let validate_response = query_referral_code(&deps.querier, &config, &normalized_code)?;
 
-    let owner = validate_response
-        .owner
-        .ok_or_else(|| cosmwasm_std::StdError::generic_err("Code owner not found"))?;
+            // If code is not registered, return error
+            if !validate_response.is_registered {
+                return Err(cosmwasm_std::StdError::generic_err(format!(
+                    "Referral code '{}' is not registered",
+                    code
+                )));
+            }
 
-    // Load per-code stats (may not exist if code was never used in a swap)
-    let code_stats = REFERRAL_CODE_STATS
-        .may_load(deps.storage, &normalized_code)?
-        .unwrap_or(ReferralCodeStats {
-            total_rewards_earned: Uint128::zero(),
-            total_user_bonuses: Uint128::zero(),
-            total_swaps: 0,
-        });
+            validate_response
+                .owner
+                .ok_or_else(|| cosmwasm_std::StdError::generic_err("Code owner not found"))?
+        }
+    };
+
+    let used = code_stats.is_some();
+    let code_stats = code_stats.unwrap_or(ReferralCodeStats {
+        total_rewards_earned: Uint128::zero(),
+        total_user_bonuses: Uint128::zero(),
+        total_swaps: 0,
+        owner: None,
+    });
+
+    let rank = if used {
+        Some(count_codes_above(deps.storage, code_stats.total_rewards_earned)? + 1)
+    } else {
+        None
+    };
 
     Ok(ReferralCodeStatsResponse {
         code: normalized_code,
@@ -1347,6 +1977,7 @@ fn query_referral_code_stats(deps: Deps, code: String) -> StdResult<ReferralCode
         total_rewards_earned: code_stats.total_rewards_earned,
         total_user_bonuses: code_stats.total_user_bonuses,
         total_swaps: code_stats.total_swaps,
+        rank,
     })
 }
 
@@ -1355,11 +1986,12 @@ fn query_referral_leaderboard(
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<ReferralLeaderboardResponse> {
-    let config = CONFIG.load(deps.storage)?;
     let limit = limit.unwrap_or(DEFAULT_LEADERBOARD_LIMIT).min(MAX_LEADERBOARD_LIMIT);
 
     let mut entries: Vec<LeaderboardEntry> = Vec::new();
-    let mut rank: u32 = 1;
+    let mut ranker = LeaderboardRanker { previous: None };
+    // Where to resume in REFERRAL_RANKING when start_after is below the top 50
+    let mut ranking_start: Option<(Uint128, String)> = None;
 
     // Find starting position
     let start_code = if let Some(ref after) = start_after {
@@ -1367,18 +1999,11 @@ fn query_referral_leaderboard(
         // Start from the code after the given one
         let link = LEADERBOARD_LINKS.may_load(deps.storage, &normalized)?;
         if let Some(link) = link {
-            // Count ranks up to this code
-            let mut current = LEADERBOARD_HEAD.load(deps.storage)?;
-            while let Some(ref curr) = current {
-                if curr == &normalized {
-                    break;
-                }
-                rank += 1;
-                let curr_link = LEADERBOARD_LINKS.load(deps.storage, curr)?;
-                current = curr_link.next;
-            }
-            rank += 1; // Move past the start_after code
             link.next
+        } else if let Some(after_stats) = REFERRAL_CODE_STATS.may_load(deps.storage, &normalized)? {
+            // Code is below the top 50 - resume the ranking right after its key
+            ranking_start = Some((after_stats.total_rewards_earned, normalized));
+            None
         } else {
             // Code not found, start from head
             LEADERBOARD_HEAD.load(deps.storage)?
@@ -1395,47 +2020,189 @@ fn query_referral_leaderboard(
             break;
         }
 
-        // Load code stats
-        let code_stats = REFERRAL_CODE_STATS.load(deps.storage, code)?;
-
-        // Query owner from referral contract
-        let validate_response: Result<ReferralValidateResponse, _> = deps.querier.query(
-            &QueryRequest::Wasm(WasmQuery::Smart {
-                contract_addr: config.referral.to_string(),
-                msg: to_json_binary(&ReferralQueryMsg::ValidateCode { code: code.clone() })?,
-            }),
-        );
-
-        let owner = match validate_response {
-            Ok(resp) => resp.owner.unwrap_or_else(|| {
-                cosmwasm_std::Addr::unchecked("unknown")
-            }),
-            Err(_) => cosmwasm_std::Addr::unchecked("unknown"),
-        };
-
-        entries.push(LeaderboardEntry {
-            code: code.clone(),
-            owner,
-            total_rewards_earned: code_stats.total_rewards_earned,
-            total_user_bonuses: code_stats.total_user_bonuses,
-            total_swaps: code_stats.total_swaps,
-            rank,
-        });
+        entries.push(leaderboard_entry(deps, code, &mut ranker)?);
 
         // Move to next
         let link = LEADERBOARD_LINKS.load(deps.storage, code)?;
         current = link.next;
-        rank += 1;
         count += 1;
     }
 
-    // Check if there are more entries
-    let has_more = current.is_some();
+    // Check if there are more entries in the top 50
+    let mut has_more = current.is_some();
 
-    Ok(ReferralLeaderboardResponse { entries, has_more })
-}
-
-// ============ TESTS ============
+    // Past the tail, continue with the remaining codes in ranking order; at
+    // most the 50 list codes are skipped along the way
+    if !has_more {
+        let start = ranking_start
+            .as_ref()
+            .map(|(rewards, code)| Bound::exclusive(ranking_key(*rewards, code)));
+        for key in REFERRAL_RANKING.keys(deps.storage, start, None, Order::Ascending) {
+            let (_, code) = key?;
+            if LEADERBOARD_LINKS.has(deps.storage, &code) {
+                continue;
+            }
+            if count >= limit {
+                has_more = true;
+                break;
+            }
+            entries.push(leaderboard_entry(deps, &code, &mut ranker)?);
+            count += 1;
+        }
+    }
+
+    Ok(ReferralLeaderboardResponse { entries, has_more })
+}
+
+fn leaderboard_entry(
+    deps: Deps,
+    code: &str,
+    ranker: &mut LeaderboardRanker,
+) -> StdResult<LeaderboardEntry> {
+    let code_stats = REFERRAL_CODE_STATS.load(deps.storage, code)?;
+    let rank = ranker.rank(deps.storage, code_stats.total_rewards_earned)?;
+
+    Ok(LeaderboardEntry {
+        code: code.to_string(),
+        owner: code_stats.owner,
+        total_rewards_earned: code_stats.total_rewards_earned,
+        total_user_bonuses: code_stats.total_user_bonuses,
+        total_swaps: code_stats.total_swaps,
+        rank,
+    })
+}
+
+/// Look up a code in the Referral contract
+fn query_referral_code(
+    querier: &QuerierWrapper,
+    config: &Config,
+    code: &str,
+) -> StdResult<ReferralValidateResponse> {
+    querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: config.referral.to_string(),
+        msg: to_json_binary(&ReferralQueryMsg::ValidateCode {
+            code: code.to_string(),
+        })?,
+    }))
+}
+
+fn query_user_stats(deps: Deps, address: String) -> StdResult<UserStatsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+
+    let response = match USER_SWAP_STATS.may_load(deps.storage, &address)? {
+        Some(stats) => UserStatsResponse {
+            address,
+            total_ustc_swapped: stats.total_ustc_swapped,
+            total_ustr_received: stats.total_ustr_received,
+            total_bonus_received: stats.total_bonus_received,
+            swap_count: stats.swap_count,
+            first_swap_time: Some(stats.first_swap_time),
+            last_swap_time: Some(stats.last_swap_time),
+        },
+        None => UserStatsResponse {
+            address,
+            total_ustc_swapped: Uint128::zero(),
+            total_ustr_received: Uint128::zero(),
+            total_bonus_received: Uint128::zero(),
+            swap_count: 0,
+            first_swap_time: None,
+            last_swap_time: None,
+        },
+    };
+    Ok(response)
+}
+
+fn query_user_swap_receipts(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<SwapReceiptsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit
+        .unwrap_or(DEFAULT_RECEIPT_LIMIT)
+        .min(MAX_RECEIPT_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let receipts = USER_SWAP_RECEIPTS
+        .prefix(&address)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|id| {
+            let id = id?;
+            Ok(receipt_response(id, SWAP_RECEIPTS.load(deps.storage, id)?))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(SwapReceiptsResponse { receipts })
+}
+
+fn query_swap_receipts(
+    deps: Deps,
+    from_time: Option<u64>,
+    to_time: Option<u64>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<SwapReceiptsResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_RECEIPT_LIMIT)
+        .min(MAX_RECEIPT_LIMIT) as usize;
+    let to_time = to_time.unwrap_or(u64::MAX);
+
+    // IDs are in time order, so the range is every ID from the first receipt
+    // at or after from_time up to the first receipt after to_time
+    let first_id = match from_time {
+        Some(from_time) => SWAP_RECEIPTS_BY_TIME
+            .keys(
+                deps.storage,
+                Some(Bound::inclusive((from_time, 0u64))),
+                None,
+                Order::Ascending,
+            )
+            .next()
+            .transpose()?
+            .map(|(_, id)| id),
+        None => Some(0),
+    };
+    let first_id = match (first_id, start_after) {
+        (None, _) => return Ok(SwapReceiptsResponse { receipts: vec![] }),
+        (Some(first_id), Some(after)) => first_id.max(after.saturating_add(1)),
+        (Some(first_id), None) => first_id,
+    };
+
+    let receipts = SWAP_RECEIPTS
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(first_id)),
+            None,
+            Order::Ascending,
+        )
+        .take_while(|item| match item {
+            Ok((_, receipt)) => receipt.timestamp.seconds() <= to_time,
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| item.map(|(id, receipt)| receipt_response(id, receipt)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(SwapReceiptsResponse { receipts })
+}
+
+fn receipt_response(id: u64, receipt: SwapReceipt) -> SwapReceiptResponse {
+    SwapReceiptResponse {
+        id,
+        user: receipt.user,
+        ustc_amount: receipt.ustc_amount,
+        rate: receipt.rate,
+        base_ustr: receipt.base_ustr,
+        user_bonus: receipt.user_bonus,
+        total_ustr_to_user: receipt.base_ustr + receipt.user_bonus,
+        referral_code: receipt.referral_code,
+        timestamp: receipt.timestamp,
+    }
+}
+
+// ============ TESTS ============
 
 #[cfg(test)]
 mod tests {
@@ -1526,6 +2293,7 @@ mod tests {
             start_rate: Decimal::from_ratio(15u128, 10u128), // 1.5
             end_rate: Decimal::from_ratio(25u128, 10u128),   // 2.5
             duration_seconds: None, // Uses DEFAULT_SWAP_DURATION (100 days)
+            rate_schedule: None,    // Linear
             admin: ADMIN.to_string(),
         };
         let info = mock_info("creator", &[]);
@@ -1628,6 +2396,7 @@ mod tests {
             start_rate: Decimal::from_ratio(15u128, 10u128),
             end_rate: Decimal::from_ratio(25u128, 10u128),
             duration_seconds: None,
+            rate_schedule: None,
             admin: ADMIN.to_string(),
         };
         let info = mock_info("creator", &[]);
@@ -1674,6 +2443,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1697,6 +2468,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1714,6 +2487,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1733,6 +2508,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1749,6 +2526,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -1766,6 +2545,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -1804,6 +2585,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         // Empty code should be treated as no referral
@@ -1830,6 +2613,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
         let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
         assert_eq!(err, ContractError::SwapPaused);
@@ -1871,6 +2656,345 @@ mod tests {
         assert_eq!(rate, Decimal::from_ratio(25u128, 10u128)); // 2.5
     }
 
+    // ============ RATE SCHEDULE TESTS ============
+
+    const DAY: u64 = 86_400;
+
+    fn schedule_msg(start_time: u64, rate_schedule: RateSchedule) -> InstantiateMsg {
+        InstantiateMsg {
+            ustr_token: USTR_TOKEN.to_string(),
+            treasury: TREASURY.to_string(),
+            referral: REFERRAL.to_string(),
+            start_time,
+            start_rate: Decimal::from_ratio(15u128, 10u128), // 1.5
+            end_rate: Decimal::from_ratio(25u128, 10u128),   // 2.5
+            duration_seconds: None,
+            rate_schedule: Some(rate_schedule),
+            admin: ADMIN.to_string(),
+        }
+    }
+
+    fn point(timestamp: u64, rate: Decimal) -> RatePoint {
+        RatePoint { timestamp, rate }
+    }
+
+    #[test]
+    fn test_rate_schedule_stepwise() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let start_time = env.block.time.seconds();
+        let schedule = RateSchedule::Stepwise {
+            breakpoints: vec![
+                point(start_time + 25 * DAY, Decimal::from_ratio(175u128, 100u128)),
+                point(start_time + 50 * DAY, Decimal::from_ratio(20u128, 10u128)),
+            ],
+        };
+        instantiate(
+            deps.as_mut(),
+            env,
+            mock_info("creator", &[]),
+            schedule_msg(start_time, schedule),
+        )
+        .unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        let rate_at = |t: u64| calculate_current_rate(&config, Timestamp::from_seconds(t));
+
+        assert_eq!(rate_at(start_time), Decimal::from_ratio(15u128, 10u128));
+        assert_eq!(
+            rate_at(start_time + 25 * DAY - 1),
+            Decimal::from_ratio(15u128, 10u128)
+        );
+        assert_eq!(
+            rate_at(start_time + 25 * DAY),
+            Decimal::from_ratio(175u128, 100u128)
+        );
+        assert_eq!(
+            rate_at(start_time + 80 * DAY),
+            Decimal::from_ratio(20u128, 10u128)
+        );
+        assert_eq!(
+            rate_at(start_time + 100 * DAY),
+            Decimal::from_ratio(25u128, 10u128)
+        );
+    }
+
+    #[test]
+    fn test_rate_schedule_exponential() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let start_time = env.block.time.seconds();
+        let schedule = RateSchedule::Exponential {
+            growth_per_period: Decimal::percent(1),
+            period_seconds: DAY,
+        };
+        instantiate(
+            deps.as_mut(),
+            env,
+            mock_info("creator", &[]),
+            schedule_msg(start_time, schedule),
+        )
+        .unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        let rate_at = |t: u64| calculate_current_rate(&config, Timestamp::from_seconds(t));
+
+        // Only whole periods count
+        assert_eq!(
+            rate_at(start_time + DAY - 1),
+            Decimal::from_ratio(15u128, 10u128)
+        );
+        assert_eq!(rate_at(start_time + DAY), Decimal::permille(1515)); // 1.5 * 1.01
+
+        // 1.5 * 1.01^30 ~= 2.02
+        let expected =
+            Decimal::from_ratio(15u128, 10u128) * Decimal::percent(101).checked_pow(30).unwrap();
+        assert_eq!(rate_at(start_time + 30 * DAY), expected);
+
+        // 1.5 * 1.01^60 ~= 2.73, capped at end_rate
+        assert_eq!(
+            rate_at(start_time + 60 * DAY),
+            Decimal::from_ratio(25u128, 10u128)
+        );
+    }
+
+    #[test]
+    fn test_rate_schedule_piecewise_linear() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let start_time = env.block.time.seconds();
+        // Fast rise to 2.0 over the first 20 days, then slow rise to 2.5
+        let schedule = RateSchedule::PiecewiseLinear {
+            breakpoints: vec![point(
+                start_time + 20 * DAY,
+                Decimal::from_ratio(20u128, 10u128),
+            )],
+        };
+        instantiate(
+            deps.as_mut(),
+            env,
+            mock_info("creator", &[]),
+            schedule_msg(start_time, schedule),
+        )
+        .unwrap();
+
+        let config = CONFIG.load(&deps.storage).unwrap();
+        let rate_at = |t: u64| calculate_current_rate(&config, Timestamp::from_seconds(t));
+
+        assert_eq!(rate_at(start_time), Decimal::from_ratio(15u128, 10u128));
+        assert_eq!(
+            rate_at(start_time + 10 * DAY),
+            Decimal::from_ratio(175u128, 100u128)
+        );
+        assert_eq!(
+            rate_at(start_time + 20 * DAY),
+            Decimal::from_ratio(20u128, 10u128)
+        );
+        assert_eq!(
+            rate_at(start_time + 60 * DAY),
+            Decimal::from_ratio(225u128, 100u128)
+        );
+        assert_eq!(
+            rate_at(start_time + 100 * DAY),
+            Decimal::from_ratio(25u128, 10u128)
+        );
+    }
+
+    #[test]
+    fn test_rate_schedule_validation() {
+        let env = mock_env();
+        let start_time = env.block.time.seconds();
+        let two = Decimal::from_ratio(20u128, 10u128);
+
+        let invalid = vec![
+            // No breakpoints
+            schedule_msg(
+                start_time,
+                RateSchedule::Stepwise {
+                    breakpoints: vec![],
+                },
+            ),
+            // Breakpoint at start_time
+            schedule_msg(
+                start_time,
+                RateSchedule::PiecewiseLinear {
+                    breakpoints: vec![point(start_time, two)],
+                },
+            ),
+            // Breakpoint at end_time
+            schedule_msg(
+                start_time,
+                RateSchedule::Stepwise {
+                    breakpoints: vec![point(start_time + 100 * DAY, two)],
+                },
+            ),
+            // Timestamps out of order
+            schedule_msg(
+                start_time,
+                RateSchedule::Stepwise {
+                    breakpoints: vec![
+                        point(start_time + 2 * DAY, two),
+                        point(start_time + DAY, two),
+                    ],
+                },
+            ),
+            // Rate falls
+            schedule_msg(
+                start_time,
+                RateSchedule::PiecewiseLinear {
+                    breakpoints: vec![
+                        point(start_time + DAY, two),
+                        point(start_time + 2 * DAY, Decimal::permille(1800)),
+                    ],
+                },
+            ),
+            // Rate above end_rate
+            schedule_msg(
+                start_time,
+                RateSchedule::Stepwise {
+                    breakpoints: vec![point(start_time + DAY, Decimal::percent(300))],
+                },
+            ),
+            // Zero growth
+            schedule_msg(
+                start_time,
+                RateSchedule::Exponential {
+                    growth_per_period: Decimal::zero(),
+                    period_seconds: DAY,
+                },
+            ),
+            // Zero period
+            schedule_msg(
+                start_time,
+                RateSchedule::Exponential {
+                    growth_per_period: Decimal::percent(1),
+                    period_seconds: 0,
+                },
+            ),
+            // end_rate below start_rate
+            InstantiateMsg {
+                end_rate: Decimal::one(),
+                ..schedule_msg(start_time, RateSchedule::Linear {})
+            },
+            // Zero duration
+            InstantiateMsg {
+                duration_seconds: Some(0),
+                ..schedule_msg(start_time, RateSchedule::Linear {})
+            },
+        ];
+
+        for msg in invalid {
+            let mut deps = mock_dependencies();
+            let err = instantiate(deps.as_mut(), env.clone(), mock_info("creator", &[]), msg)
+                .unwrap_err();
+            assert!(matches!(err, ContractError::InvalidRateSchedule { .. }));
+        }
+    }
+
+    #[test]
+    fn test_swap_follows_rate_schedule() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let mut env = mock_env();
+        let start_time = env.block.time.seconds();
+        let schedule = RateSchedule::Stepwise {
+            breakpoints: vec![point(
+                start_time + 10 * DAY,
+                Decimal::from_ratio(20u128, 10u128),
+            )],
+        };
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            schedule_msg(start_time, schedule),
+        )
+        .unwrap();
+
+        // Day 10: rate steps to 2.0, so 20 USTC buys 10 USTR
+        env.block.time = Timestamp::from_seconds(start_time + 10 * DAY);
+        let msg = ExecuteMsg::Swap {
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("user", &ustc_coins(20_000_000)),
+            msg,
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.attributes[3].value,
+            Decimal::from_ratio(20u128, 10u128).to_string()
+        ); // rate
+        assert_eq!(res.attributes[6].value, TEN_USTR.to_string()); // total_ustr_to_user
+    }
+
+    #[test]
+    fn test_query_rate_at_and_schedule() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let start_time = env.block.time.seconds();
+        setup_contract(deps.as_mut(), start_time);
+
+        // Clamped before start
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::RateAt { timestamp: 0 },
+        )
+        .unwrap();
+        let rate: RateResponse = from_json(res).unwrap();
+        assert_eq!(rate.rate, Decimal::from_ratio(15u128, 10u128));
+        assert_eq!(rate.elapsed_seconds, 0);
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::RateAt {
+                timestamp: start_time + 50 * DAY,
+            },
+        )
+        .unwrap();
+        let rate: RateResponse = from_json(res).unwrap();
+        assert_eq!(rate.rate, Decimal::from_ratio(20u128, 10u128));
+        assert_eq!(rate.elapsed_seconds, 50 * DAY);
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::RateSchedule { samples: Some(3) },
+        )
+        .unwrap();
+        let schedule: RateScheduleResponse = from_json(res).unwrap();
+        assert_eq!(schedule.schedule, RateSchedule::Linear {});
+        assert_eq!(
+            schedule.points,
+            vec![
+                point(start_time, Decimal::from_ratio(15u128, 10u128)),
+                point(start_time + 50 * DAY, Decimal::from_ratio(20u128, 10u128)),
+                point(start_time + 100 * DAY, Decimal::from_ratio(25u128, 10u128)),
+            ]
+        );
+
+        // Sample count defaults to 50 and is clamped to [2, 500]
+        let samples = |samples: Option<u32>| -> usize {
+            let res = query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::RateSchedule { samples },
+            )
+            .unwrap();
+            from_json::<RateScheduleResponse>(res).unwrap().points.len()
+        };
+        assert_eq!(samples(None), DEFAULT_RATE_SAMPLES as usize);
+        assert_eq!(samples(Some(0)), 2);
+        assert_eq!(samples(Some(10_000)), MAX_RATE_SAMPLES as usize);
+    }
+
     // ============ ADMIN TRANSFER TESTS ============
 
     #[test]
@@ -2246,6 +3370,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -2352,6 +3478,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -2429,6 +3557,7 @@ mod tests {
             total_rewards_earned: Uint128::from(100u128),
             total_user_bonuses: Uint128::from(100u128),
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, code, &code_stats)
@@ -2474,6 +3603,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2524,6 +3654,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2575,6 +3706,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2614,6 +3746,7 @@ mod tests {
                 total_rewards_earned: rewards,
                 total_user_bonuses: rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, &code, &code_stats)
@@ -2640,6 +3773,7 @@ mod tests {
             total_rewards_earned: low_rewards,
             total_user_bonuses: low_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, low_code, &code_stats)
@@ -2660,6 +3794,7 @@ mod tests {
             total_rewards_earned: high_rewards,
             total_user_bonuses: high_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, high_code, &code_stats)
@@ -2727,6 +3862,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2740,6 +3876,7 @@ mod tests {
             total_rewards_earned: delta_rewards,
             total_user_bonuses: delta_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "delta", &code_stats)
@@ -2775,6 +3912,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2788,6 +3926,7 @@ mod tests {
             total_rewards_earned: delta_rewards,
             total_user_bonuses: delta_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "delta", &code_stats)
@@ -2823,6 +3962,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2836,6 +3976,7 @@ mod tests {
             total_rewards_earned: delta_rewards,
             total_user_bonuses: delta_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "delta", &code_stats)
@@ -2869,6 +4010,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2882,6 +4024,7 @@ mod tests {
             total_rewards_earned: delta_rewards,
             total_user_bonuses: delta_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "delta", &code_stats)
@@ -2918,6 +4061,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -2931,6 +4075,7 @@ mod tests {
             total_rewards_earned: delta_rewards,
             total_user_bonuses: delta_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "delta", &code_stats)
@@ -2964,6 +4109,7 @@ mod tests {
                 total_rewards_earned: rewards,
                 total_user_bonuses: rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, &code, &code_stats)
@@ -2985,6 +4131,7 @@ mod tests {
             total_rewards_earned: new_rewards,
             total_user_bonuses: new_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "newcode", &code_stats)
@@ -3042,6 +4189,7 @@ mod tests {
             total_rewards_earned: rewards,
             total_user_bonuses: rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, code, &code_stats)
@@ -3080,6 +4228,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -3097,6 +4246,7 @@ mod tests {
             total_rewards_earned: new_alpha_rewards,
             total_user_bonuses: new_alpha_rewards,
             total_swaps: 2,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "alpha", &alpha_stats)
@@ -3138,6 +4288,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -3151,6 +4302,7 @@ mod tests {
             total_rewards_earned: new_alpha_rewards,
             total_user_bonuses: new_alpha_rewards,
             total_swaps: 2,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "alpha", &alpha_stats)
@@ -3189,6 +4341,7 @@ mod tests {
                 total_rewards_earned: rewards,
                 total_user_bonuses: rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, &code, &code_stats)
@@ -3211,6 +4364,7 @@ mod tests {
             total_rewards_earned: new_rewards,
             total_user_bonuses: new_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, new_code, &new_stats)
@@ -3261,6 +4415,7 @@ mod tests {
                 total_rewards_earned: rewards,
                 total_user_bonuses: rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, &code, &code_stats)
@@ -3275,6 +4430,7 @@ mod tests {
             total_rewards_earned: new_rewards,
             total_user_bonuses: new_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, new_code, &new_stats)
@@ -3317,6 +4473,7 @@ mod tests {
                 total_rewards_earned: *rewards,
                 total_user_bonuses: *rewards,
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
@@ -3330,6 +4487,7 @@ mod tests {
             total_rewards_earned: delta_rewards,
             total_user_bonuses: delta_rewards,
             total_swaps: 1,
+            owner: None,
         };
         REFERRAL_CODE_STATS
             .save(&mut deps.storage, "delta", &delta_stats)
@@ -3431,7 +4589,7 @@ mod tests {
                             Err(e) => {
                                 return SystemResult::Err(SystemError::InvalidRequest {
                                     error: format!("Parsing referral query: {}", e),
-                                    request: msg.into(),
+                                    request: msg,
                                 })
                             }
                         };
@@ -3448,7 +4606,7 @@ mod tests {
                                 let response = ReferralValidateResponse {
                                     is_valid_format,
                                     is_registered,
-                                    owner: owner.map(|o| Addr::unchecked(o)),
+                                    owner: owner.map(Addr::unchecked),
                                 };
 
                                 SystemResult::Ok(ContractResult::Ok(
@@ -3493,6 +4651,7 @@ mod tests {
             start_rate: Decimal::from_ratio(15u128, 10u128),
             end_rate: Decimal::from_ratio(25u128, 10u128),
             duration_seconds: None,
+            rate_schedule: None,
             admin: ADMIN.to_string(),
         };
         let info = mock_info("creator", &[]);
@@ -3513,6 +4672,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("TESTCODE".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -3581,6 +4742,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("BAD!".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -3605,6 +4768,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("NOTREGISTERED".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -3639,6 +4804,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
@@ -3660,6 +4827,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("MYCODE".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -3668,6 +4837,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("mycode".to_string()), // Test case-insensitivity
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -3705,6 +4876,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("CODE1".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -3740,6 +4913,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("STATCODE".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
         execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -3798,12 +4973,15 @@ mod tests {
                 total_rewards_earned: Uint128::from(rewards),
                 total_user_bonuses: Uint128::from(rewards),
                 total_swaps: 1,
+                owner: None,
             };
             REFERRAL_CODE_STATS
                 .save(&mut deps.storage, code, &code_stats)
                 .unwrap();
             try_insert_into_leaderboard(&mut deps.storage, code, Uint128::from(rewards), None)
                 .unwrap();
+            let rewards = Uint128::from(rewards);
+            update_referral_ranking(&mut deps.storage, code, rewards, rewards).unwrap();
         }
 
         // Query first page with limit 2
@@ -3835,50 +5013,489 @@ mod tests {
         assert_eq!(leaderboard.entries[0].rank, 3);
     }
 
+    /// Store stats for a code and place it in both the top-50 list and the full ranking
+    fn seed_ranked_code(storage: &mut dyn cosmwasm_std::Storage, code: &str, rewards: u128) {
+        let rewards = Uint128::from(rewards);
+        let code_stats = ReferralCodeStats {
+            total_rewards_earned: rewards,
+            total_user_bonuses: rewards,
+            total_swaps: 1,
+            owner: Some(Addr::unchecked(format!("{}_owner", code))),
+        };
+        REFERRAL_CODE_STATS
+            .save(storage, code, &code_stats)
+            .unwrap();
+        update_referral_ranking(storage, code, rewards, rewards).unwrap();
+        try_insert_into_leaderboard(storage, code, rewards, None).unwrap();
+    }
+
+    fn leaderboard_page(
+        deps: Deps,
+        start_after: Option<&str>,
+        limit: u32,
+    ) -> ReferralLeaderboardResponse {
+        let msg = QueryMsg::ReferralLeaderboard {
+            start_after: start_after.map(str::to_string),
+            limit: Some(limit),
+        };
+        from_json(query(deps, mock_env(), msg).unwrap()).unwrap()
+    }
+
     #[test]
-    fn test_query_swap_simulation_with_valid_referral() {
-        let mut deps = mock_deps_with_referral(vec![
-            ("VALIDCODE", true, true, Some("owner_addr")),
-        ]);
+    fn test_referral_leaderboard_pages_past_top_50() {
+        // Nothing registered with the referral mock: owners come from the cached stats
+        let mut deps = mock_deps_with_referral(vec![]);
         let env = mock_env();
         setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
 
-        let query_msg = QueryMsg::SwapSimulation {
-            ustc_amount: Uint128::from(15_000_000u128),
-            referral_code: Some("VALIDCODE".to_string()),
+        // 50 codes fill the list (rewards 5000 down to 100), three more fall below it
+        for i in 0..50u128 {
+            seed_ranked_code(&mut deps.storage, &format!("code{:02}", i), (50 - i) * 100);
+        }
+        seed_ranked_code(&mut deps.storage, "deep_b", 50);
+        seed_ranked_code(&mut deps.storage, "deep_c", 50);
+        seed_ranked_code(&mut deps.storage, "deep_a", 20);
+        assert_eq!(LEADERBOARD_SIZE.load(&deps.storage).unwrap(), 50);
+        assert!(!LEADERBOARD_LINKS.has(&deps.storage, "deep_b"));
+
+        let page = leaderboard_page(deps.as_ref(), None, 50);
+        assert_eq!(page.entries.len(), 50);
+        assert!(page.has_more);
+        assert_eq!(page.entries[49].code, "code49");
+
+        // Next page continues below the list; ties are ordered by code and share a rank
+        let page = leaderboard_page(deps.as_ref(), Some("code49"), 50);
+        assert!(!page.has_more);
+        let ranked: Vec<(&str, u32)> = page
+            .entries
+            .iter()
+            .map(|e| (e.code.as_str(), e.rank))
+            .collect();
+        assert_eq!(ranked, vec![("deep_b", 51), ("deep_c", 51), ("deep_a", 53)]);
+        assert_eq!(page.entries[0].owner, Some(Addr::unchecked("deep_b_owner")));
+
+        // A page can straddle the tail
+        let page = leaderboard_page(deps.as_ref(), Some("code48"), 2);
+        let ranked: Vec<(&str, u32)> = page
+            .entries
+            .iter()
+            .map(|e| (e.code.as_str(), e.rank))
+            .collect();
+        assert_eq!(ranked, vec![("code49", 50), ("deep_b", 51)]);
+        assert!(page.has_more);
+
+        // Cursor below the list
+        let page = leaderboard_page(deps.as_ref(), Some("deep_b"), 1);
+        assert_eq!(page.entries[0].code, "deep_c");
+        assert_eq!(page.entries[0].rank, 51);
+        assert!(page.has_more);
+
+        let page = leaderboard_page(deps.as_ref(), Some("deep_a"), 10);
+        assert!(page.entries.is_empty());
+        assert!(!page.has_more);
+
+        // Code stats report the same global rank
+        for (code, rank) in [("CODE49", 50), ("DEEP_C", 51), ("deep_a", 53)] {
+            let res = query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ReferralCodeStats {
+                    code: code.to_string(),
+                },
+            )
+            .unwrap();
+            let stats: ReferralCodeStatsResponse = from_json(res).unwrap();
+            assert_eq!(stats.rank, Some(rank));
+        }
+    }
+
+    #[test]
+    fn test_reward_counts_rank_exactly() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+        let rank = |storage: &dyn cosmwasm_std::Storage, rewards: u128| {
+            count_codes_above(storage, Uint128::new(rewards)).unwrap() + 1
         };
-        let res = query(deps.as_ref(), env, query_msg).unwrap();
-        let sim: SimulationResponse = from_json(res).unwrap();
 
-        assert!(sim.referral_valid);
-        assert_eq!(sim.base_ustr_amount, Uint128::from(TEN_USTR));
-        assert_eq!(sim.user_bonus, Uint128::from(ONE_USTR));
-        assert_eq!(sim.referrer_bonus, Uint128::from(ONE_USTR));
-        assert_eq!(sim.total_ustr_to_user, Uint128::from(TEN_USTR + ONE_USTR));
+        // Values spread over digit counts, shared prefixes and the extremes
+        let codes = [
+            ("zero", 0u128),
+            ("one", 1),
+            ("f", 0xf),
+            ("ten", 0x10),
+            ("tie_a", 0x1234),
+            ("tie_b", 0x1234),
+            ("near", 0x1235),
+            ("wide", 0x1f00),
+            ("max", u128::MAX),
+        ];
+        for (code, rewards) in codes {
+            let rewards = Uint128::new(rewards);
+            update_referral_ranking(storage, code, rewards, rewards).unwrap();
+        }
+        // Ranking the same code again changes nothing
+        update_referral_ranking(storage, "near", Uint128::new(0x1235), Uint128::new(0x1235))
+            .unwrap();
+
+        for (code, rewards) in codes {
+            let expected = codes.iter().filter(|(_, other)| *other > rewards).count() as u32 + 1;
+            assert_eq!(rank(storage, rewards), expected, "{}", code);
+        }
+        assert_eq!(rank(storage, 0x1234), 4);
+        assert_eq!(rank(storage, 0x1233), 6);
+
+        // Moving a code updates the counts on both paths
+        update_referral_ranking(storage, "tie_a", Uint128::new(0x1234), Uint128::new(0x2000))
+            .unwrap();
+        assert_eq!(rank(storage, 0x2000), 2);
+        assert_eq!(rank(storage, 0x1f00), 3);
+        assert_eq!(rank(storage, 0x1234), 5);
+        assert_eq!(rank(storage, 0x1233), 6);
+
+        // The vacated leaf is removed rather than left at zero
+        let exact = *reward_count_path(Uint128::new(0x1234)).last().unwrap();
+        assert_eq!(REFERRAL_REWARD_COUNTS.load(storage, exact).unwrap(), 1);
+        update_referral_ranking(storage, "tie_b", Uint128::new(0x1234), Uint128::new(0x2000))
+            .unwrap();
+        assert!(!REFERRAL_REWARD_COUNTS.has(storage, exact));
+        assert_eq!(rank(storage, 0x2000), 2);
+        assert_eq!(rank(storage, 0x1f00), 4);
     }
 
     #[test]
-    fn test_query_swap_simulation_with_invalid_referral() {
+    fn test_swap_updates_referral_ranking() {
         let mut deps = mock_deps_with_referral(vec![
-            ("BADCODE", false, false, None),
+            ("TESTCODE", true, true, Some("referrer_addr")),
+            ("unused", true, true, Some("owner")),
         ]);
         let env = mock_env();
         setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
 
-        let query_msg = QueryMsg::SwapSimulation {
-            ustc_amount: Uint128::from(15_000_000u128),
-            referral_code: Some("BADCODE".to_string()),
-        };
-        let res = query(deps.as_ref(), env, query_msg).unwrap();
-        let sim: SimulationResponse = from_json(res).unwrap();
+        for _ in 0..2 {
+            let msg = ExecuteMsg::Swap {
+                referral_code: Some("TESTCODE".to_string()),
+                leaderboard_hint: None,
+                min_total_ustr: None,
+                max_rate: None,
+            };
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("user", &ustc_coins(15_000_000)),
+                msg,
+            )
+            .unwrap();
+        }
 
-        assert!(!sim.referral_valid);
-        assert_eq!(sim.user_bonus, Uint128::zero());
-        assert_eq!(sim.referrer_bonus, Uint128::zero());
-        assert_eq!(sim.total_ustr_to_user, Uint128::from(TEN_USTR));
-    }
+        // One entry, keyed by the latest rewards
+        let keys = REFERRAL_RANKING
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            keys,
+            vec![(u128::MAX - 2 * ONE_USTR, "testcode".to_string())]
+        );
 
-    #[test]
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ReferralCodeStats {
+                code: "testcode".to_string(),
+            },
+        )
+        .unwrap();
+        let stats: ReferralCodeStatsResponse = from_json(res).unwrap();
+        assert_eq!(stats.rank, Some(1));
+
+        // Registered but never used
+        let res = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ReferralCodeStats {
+                code: "unused".to_string(),
+            },
+        )
+        .unwrap();
+        let stats: ReferralCodeStatsResponse = from_json(res).unwrap();
+        assert_eq!(stats.rank, None);
+    }
+
+    /// Value of the response attribute with `key`
+    fn attr_value<'a>(res: &'a Response, key: &str) -> &'a str {
+        res.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+            .unwrap_or_else(|| panic!("missing attribute {}", key))
+    }
+
+    #[test]
+    fn test_migrate_backfills_referral_codes_in_pages() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        // More pre-upgrade codes than one page, all with cached owners
+        let codes = DEFAULT_BACKFILL_LIMIT as u128 + 5;
+        for i in 0..codes {
+            let rewards = Uint128::from(i + 1);
+            let code_stats = ReferralCodeStats {
+                total_rewards_earned: rewards,
+                total_user_bonuses: rewards,
+                total_swaps: 1,
+                owner: Some(Addr::unchecked("owner")),
+            };
+            REFERRAL_CODE_STATS
+                .save(&mut deps.storage, &format!("code{:03}", i), &code_stats)
+                .unwrap();
+        }
+        let ranked = |deps: &OwnedDeps<MockStorage, MockApi, ReferralMockQuerier>| {
+            REFERRAL_RANKING
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count()
+        };
+
+        // The migration does one page and leaves a cursor
+        let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+        assert_eq!(attr_value(&res, "referral_backfill_complete"), "false");
+        assert_eq!(ranked(&deps), DEFAULT_BACKFILL_LIMIT as usize);
+        assert_eq!(
+            REFERRAL_BACKFILL_CURSOR.load(&deps.storage).unwrap(),
+            format!("code{:03}", DEFAULT_BACKFILL_LIMIT - 1)
+        );
+
+        // Anyone can continue it
+        let msg = ExecuteMsg::BackfillReferralCodes { limit: Some(3) };
+        let res = execute(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+        assert_eq!(attr_value(&res, "referral_codes_processed"), "3");
+        assert_eq!(attr_value(&res, "referral_backfill_complete"), "false");
+
+        let msg = ExecuteMsg::BackfillReferralCodes { limit: None };
+        let res = execute(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+        assert_eq!(attr_value(&res, "referral_codes_processed"), "2");
+        assert_eq!(attr_value(&res, "referral_backfill_complete"), "true");
+        assert_eq!(ranked(&deps), codes as usize);
+        assert!(!REFERRAL_BACKFILL_CURSOR.exists(&deps.storage));
+        let lowest_rank = |deps: &OwnedDeps<MockStorage, MockApi, ReferralMockQuerier>| {
+            count_codes_above(&deps.storage, Uint128::one()).unwrap() + 1
+        };
+        assert_eq!(lowest_rank(&deps), codes as u32);
+
+        let msg = ExecuteMsg::BackfillReferralCodes { limit: None };
+        let err = execute(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::NoReferralBackfillPending {}));
+
+        // Migrating again re-runs the first page without double counting
+        migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+        assert_eq!(lowest_rank(&deps), codes as u32);
+    }
+
+    #[test]
+    fn test_migrate_backfills_ranking_and_owners() {
+        let mut deps = mock_deps_with_referral(vec![
+            ("alpha", true, true, Some("alpha_owner")),
+            ("beta", true, true, Some("beta_owner")),
+        ]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        // Stats recorded before the ranking and owner caching existed
+        for (code, rewards) in [("alpha", 300u128), ("beta", 100u128)] {
+            let rewards = Uint128::from(rewards);
+            let code_stats = ReferralCodeStats {
+                total_rewards_earned: rewards,
+                total_user_bonuses: rewards,
+                total_swaps: 1,
+                owner: None,
+            };
+            REFERRAL_CODE_STATS
+                .save(&mut deps.storage, code, &code_stats)
+                .unwrap();
+        }
+
+        migrate(deps.as_mut(), env, MigrateMsg {}).unwrap();
+
+        let keys = REFERRAL_RANKING
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                (u128::MAX - 300, "alpha".to_string()),
+                (u128::MAX - 100, "beta".to_string()),
+            ]
+        );
+
+        let alpha = REFERRAL_CODE_STATS.load(&deps.storage, "alpha").unwrap();
+        assert_eq!(alpha.owner, Some(Addr::unchecked("alpha_owner")));
+        let beta = REFERRAL_CODE_STATS.load(&deps.storage, "beta").unwrap();
+        assert_eq!(beta.owner, Some(Addr::unchecked("beta_owner")));
+    }
+
+    #[test]
+    fn test_migrate_skips_failed_owner_lookups() {
+        let mut deps = mock_deps_with_referral(vec![("alpha", true, true, Some("alpha_owner"))]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        for code in ["alpha", "beta"] {
+            let code_stats = ReferralCodeStats {
+                total_rewards_earned: Uint128::from(100u128),
+                total_user_bonuses: Uint128::from(100u128),
+                total_swaps: 1,
+                owner: None,
+            };
+            REFERRAL_CODE_STATS
+                .save(&mut deps.storage, code, &code_stats)
+                .unwrap();
+        }
+
+        // The Referral contract is unreachable during the migration
+        let mut config = CONFIG.load(&deps.storage).unwrap();
+        config.referral = Addr::unchecked("missing_referral");
+        CONFIG.save(&mut deps.storage, &config).unwrap();
+
+        let res = migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+        assert_eq!(attr_value(&res, "referral_backfill_complete"), "true");
+        assert_eq!(
+            attr_value(&res, "referral_owner_lookups_failed"),
+            "alpha,beta"
+        );
+
+        // Codes are still ranked, only their owners are missing
+        let keys = REFERRAL_RANKING
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .count();
+        assert_eq!(keys, 2);
+        let alpha = REFERRAL_CODE_STATS.load(&deps.storage, "alpha").unwrap();
+        assert_eq!(alpha.owner, None);
+        let page = leaderboard_page(deps.as_ref(), None, 10);
+        assert_eq!(page.entries[0].code, "alpha");
+        assert_eq!(page.entries[0].owner, None);
+
+        // Once the Referral contract answers again, a refresh fills the owner
+        config.referral = Addr::unchecked(REFERRAL);
+        CONFIG.save(&mut deps.storage, &config).unwrap();
+        let msg = ExecuteMsg::RefreshReferralOwner {
+            code: "alpha".to_string(),
+        };
+        execute(deps.as_mut(), env, mock_info("anyone", &[]), msg).unwrap();
+        let alpha = REFERRAL_CODE_STATS.load(&deps.storage, "alpha").unwrap();
+        assert_eq!(alpha.owner, Some(Addr::unchecked("alpha_owner")));
+    }
+
+    #[test]
+    fn test_referral_owner_cached_and_refreshed() {
+        let mut deps = mock_deps_with_referral(vec![("TESTCODE", true, true, Some("old_owner"))]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let msg = ExecuteMsg::Swap {
+            referral_code: Some("TESTCODE".to_string()),
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("user", &ustc_coins(15_000_000)),
+            msg,
+        )
+        .unwrap();
+
+        let code_stats = REFERRAL_CODE_STATS.load(&deps.storage, "testcode").unwrap();
+        assert_eq!(code_stats.owner, Some(Addr::unchecked("old_owner")));
+
+        // Ownership changes in the Referral contract; queries keep serving the cache
+        deps.querier = ReferralMockQuerier::new().with_referral_code(
+            "testcode",
+            true,
+            true,
+            Some("new_owner"),
+        );
+
+        fn owner_from_queries(deps: Deps) -> (String, String) {
+            let msg = QueryMsg::ReferralCodeStats {
+                code: "testcode".to_string(),
+            };
+            let stats: ReferralCodeStatsResponse =
+                from_json(query(deps, mock_env(), msg).unwrap()).unwrap();
+            let page = leaderboard_page(deps, None, 10);
+            let entry_owner = page.entries[0].owner.as_ref().unwrap();
+            (stats.owner.to_string(), entry_owner.to_string())
+        }
+        assert_eq!(
+            owner_from_queries(deps.as_ref()),
+            ("old_owner".to_string(), "old_owner".to_string())
+        );
+
+        // Anyone can refresh the cache
+        let msg = ExecuteMsg::RefreshReferralOwner {
+            code: "TESTCODE".to_string(),
+        };
+        let res = execute(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+        assert_eq!(attr_value(&res, "action"), "refresh_referral_owner");
+        assert_eq!(attr_value(&res, "owner"), "new_owner");
+        assert_eq!(
+            owner_from_queries(deps.as_ref()),
+            ("new_owner".to_string(), "new_owner".to_string())
+        );
+
+        // Only used codes have a cache to refresh
+        let msg = ExecuteMsg::RefreshReferralOwner {
+            code: "other".to_string(),
+        };
+        let err = execute(deps.as_mut(), env, mock_info("anyone", &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::ReferralCodeNotUsed { .. }));
+    }
+
+    #[test]
+    fn test_query_swap_simulation_with_valid_referral() {
+        let mut deps = mock_deps_with_referral(vec![("VALIDCODE", true, true, Some("owner_addr"))]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let query_msg = QueryMsg::SwapSimulation {
+            ustc_amount: Uint128::from(15_000_000u128),
+            referral_code: Some("VALIDCODE".to_string()),
+            min_total_ustr: None,
+            max_rate: None,
+        };
+        let res = query(deps.as_ref(), env, query_msg).unwrap();
+        let sim: SimulationResponse = from_json(res).unwrap();
+
+        assert!(sim.referral_valid);
+        assert_eq!(sim.base_ustr_amount, Uint128::from(TEN_USTR));
+        assert_eq!(sim.user_bonus, Uint128::from(ONE_USTR));
+        assert_eq!(sim.referrer_bonus, Uint128::from(ONE_USTR));
+        assert_eq!(sim.total_ustr_to_user, Uint128::from(TEN_USTR + ONE_USTR));
+    }
+
+    #[test]
+    fn test_query_swap_simulation_with_invalid_referral() {
+        let mut deps = mock_deps_with_referral(vec![("BADCODE", false, false, None)]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let query_msg = QueryMsg::SwapSimulation {
+            ustc_amount: Uint128::from(15_000_000u128),
+            referral_code: Some("BADCODE".to_string()),
+            min_total_ustr: None,
+            max_rate: None,
+        };
+        let res = query(deps.as_ref(), env, query_msg).unwrap();
+        let sim: SimulationResponse = from_json(res).unwrap();
+
+        assert!(!sim.referral_valid);
+        assert_eq!(sim.user_bonus, Uint128::zero());
+        assert_eq!(sim.referrer_bonus, Uint128::zero());
+        assert_eq!(sim.total_ustr_to_user, Uint128::from(TEN_USTR));
+    }
+
+    #[test]
     fn test_query_swap_simulation_with_empty_referral() {
         let mut deps = mock_deps_with_referral(vec![]);
         let env = mock_env();
@@ -3887,6 +5504,8 @@ mod tests {
         let query_msg = QueryMsg::SwapSimulation {
             ustc_amount: Uint128::from(15_000_000u128),
             referral_code: Some("".to_string()),
+            min_total_ustr: None,
+            max_rate: None,
         };
         let res = query(deps.as_ref(), env, query_msg).unwrap();
         let sim: SimulationResponse = from_json(res).unwrap();
@@ -3898,9 +5517,7 @@ mod tests {
     #[test]
     fn test_referral_code_case_insensitive() {
         // Setup with a lowercase code
-        let mut deps = mock_deps_with_referral(vec![
-            ("mycode", true, true, Some("referrer")),
-        ]);
+        let mut deps = mock_deps_with_referral(vec![("mycode", true, true, Some("referrer"))]);
         let env = mock_env();
         setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
 
@@ -3909,6 +5526,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("MYCODE".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -3931,6 +5550,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("CODE".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -3987,6 +5608,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4018,6 +5641,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4048,6 +5673,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4078,6 +5705,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4103,6 +5732,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4130,6 +5761,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4159,6 +5792,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         );
         assert!(res.is_ok(), "555 USTC should not overflow");
@@ -4175,6 +5810,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         );
         assert!(res.is_ok(), "1000 USTC should not overflow");
@@ -4191,6 +5828,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         );
         assert!(res.is_ok(), "3487 USTC should not overflow");
@@ -4203,6 +5842,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         );
         assert!(res.is_ok(), "1 million USTC should not overflow");
@@ -4233,6 +5874,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: Some("BONUSCODE".to_string()),
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4258,6 +5901,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -4289,6 +5934,8 @@ mod tests {
             QueryMsg::SwapSimulation {
                 ustc_amount,
                 referral_code: None,
+                min_total_ustr: None,
+                max_rate: None,
             },
         )
         .unwrap();
@@ -4312,6 +5959,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         // Should succeed - 10 USTR is way under 5% of 1 billion
@@ -4339,6 +5988,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         // Should fail - 10 USTR > 5% of 100 USTR (which is 5 USTR)
@@ -4368,6 +6019,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: None,
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         // Should succeed - 10 USTR = 5% of 200 USTR (equal is allowed)
@@ -4398,6 +6051,8 @@ mod tests {
         let msg = ExecuteMsg::Swap {
             referral_code: Some("TESTCODE".to_string()),
             leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
         };
 
         // Should fail - 12 USTR > 5% of 200 USTR (10 USTR)
@@ -4407,5 +6062,908 @@ mod tests {
             _ => panic!("Expected MintExceedsSafetyLimit error, got: {:?}", err),
         }
     }
+
+    // ============ NOTIFY DEPOSIT TESTS ============
+
+    #[test]
+    fn test_notify_deposit_success_no_referral() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let ustc_amount = 15_000_000u128; // 15 USTC
+        let info = mock_info(TREASURY, &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(ustc_amount),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let res = execute(deps.as_mut(), env, info, msg).unwrap();
+
+        // Only the mint to user - USTC is already in the Treasury
+        assert_eq!(res.messages.len(), 1);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, USTR_TOKEN);
+                let mint: Cw20ExecuteMsg = from_json(msg).unwrap();
+                assert_eq!(
+                    mint,
+                    Cw20ExecuteMsg::Mint {
+                        recipient: "user".to_string(),
+                        amount: Uint128::from(TEN_USTR),
+                    }
+                );
+            }
+            _ => panic!("Expected WasmMsg::Execute"),
+        }
+
+        assert_eq!(res.attributes[0].value, "notify_deposit");
+        assert_eq!(res.attributes[1].value, "user");
+        assert_eq!(res.attributes[2].value, ustc_amount.to_string());
+        assert_eq!(res.attributes[6].value, TEN_USTR.to_string()); // total_ustr_to_user
+
+        let stats = STATS.load(&deps.storage).unwrap();
+        assert_eq!(stats.total_ustc_received, Uint128::from(ustc_amount));
+        assert_eq!(stats.total_ustr_minted, Uint128::from(TEN_USTR));
+    }
+
+    #[test]
+    fn test_notify_deposit_with_referral_code() {
+        let mut deps =
+            mock_deps_with_referral(vec![("TESTCODE", true, true, Some("referrer_addr"))]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let info = mock_info(TREASURY, &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(15_000_000u128),
+            referral_code: Some("TESTCODE".to_string()),
+            leaderboard_hint: Some(crate::msg::LeaderboardHint { insert_after: None }),
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let res = execute(deps.as_mut(), env, info, msg).unwrap();
+
+        // Mint to user and mint to referrer, no USTC forward
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(res.attributes[6].value, (TEN_USTR + ONE_USTR).to_string());
+        assert_eq!(res.attributes[7].value, "referrer_addr");
+        assert_eq!(res.attributes[8].value, ONE_USTR.to_string());
+
+        let code_stats = REFERRAL_CODE_STATS.load(&deps.storage, "testcode").unwrap();
+        assert_eq!(code_stats.total_rewards_earned, Uint128::from(ONE_USTR));
+        assert_eq!(code_stats.total_swaps, 1);
+
+        let head = LEADERBOARD_HEAD.load(&deps.storage).unwrap();
+        assert_eq!(head, Some("testcode".to_string()));
+
+        let stats = STATS.load(&deps.storage).unwrap();
+        assert_eq!(stats.total_referral_swaps, 1);
+        assert_eq!(stats.unique_referral_codes_used, 1);
+    }
+
+    #[test]
+    fn test_notify_deposit_unauthorized() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        // Anyone other than the Treasury must be rejected, otherwise USTR could be
+        // minted without any USTC being deposited
+        let info = mock_info("user", &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(15_000_000u128),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert_eq!(err, ContractError::UnauthorizedTreasury);
+    }
+
+    #[test]
+    fn test_notify_deposit_paused() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            ExecuteMsg::EmergencyPause {},
+        )
+        .unwrap();
+
+        let info = mock_info(TREASURY, &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(15_000_000u128),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert_eq!(err, ContractError::SwapPaused);
+    }
+
+    #[test]
+    fn test_notify_deposit_after_end() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let mut env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        env.block.time = env.block.time.plus_seconds(8_640_001);
+
+        let info = mock_info(TREASURY, &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(15_000_000u128),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert_eq!(err, ContractError::SwapEnded);
+    }
+
+    #[test]
+    fn test_notify_deposit_below_minimum() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let info = mock_info(TREASURY, &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(999_999u128),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert_eq!(err, ContractError::BelowMinimumSwap);
+    }
+
+    #[test]
+    fn test_notify_deposit_mint_safety_limit() {
+        let small_supply = Uint128::from(100_000_000_000_000_000_000u128); // 100 USTR
+        let querier = ReferralMockQuerier::new().with_ustr_total_supply(small_supply);
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier,
+            custom_query_type: std::marker::PhantomData,
+        };
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let info = mock_info(TREASURY, &[]);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(15_000_000u128), // 10 USTR > 5% of 100 USTR
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+
+        let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::MintExceedsSafetyLimit { .. }));
+    }
+
+    // ============ SLIPPAGE TESTS ============
+
+    #[test]
+    fn test_swap_slippage_limits() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        // At day 0 the rate is 1.5, so 15 USTC buys exactly 10 USTR
+        let swap = |min_total_ustr: Option<u128>, max_rate: Option<Decimal>| ExecuteMsg::Swap {
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: min_total_ustr.map(Uint128::from),
+            max_rate,
+        };
+        let info = mock_info("user", &ustc_coins(15_000_000));
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            swap(Some(TEN_USTR + 1), None),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::SlippageExceeded {
+                total_ustr: TEN_USTR.to_string(),
+                min_total_ustr: (TEN_USTR + 1).to_string(),
+            }
+        );
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            swap(None, Some(Decimal::percent(149))),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::RateAboveMax {
+                rate: "1.5".to_string(),
+                max_rate: "1.49".to_string(),
+            }
+        );
+
+        // Nothing was recorded for the failed attempts
+        assert_eq!(
+            STATS.load(&deps.storage).unwrap().total_ustc_received,
+            Uint128::zero()
+        );
+
+        // Bounds met exactly are accepted
+        let res = execute(
+            deps.as_mut(),
+            env,
+            info,
+            swap(Some(TEN_USTR), Some(Decimal::percent(150))),
+        )
+        .unwrap();
+        assert_eq!(res.attributes[6].value, TEN_USTR.to_string());
+    }
+
+    #[test]
+    fn test_notify_deposit_slippage_limits() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let mut env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        // Deposit signed at day 0 but included after the rate has moved
+        env.block.time = env.block.time.plus_seconds(DEFAULT_SWAP_DURATION / 2);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(15_000_000u128),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: Some(Decimal::percent(150)),
+        };
+        let err = execute(deps.as_mut(), env, mock_info(TREASURY, &[]), msg).unwrap_err();
+        assert!(matches!(err, ContractError::RateAboveMax { .. }));
+    }
+
+    #[test]
+    fn test_swap_simulation_reports_limits() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let env = mock_env();
+        setup_contract_with_querier(deps.as_mut(), env.block.time.seconds());
+
+        let simulate =
+            |min_total_ustr: Option<u128>, max_rate: Option<Decimal>| QueryMsg::SwapSimulation {
+                ustc_amount: Uint128::from(15_000_000u128),
+                referral_code: None,
+                min_total_ustr: min_total_ustr.map(Uint128::from),
+                max_rate,
+            };
+
+        let sim: SimulationResponse =
+            from_json(query(deps.as_ref(), env.clone(), simulate(None, None)).unwrap()).unwrap();
+        assert!(sim.within_limits);
+
+        let sim: SimulationResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                simulate(Some(TEN_USTR), Some(Decimal::percent(150))),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(sim.within_limits);
+
+        let sim: SimulationResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                simulate(Some(TEN_USTR + 1), None),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(!sim.within_limits);
+
+        let sim: SimulationResponse = from_json(
+            query(
+                deps.as_ref(),
+                env,
+                simulate(None, Some(Decimal::percent(149))),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(!sim.within_limits);
+    }
+
+    // ============ SWAP HISTORY TESTS ============
+
+    fn swap_at(
+        deps: DepsMut,
+        time: u64,
+        user: &str,
+        ustc_amount: u128,
+        referral_code: Option<&str>,
+    ) -> Response {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(time);
+        let msg = ExecuteMsg::Swap {
+            referral_code: referral_code.map(str::to_string),
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+        execute(deps, env, mock_info(user, &ustc_coins(ustc_amount)), msg).unwrap()
+    }
+
+    fn receipt_ids(deps: Deps, msg: QueryMsg) -> Vec<u64> {
+        let res: SwapReceiptsResponse = from_json(query(deps, mock_env(), msg).unwrap()).unwrap();
+        res.receipts.into_iter().map(|r| r.id).collect()
+    }
+
+    #[test]
+    fn test_user_stats_and_receipts() {
+        let mut deps =
+            mock_deps_with_referral(vec![("TESTCODE", true, true, Some("referrer_addr"))]);
+        let start_time = mock_env().block.time.seconds();
+        setup_contract_with_querier(deps.as_mut(), start_time);
+
+        // Swap with referral at day 0: 10 USTR + 1 USTR bonus
+        let res = swap_at(
+            deps.as_mut(),
+            start_time,
+            "user",
+            15_000_000,
+            Some("TESTCODE"),
+        );
+        assert_eq!(res.attributes.last().unwrap().value, "0"); // swap_id
+
+        swap_at(deps.as_mut(), start_time + DAY, "other", 15_000_000, None);
+
+        // Deposit through the Treasury at day 2 counts for the depositor
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(start_time + 2 * DAY);
+        let msg = ExecuteMsg::NotifyDeposit {
+            depositor: "user".to_string(),
+            amount: Uint128::from(30_000_000u128),
+            referral_code: None,
+            leaderboard_hint: None,
+            min_total_ustr: None,
+            max_rate: None,
+        };
+        let res = execute(deps.as_mut(), env, mock_info(TREASURY, &[]), msg).unwrap();
+        let second_ustr: Uint128 = res.attributes[6].value.parse().unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::UserStats {
+                address: "user".to_string(),
+            },
+        )
+        .unwrap();
+        let stats: UserStatsResponse = from_json(res).unwrap();
+        assert_eq!(stats.total_ustc_swapped, Uint128::from(45_000_000u128));
+        assert_eq!(
+            stats.total_ustr_received,
+            Uint128::from(TEN_USTR + ONE_USTR) + second_ustr
+        );
+        assert_eq!(stats.total_bonus_received, Uint128::from(ONE_USTR));
+        assert_eq!(stats.swap_count, 2);
+        assert_eq!(
+            stats.first_swap_time,
+            Some(Timestamp::from_seconds(start_time))
+        );
+        assert_eq!(
+            stats.last_swap_time,
+            Some(Timestamp::from_seconds(start_time + 2 * DAY))
+        );
+
+        // Address that never swapped
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::UserStats {
+                address: "nobody".to_string(),
+            },
+        )
+        .unwrap();
+        let stats: UserStatsResponse = from_json(res).unwrap();
+        assert_eq!(stats.swap_count, 0);
+        assert_eq!(stats.total_ustr_received, Uint128::zero());
+        assert_eq!(stats.first_swap_time, None);
+
+        // Receipts for one user, paginated
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::UserSwapReceipts {
+                address: "user".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let receipts: SwapReceiptsResponse = from_json(res).unwrap();
+        assert_eq!(receipts.receipts.len(), 2);
+        let first = &receipts.receipts[0];
+        assert_eq!(first.id, 0);
+        assert_eq!(first.user.as_str(), "user");
+        assert_eq!(first.ustc_amount, Uint128::from(15_000_000u128));
+        assert_eq!(first.rate, Decimal::from_ratio(15u128, 10u128));
+        assert_eq!(first.base_ustr, Uint128::from(TEN_USTR));
+        assert_eq!(first.user_bonus, Uint128::from(ONE_USTR));
+        assert_eq!(first.total_ustr_to_user, Uint128::from(TEN_USTR + ONE_USTR));
+        assert_eq!(first.referral_code, Some("testcode".to_string()));
+        assert_eq!(receipts.receipts[1].id, 2);
+        assert_eq!(receipts.receipts[1].referral_code, None);
+
+        let ids = receipt_ids(
+            deps.as_ref(),
+            QueryMsg::UserSwapReceipts {
+                address: "user".to_string(),
+                start_after: Some(0),
+                limit: Some(1),
+            },
+        );
+        assert_eq!(ids, vec![2]);
+    }
+
+    #[test]
+    fn test_swap_receipts_by_time_range() {
+        let mut deps = mock_deps_with_referral(vec![]);
+        let start_time = mock_env().block.time.seconds();
+        setup_contract_with_querier(deps.as_mut(), start_time);
+
+        swap_at(deps.as_mut(), start_time, "user_a", 15_000_000, None);
+        swap_at(deps.as_mut(), start_time + DAY, "user_b", 15_000_000, None);
+        swap_at(deps.as_mut(), start_time + DAY, "user_a", 15_000_000, None);
+        swap_at(
+            deps.as_mut(),
+            start_time + 3 * DAY,
+            "user_c",
+            15_000_000,
+            None,
+        );
+
+        let by_time = |from_time, to_time, start_after, limit| QueryMsg::SwapReceipts {
+            from_time,
+            to_time,
+            start_after,
+            limit,
+        };
+
+        // Everything, paginated
+        assert_eq!(
+            receipt_ids(deps.as_ref(), by_time(None, None, None, Some(3))),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            receipt_ids(deps.as_ref(), by_time(None, None, Some(2), None)),
+            vec![3]
+        );
+
+        // Bounds are inclusive and need not match a swap time exactly
+        assert_eq!(
+            receipt_ids(
+                deps.as_ref(),
+                by_time(Some(start_time + 1), Some(start_time + DAY), None, None)
+            ),
+            vec![1, 2]
+        );
+        assert_eq!(
+            receipt_ids(
+                deps.as_ref(),
+                by_time(Some(start_time + DAY), None, Some(1), None)
+            ),
+            vec![2, 3]
+        );
+        assert_eq!(
+            receipt_ids(
+                deps.as_ref(),
+                by_time(None, Some(start_time + DAY - 1), None, None)
+            ),
+            vec![0]
+        );
+
+        // Nothing after the last swap
+        assert!(receipt_ids(
+            deps.as_ref(),
+            by_time(Some(start_time + 4 * DAY), None, None, None)
+        )
+        .is_empty());
+    }
 }
 
+#[cfg(test)]
+mod integration_tests {
+    use cosmwasm_std::{to_json_binary, Addr, Coin, Empty, Uint128};
+    use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, MinterResponse};
+    use cw_multi_test::{App, ContractWrapper, Executor};
+
+    use crate::msg::{ExecuteMsg, QueryMsg, ReferralCodeStatsResponse, StatsResponse};
+    use crate::state::{DEFAULT_SWAP_DURATION, USTC_DENOM as DENOM_USTC};
+
+    const GOVERNANCE: &str = "governance";
+    const USER: &str = "user";
+    const REFERRER: &str = "referrer";
+    const HOLDER: &str = "holder";
+
+    /// 1 USTR with 18 decimals
+    const ONE_USTR: u128 = 1_000_000_000_000_000_000;
+
+    fn treasury_contract() -> Box<dyn cw_multi_test::Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            treasury::contract::execute,
+            treasury::contract::instantiate,
+            treasury::contract::query,
+        );
+        Box::new(contract)
+    }
+
+    fn swap_contract() -> Box<dyn cw_multi_test::Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        );
+        Box::new(contract)
+    }
+
+    fn referral_contract() -> Box<dyn cw_multi_test::Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            referral::contract::execute,
+            referral::contract::instantiate,
+            referral::contract::query,
+        );
+        Box::new(contract)
+    }
+
+    fn cw20_contract() -> Box<dyn cw_multi_test::Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            cw20_base::contract::execute,
+            cw20_base::contract::instantiate,
+            cw20_base::contract::query,
+        );
+        Box::new(contract)
+    }
+
+    struct TestEnv {
+        app: App,
+        treasury_addr: Addr,
+        swap_addr: Addr,
+        ustr_addr: Addr,
+    }
+
+    fn setup_full_env() -> TestEnv {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(USER),
+                    vec![Coin::new(100_000_000u128, DENOM_USTC)],
+                )
+                .unwrap();
+        });
+
+        // Deploy USTR (18 decimals). HOLDER supply keeps swaps under the 5% mint safety limit.
+        let cw20_code = app.store_code(cw20_contract());
+        let ustr_addr = app
+            .instantiate_contract(
+                cw20_code,
+                Addr::unchecked(GOVERNANCE),
+                &cw20_base::msg::InstantiateMsg {
+                    name: "USTR".to_string(),
+                    symbol: "USTR".to_string(),
+                    decimals: 18,
+                    initial_balances: vec![
+                        Cw20Coin {
+                            address: HOLDER.to_string(),
+                            amount: Uint128::from(1_000_000 * ONE_USTR),
+                        },
+                        Cw20Coin {
+                            address: REFERRER.to_string(),
+                            amount: Uint128::from(10 * ONE_USTR),
+                        },
+                    ],
+                    mint: Some(MinterResponse {
+                        minter: GOVERNANCE.to_string(),
+                        cap: None,
+                    }),
+                    marketing: None,
+                },
+                &[],
+                "ustr",
+                None,
+            )
+            .unwrap();
+
+        // Deploy treasury
+        let treasury_code = app.store_code(treasury_contract());
+        let treasury_addr = app
+            .instantiate_contract(
+                treasury_code,
+                Addr::unchecked(GOVERNANCE),
+                &treasury::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+
+        // Deploy referral
+        let referral_code = app.store_code(referral_contract());
+        let referral_addr = app
+            .instantiate_contract(
+                referral_code,
+                Addr::unchecked(GOVERNANCE),
+                &referral::msg::InstantiateMsg {
+                    ustr_token: ustr_addr.to_string(),
+                },
+                &[],
+                "referral",
+                None,
+            )
+            .unwrap();
+
+        // Deploy swap, starting now
+        let swap_code = app.store_code(swap_contract());
+        let start_time = app.block_info().time.seconds();
+        let swap_addr = app
+            .instantiate_contract(
+                swap_code,
+                Addr::unchecked(GOVERNANCE),
+                &crate::msg::InstantiateMsg {
+                    ustr_token: ustr_addr.to_string(),
+                    treasury: treasury_addr.to_string(),
+                    referral: referral_addr.to_string(),
+                    start_time,
+                    start_rate: cosmwasm_std::Decimal::from_ratio(15u128, 10u128),
+                    end_rate: cosmwasm_std::Decimal::from_ratio(25u128, 10u128),
+                    duration_seconds: Some(DEFAULT_SWAP_DURATION),
+                    rate_schedule: None,
+                    admin: GOVERNANCE.to_string(),
+                },
+                &[],
+                "ustc-swap",
+                None,
+            )
+            .unwrap();
+
+        // Swap contract becomes the USTR minter
+        app.execute_contract(
+            Addr::unchecked(GOVERNANCE),
+            ustr_addr.clone(),
+            &Cw20ExecuteMsg::UpdateMinter {
+                new_minter: Some(swap_addr.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Treasury forwards SwapDeposit notifications to the swap contract
+        app.execute_contract(
+            Addr::unchecked(GOVERNANCE),
+            treasury_addr.clone(),
+            &treasury::msg::ExecuteMsg::SetSwapContract {
+                contract_addr: swap_addr.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Referrer registers code "alpha" (burns the 10 USTR fee)
+        app.execute_contract(
+            Addr::unchecked(REFERRER),
+            ustr_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: referral_addr.to_string(),
+                amount: Uint128::from(10 * ONE_USTR),
+                msg: to_json_binary(&referral::msg::RegisterCodeMsg {
+                    code: "alpha".to_string(),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        TestEnv {
+            app,
+            treasury_addr,
+            swap_addr,
+            ustr_addr,
+        }
+    }
+
+    fn ustr_balance(env: &TestEnv, addr: &str) -> Uint128 {
+        let res: BalanceResponse = env
+            .app
+            .wrap()
+            .query_wasm_smart(
+                env.ustr_addr.clone(),
+                &cw20::Cw20QueryMsg::Balance {
+                    address: addr.to_string(),
+                },
+            )
+            .unwrap();
+        res.balance
+    }
+
+    fn ustc_balance(env: &TestEnv, addr: &Addr) -> Uint128 {
+        env.app
+            .wrap()
+            .query_balance(addr, DENOM_USTC)
+            .unwrap()
+            .amount
+    }
+
+    #[test]
+    fn test_swap_deposit_mints_ustr_end_to_end() {
+        let mut env = setup_full_env();
+
+        // 15 USTC at rate 1.5 = 10 USTR
+        env.app
+            .execute_contract(
+                Addr::unchecked(USER),
+                env.treasury_addr.clone(),
+                &treasury::msg::ExecuteMsg::SwapDeposit {
+                    referral_code: None,
+                    leaderboard_hint: None,
+                    min_total_ustr: None,
+                    max_rate: None,
+                },
+                &[Coin::new(15_000_000u128, DENOM_USTC)],
+            )
+            .unwrap();
+
+        assert_eq!(ustr_balance(&env, USER), Uint128::from(10 * ONE_USTR));
+        assert_eq!(
+            ustc_balance(&env, &env.treasury_addr),
+            Uint128::from(15_000_000u128)
+        );
+        assert_eq!(ustc_balance(&env, &env.swap_addr), Uint128::zero());
+
+        let stats: StatsResponse = env
+            .app
+            .wrap()
+            .query_wasm_smart(env.swap_addr.clone(), &QueryMsg::Stats {})
+            .unwrap();
+        assert_eq!(stats.total_ustc_received, Uint128::from(15_000_000u128));
+        assert_eq!(stats.total_ustr_minted, Uint128::from(10 * ONE_USTR));
+    }
+
+    #[test]
+    fn test_swap_deposit_with_referral_code_end_to_end() {
+        let mut env = setup_full_env();
+
+        env.app
+            .execute_contract(
+                Addr::unchecked(USER),
+                env.treasury_addr.clone(),
+                &treasury::msg::ExecuteMsg::SwapDeposit {
+                    referral_code: Some("ALPHA".to_string()),
+                    leaderboard_hint: Some(treasury::msg::SwapLeaderboardHint {
+                        insert_after: None,
+                    }),
+                    min_total_ustr: None,
+                    max_rate: None,
+                },
+                &[Coin::new(15_000_000u128, DENOM_USTC)],
+            )
+            .unwrap();
+
+        // 10 USTR base + 10% user bonus; referrer gets 10% bonus
+        assert_eq!(ustr_balance(&env, USER), Uint128::from(11 * ONE_USTR));
+        assert_eq!(ustr_balance(&env, REFERRER), Uint128::from(ONE_USTR));
+
+        let code_stats: ReferralCodeStatsResponse = env
+            .app
+            .wrap()
+            .query_wasm_smart(
+                env.swap_addr.clone(),
+                &QueryMsg::ReferralCodeStats {
+                    code: "alpha".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(code_stats.owner, Addr::unchecked(REFERRER));
+        assert_eq!(code_stats.total_rewards_earned, Uint128::from(ONE_USTR));
+        assert_eq!(code_stats.total_swaps, 1);
+    }
+
+    #[test]
+    fn test_notify_deposit_rejects_non_treasury() {
+        let mut env = setup_full_env();
+
+        let err = env
+            .app
+            .execute_contract(
+                Addr::unchecked(USER),
+                env.swap_addr.clone(),
+                &ExecuteMsg::NotifyDeposit {
+                    depositor: USER.to_string(),
+                    amount: Uint128::from(15_000_000u128),
+                    referral_code: None,
+                    leaderboard_hint: None,
+                    min_total_ustr: None,
+                    max_rate: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.downcast::<crate::error::ContractError>().unwrap(),
+            crate::error::ContractError::UnauthorizedTreasury
+        );
+        assert_eq!(ustr_balance(&env, USER), Uint128::zero());
+    }
+
+    #[test]
+    fn test_swap_deposit_reverts_when_swap_paused() {
+        let mut env = setup_full_env();
+
+        env.app
+            .execute_contract(
+                Addr::unchecked(GOVERNANCE),
+                env.swap_addr.clone(),
+                &ExecuteMsg::EmergencyPause {},
+                &[],
+            )
+            .unwrap();
+
+        env.app
+            .execute_contract(
+                Addr::unchecked(USER),
+                env.treasury_addr.clone(),
+                &treasury::msg::ExecuteMsg::SwapDeposit {
+                    referral_code: None,
+                    leaderboard_hint: None,
+                    min_total_ustr: None,
+                    max_rate: None,
+                },
+                &[Coin::new(15_000_000u128, DENOM_USTC)],
+            )
+            .unwrap_err();
+
+        // Whole transaction reverted: user keeps USTC, treasury holds nothing
+        assert_eq!(
+            ustc_balance(&env, &Addr::unchecked(USER)),
+            Uint128::from(100_000_000u128)
+        );
+        assert_eq!(ustc_balance(&env, &env.treasury_addr), Uint128::zero());
+        assert_eq!(ustr_balance(&env, USER), Uint128::zero());
+    }
+}
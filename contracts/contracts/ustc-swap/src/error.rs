@@ -11,9 +11,15 @@ pub enum ContractError {
     #[error("USTR token has {actual} decimals, expected {expected}")]
     InvalidUstrDecimals { expected: u8, actual: u8 },
 
+    #[error("Invalid rate schedule: {reason}")]
+    InvalidRateSchedule { reason: String },
+
     #[error("Unauthorized: only admin can perform this action")]
     Unauthorized,
 
+    #[error("Unauthorized: only treasury can notify deposits")]
+    UnauthorizedTreasury,
+
     #[error("Unauthorized: only pending admin can accept")]
     UnauthorizedPendingAdmin,
 
@@ -56,6 +62,21 @@ pub enum ContractError {
     #[error("Referral code not registered: {code}")]
     ReferralCodeNotRegistered { code: String },
 
+    #[error("Referral code has not been used in a swap: {code}")]
+    ReferralCodeNotUsed { code: String },
+
+    #[error("No referral backfill is pending")]
+    NoReferralBackfillPending {},
+
+    #[error("USTR to user {total_ustr} is below the minimum of {min_total_ustr}")]
+    SlippageExceeded {
+        total_ustr: String,
+        min_total_ustr: String,
+    },
+
+    #[error("Rate {rate} is above the maximum of {max_rate}")]
+    RateAboveMax { rate: String, max_rate: String },
+
     #[error("Mint amount {mint_amount} exceeds 5% safety limit of total supply {total_supply}")]
     MintExceedsSafetyLimit {
         mint_amount: String,
@@ -4,6 +4,8 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 use common::AssetInfo;
 
+use crate::state::{RatePoint, RateSchedule};
+
 /// Migrate message (empty for now, can be extended for future migrations)
 #[cw_serde]
 pub struct MigrateMsg {}
@@ -25,6 +27,8 @@ pub struct InstantiateMsg {
     pub end_rate: Decimal,
     /// Swap duration in seconds (defaults to 8,640,000 for 100 days if not provided)
     pub duration_seconds: Option<u64>,
+    /// Rate curve between start_rate and end_rate (defaults to linear)
+    pub rate_schedule: Option<RateSchedule>,
     /// Admin address for emergencies
     pub admin: String,
 }
@@ -54,6 +58,30 @@ pub enum ExecuteMsg {
         /// If None, falls back to O(n) search from tail.
         /// If wrong, searches from hint position (up or down).
         leaderboard_hint: Option<LeaderboardHint>,
+        /// Optional slippage bound: fails if the USTR sent to the user
+        /// (base + user bonus) would be lower
+        min_total_ustr: Option<Uint128>,
+        /// Optional slippage bound: fails if the current rate (USTC per USTR)
+        /// is higher
+        max_rate: Option<Decimal>,
+    },
+
+    /// Called by Treasury after a user's SwapDeposit (tax-optimized path)
+    /// USTC already sits in the Treasury, so nothing is forwarded
+    /// Applies the same rate, referral bonus, leaderboard and mint safety logic as Swap
+    NotifyDeposit {
+        /// Address that deposited USTC into the Treasury (receives USTR)
+        depositor: String,
+        /// USTC amount deposited (pre-tax, in uusd)
+        amount: Uint128,
+        /// Optional referral code forwarded from SwapDeposit
+        referral_code: Option<String>,
+        /// Optional leaderboard hint forwarded from SwapDeposit
+        leaderboard_hint: Option<LeaderboardHint>,
+        /// Optional minimum USTR to the depositor forwarded from SwapDeposit
+        min_total_ustr: Option<Uint128>,
+        /// Optional maximum rate forwarded from SwapDeposit
+        max_rate: Option<Decimal>,
     },
 
     /// Pauses swap functionality (admin only)
@@ -77,6 +105,14 @@ pub enum ExecuteMsg {
         amount: Uint128,
         recipient: String,
     },
+
+    /// Re-reads a used code's owner from the Referral contract into the cached
+    /// stats (anyone can call)
+    RefreshReferralOwner { code: String },
+
+    /// Continues the migration's backfill of referral codes used before the
+    /// upgrade, processing up to `limit` codes (default: 50, max: 200; anyone can call)
+    BackfillReferralCodes { limit: Option<u32> },
 }
 
 /// Query messages
@@ -91,12 +127,28 @@ pub enum QueryMsg {
     #[returns(RateResponse)]
     CurrentRate {},
 
+    /// Returns the rate at any timestamp, clamped to the swap period
+    #[returns(RateResponse)]
+    RateAt { timestamp: u64 },
+
+    /// Returns the configured schedule and evenly spaced rate samples across
+    /// the swap period, for charting
+    #[returns(RateScheduleResponse)]
+    RateSchedule {
+        /// Number of samples including both ends (default: 50, min: 2, max: 500)
+        samples: Option<u32>,
+    },
+
     /// Returns USTR amount for given USTC, including referral bonus if applicable
     #[returns(SimulationResponse)]
     SwapSimulation {
         ustc_amount: Uint128,
         /// Optional referral code to simulate bonus
         referral_code: Option<String>,
+        /// Optional slippage bound to check, as on Swap
+        min_total_ustr: Option<Uint128>,
+        /// Optional rate bound to check, as on Swap
+        max_rate: Option<Decimal>,
     },
 
     /// Returns active/ended status, time remaining
@@ -119,6 +171,7 @@ pub enum QueryMsg {
     },
 
     /// Returns paginated leaderboard of referral codes ranked by total rewards earned
+    /// Pages continue past the top 50 through every code that has earned rewards
     #[returns(ReferralLeaderboardResponse)]
     ReferralLeaderboard {
         /// Optional code to start after (for cursor-based pagination)
@@ -126,6 +179,33 @@ pub enum QueryMsg {
         /// Maximum number of entries to return (default: 10, max: 50)
         limit: Option<u32>,
     },
+
+    /// Returns swap totals for an address (zeros if it has never swapped)
+    #[returns(UserStatsResponse)]
+    UserStats { address: String },
+
+    /// Returns an address's swap receipts, oldest first
+    #[returns(SwapReceiptsResponse)]
+    UserSwapReceipts {
+        address: String,
+        /// Optional swap ID to start after (for cursor-based pagination)
+        start_after: Option<u64>,
+        /// Maximum number of receipts to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// Returns all swap receipts within a time range, oldest first
+    #[returns(SwapReceiptsResponse)]
+    SwapReceipts {
+        /// Earliest block time in seconds, inclusive (default: no lower bound)
+        from_time: Option<u64>,
+        /// Latest block time in seconds, inclusive (default: no upper bound)
+        to_time: Option<u64>,
+        /// Optional swap ID to start after (for cursor-based pagination)
+        start_after: Option<u64>,
+        /// Maximum number of receipts to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
 }
 
 /// Response for Config query
@@ -140,6 +220,7 @@ pub struct ConfigResponse {
     pub end_rate: Decimal,
     pub admin: Addr,
     pub paused: bool,
+    pub rate_schedule: RateSchedule,
 }
 
 /// Response for CurrentRate query
@@ -153,6 +234,14 @@ pub struct RateResponse {
     pub total_seconds: u64,
 }
 
+/// Response for RateSchedule query
+#[cw_serde]
+pub struct RateScheduleResponse {
+    pub schedule: RateSchedule,
+    /// Sampled rates from start_time to end_time inclusive
+    pub points: Vec<RatePoint>,
+}
+
 /// Response for SwapSimulation query
 #[cw_serde]
 pub struct SimulationResponse {
@@ -170,6 +259,8 @@ pub struct SimulationResponse {
     pub rate: Decimal,
     /// Whether referral code is valid
     pub referral_valid: bool,
+    /// Whether a swap now would satisfy `min_total_ustr` and `max_rate`
+    pub within_limits: bool,
 }
 
 /// Response for Status query
@@ -216,7 +307,8 @@ pub struct PendingAdminResponse {
 pub struct ReferralCodeStatsResponse {
     /// The referral code (normalized to lowercase)
     pub code: String,
-    /// Code owner address (queried from Referral contract)
+    /// Code owner address (cached at first use; queried from Referral contract
+    /// for codes never used)
     pub owner: Addr,
     /// Total USTR earned by the code owner from referrals
     pub total_rewards_earned: Uint128,
@@ -224,6 +316,8 @@ pub struct ReferralCodeStatsResponse {
     pub total_user_bonuses: Uint128,
     /// Number of swaps that used this referral code
     pub total_swaps: u64,
+    /// 1 + the number of codes with more rewards (tied codes share a rank), None if never used
+    pub rank: Option<u32>,
 }
 
 /// Response for ReferralLeaderboard query
@@ -240,15 +334,61 @@ pub struct ReferralLeaderboardResponse {
 pub struct LeaderboardEntry {
     /// The referral code
     pub code: String,
-    /// Code owner address
-    pub owner: Addr,
+    /// Code owner address, None while the cache has not been filled (codes used
+    /// before owner caching whose backfill lookup failed)
+    pub owner: Option<Addr>,
     /// Total USTR earned by the code owner from referrals
     pub total_rewards_earned: Uint128,
     /// Total USTR bonuses given to users who used this code
     pub total_user_bonuses: Uint128,
     /// Number of swaps that used this referral code
     pub total_swaps: u64,
-    /// Position on the leaderboard (1-indexed)
+    /// 1 + the number of codes with more rewards (tied codes share a rank)
     pub rank: u32,
 }
 
+/// Response for UserStats query
+#[cw_serde]
+pub struct UserStatsResponse {
+    pub address: Addr,
+    /// Total USTC swapped (pre-tax amounts)
+    pub total_ustc_swapped: Uint128,
+    /// Total USTR received (base + user bonus)
+    pub total_ustr_received: Uint128,
+    /// Part of total_ustr_received that was referral bonus
+    pub total_bonus_received: Uint128,
+    /// Number of swaps
+    pub swap_count: u64,
+    /// Block time of the first swap (None if the address has never swapped)
+    pub first_swap_time: Option<Timestamp>,
+    /// Block time of the most recent swap (None if the address has never swapped)
+    pub last_swap_time: Option<Timestamp>,
+}
+
+/// Response for UserSwapReceipts and SwapReceipts queries
+#[cw_serde]
+pub struct SwapReceiptsResponse {
+    /// Receipts in ascending ID (and time) order
+    pub receipts: Vec<SwapReceiptResponse>,
+}
+
+/// Single swap receipt
+#[cw_serde]
+pub struct SwapReceiptResponse {
+    /// Swap ID, usable as `start_after` for the next page
+    pub id: u64,
+    pub user: Addr,
+    /// USTC swapped (pre-tax amount)
+    pub ustc_amount: Uint128,
+    /// Rate used for the swap
+    pub rate: Decimal,
+    /// USTR before referral bonus
+    pub base_ustr: Uint128,
+    /// Referral bonus paid to the user
+    pub user_bonus: Uint128,
+    /// Total USTR to user (base + user_bonus)
+    pub total_ustr_to_user: Uint128,
+    /// Normalized referral code, if a valid one was used
+    pub referral_code: Option<String>,
+    pub timestamp: Timestamp,
+}
@@ -25,6 +25,46 @@ pub struct Config {
     pub admin: Addr,
     /// Whether swap is currently paused
     pub paused: bool,
+    /// How the rate moves from `start_rate` to `end_rate`
+    #[serde(default)]
+    pub rate_schedule: RateSchedule,
+}
+
+/// A rate at a point in time
+#[cw_serde]
+pub struct RatePoint {
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+    /// USTC/USTR rate
+    pub rate: Decimal,
+}
+
+/// Rate curve over the swap period. Every schedule starts at `start_rate`,
+/// never decreases, never exceeds `end_rate` and is at `end_rate` from
+/// `end_time` on.
+#[cw_serde]
+pub enum RateSchedule {
+    /// Straight line from `start_rate` to `end_rate`
+    Linear {},
+    /// `start_rate` until the first breakpoint, then each breakpoint's rate
+    /// from its timestamp until the next
+    Stepwise { breakpoints: Vec<RatePoint> },
+    /// `start_rate * (1 + growth_per_period)^n` after `n` whole periods,
+    /// capped at `end_rate`
+    Exponential {
+        growth_per_period: Decimal,
+        period_seconds: u64,
+    },
+    /// Straight lines between `(start_time, start_rate)`, each breakpoint and
+    /// `(end_time, end_rate)`
+    PiecewiseLinear { breakpoints: Vec<RatePoint> },
+}
+
+/// Configs stored before schedules existed are linear
+impl Default for RateSchedule {
+    fn default() -> Self {
+        RateSchedule::Linear {}
+    }
 }
 
 /// Pending admin change proposal
@@ -60,6 +100,46 @@ pub struct ReferralCodeStats {
     pub total_user_bonuses: Uint128,
     /// Number of swaps that used this referral code
     pub total_swaps: u64,
+    /// Code owner, cached at first use and refreshed by every swap with the code
+    /// or `RefreshReferralOwner` (None only for entries the migration could not backfill)
+    #[serde(default)]
+    pub owner: Option<Addr>,
+}
+
+/// Per-address swap totals
+#[cw_serde]
+pub struct UserSwapStats {
+    /// Cumulative USTC swapped (pre-tax amount)
+    pub total_ustc_swapped: Uint128,
+    /// Cumulative USTR received (base + user bonus)
+    pub total_ustr_received: Uint128,
+    /// Part of total_ustr_received that was referral bonus
+    pub total_bonus_received: Uint128,
+    /// Number of swaps
+    pub swap_count: u64,
+    /// Block time of the first swap
+    pub first_swap_time: Timestamp,
+    /// Block time of the most recent swap
+    pub last_swap_time: Timestamp,
+}
+
+/// Record of a single swap
+#[cw_serde]
+pub struct SwapReceipt {
+    /// Address that received the USTR (sender of Swap, depositor of NotifyDeposit)
+    pub user: Addr,
+    /// USTC swapped (pre-tax amount)
+    pub ustc_amount: Uint128,
+    /// Rate used for the swap
+    pub rate: Decimal,
+    /// USTR before referral bonus
+    pub base_ustr: Uint128,
+    /// Referral bonus paid to the user
+    pub user_bonus: Uint128,
+    /// Normalized referral code, if a valid one was used
+    pub referral_code: Option<String>,
+    /// Block time of the swap
+    pub timestamp: Timestamp,
 }
 
 /// Linked list node for maintaining sorted leaderboard
@@ -83,6 +163,13 @@ pub const ADMIN_TIMELOCK_DURATION: u64 = 604_800;
 /// 100 days in seconds for swap duration
 pub const DEFAULT_SWAP_DURATION: u64 = 8_640_000;
 
+/// Maximum number of breakpoints in a stepwise or piecewise-linear schedule
+pub const MAX_RATE_BREAKPOINTS: usize = 100;
+
+/// Default and maximum number of points returned by the RateSchedule query
+pub const DEFAULT_RATE_SAMPLES: u32 = 50;
+pub const MAX_RATE_SAMPLES: u32 = 500;
+
 /// Minimum swap amount: 1 USTC = 1,000,000 uusd
 pub const MIN_SWAP_AMOUNT: u128 = 1_000_000;
 
@@ -114,6 +201,16 @@ pub const DEFAULT_LEADERBOARD_LIMIT: u32 = 10;
 /// Maximum limit for leaderboard pagination
 pub const MAX_LEADERBOARD_LIMIT: u32 = 50;
 
+/// Default number of codes processed per referral backfill call
+pub const DEFAULT_BACKFILL_LIMIT: u32 = 50;
+/// Maximum number of codes processed per referral backfill call
+pub const MAX_BACKFILL_LIMIT: u32 = 200;
+
+/// Default limit for swap receipt pagination
+pub const DEFAULT_RECEIPT_LIMIT: u32 = 10;
+/// Maximum limit for swap receipt pagination
+pub const MAX_RECEIPT_LIMIT: u32 = 50;
+
 /// Primary config storage
 pub const CONFIG: Item<Config> = Item::new("config");
 
@@ -126,6 +223,25 @@ pub const STATS: Item<Stats> = Item::new("stats");
 /// Per-referral-code statistics, keyed by normalized (lowercase) code
 pub const REFERRAL_CODE_STATS: Map<&str, ReferralCodeStats> = Map::new("referral_code_stats");
 
+/// Ranking of every code that has earned rewards, in leaderboard order
+/// Key: (u128::MAX - total_rewards_earned, normalized code), so ascending
+/// iteration runs from highest rewards down, with ties ordered by code
+/// Updated in O(1) per swap; the top-50 linked list below stays authoritative
+/// for the order of the codes it holds
+pub const REFERRAL_RANKING: Map<(u128, &str), ()> = Map::new("referral_ranking");
+
+/// Number of ranked codes per rewards prefix, a radix-16 trie over the
+/// significant hex digits of total_rewards_earned
+/// Key: (digit count, depth, first `depth` digits); depth 0 counts every code
+/// with that many digits and depth == digit count counts codes tied at a value
+/// Counting the codes above any rewards takes at most 33 reads and 32 short
+/// ranges, so ranks stay exact without walking REFERRAL_RANKING
+pub const REFERRAL_REWARD_COUNTS: Map<(u8, u8, u128), u32> = Map::new("referral_reward_counts");
+
+/// Last code processed by the referral backfill, present only while codes
+/// used before the upgrade remain to be processed
+pub const REFERRAL_BACKFILL_CURSOR: Item<String> = Item::new("referral_backfill_cursor");
+
 /// Head of the sorted leaderboard linked list (code with highest rewards)
 /// None if no referral codes have been used yet
 pub const LEADERBOARD_HEAD: Item<Option<String>> = Item::new("leaderboard_head");
@@ -143,3 +259,20 @@ pub const LEADERBOARD_SIZE: Item<u32> = Item::new("leaderboard_size");
 /// Only contains the top 50 codes by total_rewards_earned
 pub const LEADERBOARD_LINKS: Map<&str, LeaderboardLink> = Map::new("leaderboard_links");
 
+/// Per-address swap totals, recorded from the upgrade that introduced them
+pub const USER_SWAP_STATS: Map<&Addr, UserSwapStats> = Map::new("user_swap_stats");
+
+/// ID assigned to the next swap receipt
+pub const NEXT_SWAP_ID: Item<u64> = Item::new("next_swap_id");
+
+/// Swap receipts keyed by ID. IDs are assigned in block order, so ID order is
+/// also time order
+pub const SWAP_RECEIPTS: Map<u64, SwapReceipt> = Map::new("swap_receipts");
+
+/// Receipt index by user
+/// Key: (user, swap_id)
+pub const USER_SWAP_RECEIPTS: Map<(&Addr, u64), ()> = Map::new("user_swap_receipts");
+
+/// Receipt index by time, used to find the first receipt at or after a timestamp
+/// Key: (timestamp seconds, swap_id)
+pub const SWAP_RECEIPTS_BY_TIME: Map<(u64, u64), ()> = Map::new("swap_receipts_by_time");
@@ -0,0 +1,1431 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Env,
+    MessageInfo, Order, Response, StdError, StdResult, Uint128, Uint256, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{
+    AprResponse, ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PairCw20HookMsg,
+    PendingGovernanceResponse, QueryMsg, ReceiveMsg, RollingPoolConfigResponse,
+    RollingPoolExecuteMsg, RollingPoolPendingResponse, RollingPoolQueryMsg,
+    RollingPoolStateResponse, StakerResponse, StakersResponse, StateResponse,
+};
+use crate::state::{
+    Config, PendingCompound, PendingGovernance, StakerInfo, StakingState, UnbondingEntry, CONFIG,
+    CONTRACT_NAME, CONTRACT_VERSION, DEFAULT_QUERY_LIMIT, GOVERNANCE_TIMELOCK, MAX_QUERY_LIMIT,
+    MAX_UNBONDING_ENTRIES, MAX_UNBONDING_PERIOD, PENDING_COMPOUND, PENDING_GOVERNANCE,
+    ROLLING_POOL_PERIOD, SECONDS_PER_YEAR, STAKERS, STAKING_STATE, UNBONDING,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    validate_unbonding_period(msg.unbonding_period)?;
+
+    let config = Config {
+        governance: deps.api.addr_validate(&msg.governance)?,
+        ustr_token: deps.api.addr_validate(&msg.ustr_token)?,
+        ust1_token: deps.api.addr_validate(&msg.ust1_token)?,
+        rolling_pool: msg
+            .rolling_pool
+            .map(|a| deps.api.addr_validate(&a))
+            .transpose()?,
+        compound_pair: msg
+            .compound_pair
+            .map(|a| deps.api.addr_validate(&a))
+            .transpose()?,
+        unbonding_period: msg.unbonding_period,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    STAKING_STATE.save(
+        deps.storage,
+        &StakingState {
+            total_staked: Uint128::zero(),
+            total_unbonding: Uint128::zero(),
+            reward_per_token: Decimal256::zero(),
+            undistributed: Uint128::zero(),
+            prefunded: Uint128::zero(),
+            total_distributed: Uint128::zero(),
+            total_claimed: Uint128::zero(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("governance", config.governance)
+        .add_attribute("ustr_token", config.ustr_token)
+        .add_attribute("ust1_token", config.ust1_token)
+        .add_attribute("unbonding_period", config.unbonding_period.to_string()))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(cw20_msg) => execute_receive(deps, env, info, cw20_msg),
+        ExecuteMsg::Unbond { amount } => execute_unbond(deps, env, info, amount),
+        ExecuteMsg::WithdrawUnbonded {} => execute_withdraw_unbonded(deps, env, info),
+        ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::Compound {
+            belief_price,
+            max_spread,
+        } => execute_compound(deps, env, info, belief_price, max_spread),
+        ExecuteMsg::CompoundCallback {} => execute_compound_callback(deps, env, info),
+        ExecuteMsg::UpdateConfig {
+            rolling_pool,
+            compound_pair,
+            unbonding_period,
+        } => execute_update_config(
+            deps,
+            env,
+            info,
+            rolling_pool,
+            compound_pair,
+            unbonding_period,
+        ),
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => {
+            execute_propose_governance_transfer(deps, env, info, new_governance)
+        }
+        ExecuteMsg::AcceptGovernanceTransfer {} => {
+            execute_accept_governance_transfer(deps, env, info)
+        }
+        ExecuteMsg::CancelGovernanceTransfer {} => execute_cancel_governance_transfer(deps, info),
+    }
+}
+
+fn execute_receive(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if cw20_msg.amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+
+    match from_json(&cw20_msg.msg)? {
+        ReceiveMsg::Stake {} => {
+            if info.sender != config.ustr_token {
+                return Err(ContractError::UnauthorizedToken);
+            }
+            let staker = deps.api.addr_validate(&cw20_msg.sender)?;
+            let drip_msg = sync_stored_pool(deps.branch(), &env, &config)?;
+            let staked = stake(deps, &staker, cw20_msg.amount)?;
+            Ok(Response::new()
+                .add_messages(drip_msg)
+                .add_attribute("action", "stake")
+                .add_attribute("staker", staker)
+                .add_attribute("amount", cw20_msg.amount)
+                .add_attribute("staked", staked))
+        }
+        ReceiveMsg::DistributeRewards {} => {
+            if info.sender != config.ust1_token {
+                return Err(ContractError::UnauthorizedToken);
+            }
+            let mut state = STAKING_STATE.load(deps.storage)?;
+            // Drips already credited by a sync only settle the prefunded amount
+            let covered = cw20_msg.amount.min(state.prefunded);
+            state.prefunded -= covered;
+            distribute(&mut state, cw20_msg.amount - covered)?;
+            STAKING_STATE.save(deps.storage, &state)?;
+            Ok(Response::new()
+                .add_attribute("action", "distribute_rewards")
+                .add_attribute("amount", cw20_msg.amount)
+                .add_attribute("prefunded", covered)
+                .add_attribute("reward_per_token", state.reward_per_token.to_string()))
+        }
+    }
+}
+
+fn execute_unbond(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let mut staker = STAKERS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+
+    if amount > staker.staked {
+        return Err(ContractError::InsufficientStake {
+            requested: amount,
+            staked: staker.staked,
+        });
+    }
+
+    let mut entries = UNBONDING
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if entries.len() >= MAX_UNBONDING_ENTRIES {
+        return Err(ContractError::TooManyUnbondingEntries {
+            max: MAX_UNBONDING_ENTRIES,
+        });
+    }
+    let release_at = env.block.time.plus_seconds(config.unbonding_period);
+    entries.push(UnbondingEntry { amount, release_at });
+    UNBONDING.save(deps.storage, &info.sender, &entries)?;
+
+    let drip_msg = sync_pool(deps.as_ref(), &env, &config, &mut state)?;
+    update_rewards(&state, &mut staker)?;
+    staker.staked -= amount;
+    state.total_staked -= amount;
+    state.total_unbonding += amount;
+    STAKERS.save(deps.storage, &info.sender, &staker)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_attribute("action", "unbond")
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("release_at", release_at.to_string()))
+}
+
+fn execute_withdraw_unbonded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let entries = UNBONDING
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let (released, pending): (Vec<_>, Vec<_>) = entries
+        .into_iter()
+        .partition(|e| e.release_at <= env.block.time);
+
+    let amount: Uint128 = released.iter().map(|e| e.amount).sum();
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw);
+    }
+    if pending.is_empty() {
+        UNBONDING.remove(deps.storage, &info.sender);
+    } else {
+        UNBONDING.save(deps.storage, &info.sender, &pending)?;
+    }
+
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    state.total_unbonding -= amount;
+    STAKING_STATE.save(deps.storage, &state)?;
+
+    let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ustr_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(transfer_msg)
+        .add_attribute("action", "withdraw_unbonded")
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_claim(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let drip_msg = sync_stored_pool(deps.branch(), &env, &config)?;
+    let amount = take_rewards(deps, &info.sender)?;
+
+    // The drip runs first, so prefunded rewards are in the contract before the transfer
+    let transfer_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_message(transfer_msg)
+        .add_attribute("action", "claim")
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount))
+}
+
+/// Sends the caller's rewards to the pair and dispatches the callback that
+/// stakes whatever USTR the swap returned
+fn execute_compound(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    belief_price: Option<Decimal>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let pair = config
+        .compound_pair
+        .clone()
+        .ok_or(ContractError::NoCompoundPair)?;
+    let drip_msg = sync_stored_pool(deps.branch(), &env, &config)?;
+    let amount = take_rewards(deps.branch(), &info.sender)?;
+
+    let ustr_balance_before = ustr_balance(deps.as_ref(), &env, &config)?;
+    PENDING_COMPOUND.save(
+        deps.storage,
+        &PendingCompound {
+            staker: info.sender.clone(),
+            ustr_balance_before,
+        },
+    )?;
+
+    let swap_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.ust1_token.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Send {
+            contract: pair.to_string(),
+            amount,
+            msg: to_json_binary(&PairCw20HookMsg::Swap {
+                belief_price,
+                max_spread,
+                to: None,
+            })?,
+        })?,
+        funds: vec![],
+    });
+    let callback_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::CompoundCallback {})?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_message(swap_msg)
+        .add_message(callback_msg)
+        .add_attribute("action", "compound")
+        .add_attribute("staker", info.sender)
+        .add_attribute("ust1_amount", amount))
+}
+
+fn execute_compound_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let pending = PENDING_COMPOUND.load(deps.storage)?;
+    PENDING_COMPOUND.remove(deps.storage);
+
+    let received =
+        ustr_balance(deps.as_ref(), &env, &config)?.saturating_sub(pending.ustr_balance_before);
+    if received.is_zero() {
+        return Err(ContractError::ZeroAmount);
+    }
+    let staked = stake(deps, &pending.staker, received)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "compound_callback")
+        .add_attribute("staker", pending.staker)
+        .add_attribute("ustr_amount", received)
+        .add_attribute("staked", staked))
+}
+
+fn execute_update_config(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    rolling_pool: Option<String>,
+    compound_pair: Option<String>,
+    unbonding_period: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Settle the old pool before switching
+    let drip_msg = sync_stored_pool(deps.branch(), &env, &config)?;
+
+    if let Some(rolling_pool) = rolling_pool {
+        config.rolling_pool = Some(deps.api.addr_validate(&rolling_pool)?);
+    }
+    if let Some(compound_pair) = compound_pair {
+        config.compound_pair = Some(deps.api.addr_validate(&compound_pair)?);
+    }
+    // Existing unbonding entries keep their release time
+    if let Some(unbonding_period) = unbonding_period {
+        validate_unbonding_period(unbonding_period)?;
+        config.unbonding_period = unbonding_period;
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_messages(drip_msg)
+        .add_attribute("action", "update_config")
+        .add_attribute("unbonding_period", config.unbonding_period.to_string()))
+}
+
+fn execute_propose_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_governance: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let new_address = deps.api.addr_validate(&new_governance)?;
+    let pending = PendingGovernance {
+        new_address: new_address.clone(),
+        execute_after: env.block.time.plus_seconds(GOVERNANCE_TIMELOCK),
+    };
+    PENDING_GOVERNANCE.save(deps.storage, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_governance_transfer")
+        .add_attribute("new_governance", new_address)
+        .add_attribute("execute_after", pending.execute_after.to_string()))
+}
+
+fn execute_accept_governance_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending = PENDING_GOVERNANCE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingGovernance)?;
+
+    if info.sender != pending.new_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if env.block.time < pending.execute_after {
+        let remaining = pending.execute_after.seconds() - env.block.time.seconds();
+        return Err(ContractError::TimelockNotExpired {
+            remaining_seconds: remaining,
+        });
+    }
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_governance = config.governance.clone();
+    config.governance = pending.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_governance_transfer")
+        .add_attribute("old_governance", old_governance)
+        .add_attribute("new_governance", config.governance))
+}
+
+fn execute_cancel_governance_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.governance {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if PENDING_GOVERNANCE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingGovernance);
+    }
+    PENDING_GOVERNANCE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_governance_transfer"))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::State {} => to_json_binary(&query_state(deps)?),
+        QueryMsg::Staker { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&query_staker(deps, &env, address)?)
+        }
+        QueryMsg::Stakers { start_after, limit } => {
+            to_json_binary(&query_stakers(deps, env, start_after, limit)?)
+        }
+        QueryMsg::Apr { ustr_price } => to_json_binary(&query_apr(deps, ustr_price)?),
+        QueryMsg::PendingGovernance {} => to_json_binary(&query_pending_governance(deps)?),
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        governance: config.governance,
+        ustr_token: config.ustr_token,
+        ust1_token: config.ust1_token,
+        rolling_pool: config.rolling_pool,
+        compound_pair: config.compound_pair,
+        unbonding_period: config.unbonding_period,
+    })
+}
+
+fn query_state(deps: Deps) -> StdResult<StateResponse> {
+    let state = STAKING_STATE.load(deps.storage)?;
+    Ok(StateResponse {
+        total_staked: state.total_staked,
+        total_unbonding: state.total_unbonding,
+        reward_per_token: state.reward_per_token,
+        undistributed: state.undistributed,
+        prefunded: state.prefunded,
+        total_distributed: state.total_distributed,
+        total_claimed: state.total_claimed,
+    })
+}
+
+fn query_staker(deps: Deps, env: &Env, address: Addr) -> StdResult<StakerResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    sync_pool(deps, env, &config, &mut state)?;
+
+    let mut staker = STAKERS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    update_rewards(&state, &mut staker)?;
+    let unbonding = UNBONDING
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    Ok(staker_response(env, address, staker, unbonding))
+}
+
+fn query_stakers(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<StakersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+
+    let addresses = STAKERS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let stakers = addresses
+        .into_iter()
+        .map(|address| query_staker(deps, &env, address))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(StakersResponse { stakers })
+}
+
+fn query_apr(deps: Deps, ustr_price: Option<Decimal>) -> StdResult<AprResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let state = STAKING_STATE.load(deps.storage)?;
+
+    let (rate_per_second, annual_rewards) = match config.rolling_pool {
+        Some(pool) => {
+            let res: RollingPoolStateResponse = deps
+                .querier
+                .query_wasm_smart(pool, &RollingPoolQueryMsg::PoolState {})?;
+            // Derived from the balance rather than the rounded per-second rate
+            let annual = res
+                .streaming_balance
+                .multiply_ratio(SECONDS_PER_YEAR, ROLLING_POOL_PERIOD);
+            (res.rate_per_second, annual)
+        }
+        None => (Decimal256::zero(), Uint128::zero()),
+    };
+
+    let staked_value = state
+        .total_staked
+        .mul_floor(ustr_price.unwrap_or_else(Decimal::one));
+    let apr = if staked_value.is_zero() {
+        None
+    } else {
+        Some(Decimal::from_ratio(annual_rewards, staked_value))
+    };
+
+    Ok(AprResponse {
+        rate_per_second,
+        annual_rewards,
+        total_staked: state.total_staked,
+        apr,
+    })
+}
+
+fn query_pending_governance(deps: Deps) -> StdResult<PendingGovernanceResponse> {
+    let pending = PENDING_GOVERNANCE.may_load(deps.storage)?;
+    Ok(PendingGovernanceResponse {
+        new_governance: pending.as_ref().map(|p| p.new_address.clone()),
+        execute_after: pending.as_ref().map(|p| p.execute_after),
+    })
+}
+
+// ============ HELPERS ============
+
+/// Credits what the rolling pool has accrued up to now and returns the drip
+/// that delivers it. Pools not dripping to this contract with the
+/// `DistributeRewards` hook are skipped, since their drips would never
+/// settle the prefunded amount.
+fn sync_pool(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    state: &mut StakingState,
+) -> StdResult<Option<CosmosMsg>> {
+    let Some(pool) = &config.rolling_pool else {
+        return Ok(None);
+    };
+
+    let pool_config: RollingPoolConfigResponse = deps
+        .querier
+        .query_wasm_smart(pool, &RollingPoolQueryMsg::Config {})?;
+    let hook = to_json_binary(&ReceiveMsg::DistributeRewards {})?;
+    if pool_config.beneficiary.as_ref() != Some(&env.contract.address)
+        || pool_config.hook_msg != Some(hook)
+    {
+        return Ok(None);
+    }
+
+    let pending: RollingPoolPendingResponse = deps.querier.query_wasm_smart(
+        pool,
+        &RollingPoolQueryMsg::PendingDistribution {
+            start: None,
+            end: env.block.time.seconds(),
+        },
+    )?;
+    let amount = pending.accrued_before_start;
+    if amount.is_zero() {
+        return Ok(None);
+    }
+
+    distribute(state, amount)?;
+    state.prefunded = state.prefunded.checked_add(amount)?;
+
+    Ok(Some(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: pool.to_string(),
+        msg: to_json_binary(&RollingPoolExecuteMsg::Drip {})?,
+        funds: vec![],
+    })))
+}
+
+/// `sync_pool` on the stored state, for actions whose helpers load it themselves
+fn sync_stored_pool(deps: DepsMut, env: &Env, config: &Config) -> StdResult<Option<CosmosMsg>> {
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let drip_msg = sync_pool(deps.as_ref(), env, config, &mut state)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+    Ok(drip_msg)
+}
+
+/// Adds `amount` to a staker's stake, releasing rewards held while nothing
+/// was staked. Returns the staker's new stake.
+fn stake(deps: DepsMut, address: &Addr, amount: Uint128) -> StdResult<Uint128> {
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let mut staker = STAKERS.may_load(deps.storage, address)?.unwrap_or_default();
+
+    update_rewards(&state, &mut staker)?;
+    staker.staked = staker.staked.checked_add(amount)?;
+    state.total_staked = state.total_staked.checked_add(amount)?;
+    if !state.undistributed.is_zero() {
+        distribute(&mut state, Uint128::zero())?;
+    }
+
+    STAKERS.save(deps.storage, address, &staker)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+    Ok(staker.staked)
+}
+
+/// Credits `amount` (plus anything held back) to the reward index, or holds
+/// it back if nothing is staked
+fn distribute(state: &mut StakingState, amount: Uint128) -> StdResult<()> {
+    state.total_distributed = state.total_distributed.checked_add(amount)?;
+    let amount = state.undistributed.checked_add(amount)?;
+    if state.total_staked.is_zero() {
+        state.undistributed = amount;
+        return Ok(());
+    }
+    state.reward_per_token += Decimal256::from_ratio(amount, state.total_staked);
+    state.undistributed = Uint128::zero();
+    Ok(())
+}
+
+/// Moves rewards earned since the staker's last update into `pending_rewards`
+fn update_rewards(state: &StakingState, staker: &mut StakerInfo) -> StdResult<()> {
+    let earned =
+        Uint256::from(staker.staked).mul_floor(state.reward_per_token - staker.reward_index);
+    let earned =
+        Uint128::try_from(earned).map_err(|e| StdError::ConversionOverflow { source: e })?;
+    staker.pending_rewards = staker.pending_rewards.checked_add(earned)?;
+    staker.reward_index = state.reward_per_token;
+    Ok(())
+}
+
+/// Zeroes a staker's rewards and returns the amount, for claim and compound
+fn take_rewards(deps: DepsMut, address: &Addr) -> Result<Uint128, ContractError> {
+    let mut state = STAKING_STATE.load(deps.storage)?;
+    let mut staker = STAKERS.may_load(deps.storage, address)?.unwrap_or_default();
+    update_rewards(&state, &mut staker)?;
+
+    let amount = staker.pending_rewards;
+    if amount.is_zero() {
+        return Err(ContractError::NoRewards);
+    }
+    staker.pending_rewards = Uint128::zero();
+    state.total_claimed = state
+        .total_claimed
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+
+    STAKERS.save(deps.storage, address, &staker)?;
+    STAKING_STATE.save(deps.storage, &state)?;
+    Ok(amount)
+}
+
+fn staker_response(
+    env: &Env,
+    address: Addr,
+    staker: StakerInfo,
+    unbonding: Vec<UnbondingEntry>,
+) -> StakerResponse {
+    let withdrawable = unbonding
+        .iter()
+        .filter(|e| e.release_at <= env.block.time)
+        .map(|e| e.amount)
+        .sum();
+    StakerResponse {
+        address,
+        staked: staker.staked,
+        pending_rewards: staker.pending_rewards,
+        unbonding,
+        withdrawable,
+    }
+}
+
+fn ustr_balance(deps: Deps, env: &Env, config: &Config) -> StdResult<Uint128> {
+    let res: BalanceResponse = deps.querier.query_wasm_smart(
+        &config.ustr_token,
+        &Cw20QueryMsg::Balance {
+            address: env.contract.address.to_string(),
+        },
+    )?;
+    Ok(res.balance)
+}
+
+fn validate_unbonding_period(unbonding_period: u64) -> Result<(), ContractError> {
+    if unbonding_period > MAX_UNBONDING_PERIOD {
+        return Err(ContractError::InvalidUnbondingPeriod {
+            max: MAX_UNBONDING_PERIOD,
+        });
+    }
+    Ok(())
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+        MOCK_CONTRACT_ADDR,
+    };
+    use cosmwasm_std::{ContractResult, OwnedDeps, SystemResult, Timestamp, WasmQuery};
+
+    const GOVERNANCE: &str = "governance";
+    const USTR: &str = "ustr_token";
+    const UST1: &str = "ust1_token";
+    const POOL: &str = "rolling_pool";
+    const PAIR: &str = "ust1_ustr_pair";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+
+    /// 7 days
+    const UNBONDING_PERIOD: u64 = 604_800;
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn setup_contract(deps: &mut MockDeps) {
+        let msg = InstantiateMsg {
+            governance: GOVERNANCE.to_string(),
+            ustr_token: USTR.to_string(),
+            ust1_token: UST1.to_string(),
+            rolling_pool: Some(POOL.to_string()),
+            compound_pair: Some(PAIR.to_string()),
+            unbonding_period: UNBONDING_PERIOD,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).unwrap();
+        mock_queries(deps, 0, 0);
+    }
+
+    /// Mocks the contract's USTR balance and the rolling pool's rate. The pool
+    /// has no beneficiary, so syncs skip it and rewards arrive via
+    /// `distribute`.
+    fn mock_queries(deps: &mut MockDeps, ustr_balance: u128, pool_balance: u128) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                let bin = match contract_addr.as_str() {
+                    USTR => to_json_binary(&BalanceResponse {
+                        balance: Uint128::new(ustr_balance),
+                    }),
+                    POOL => match from_json(msg).unwrap() {
+                        RollingPoolQueryMsg::Config {} => {
+                            to_json_binary(&RollingPoolConfigResponse {
+                                governance: Addr::unchecked(GOVERNANCE),
+                                ust1_token: Addr::unchecked(UST1),
+                                beneficiary: None,
+                                hook_msg: None,
+                            })
+                        }
+                        RollingPoolQueryMsg::PoolState {} => {
+                            to_json_binary(&RollingPoolStateResponse {
+                                streaming_balance: Uint128::new(pool_balance),
+                                accrued: Uint128::zero(),
+                                rate_per_second: Decimal256::from_ratio(
+                                    pool_balance,
+                                    157_680_000u64,
+                                ),
+                                last_update: Timestamp::from_seconds(0),
+                                total_distributed: Uint128::zero(),
+                            })
+                        }
+                        _ => panic!("unexpected pool query"),
+                    },
+                    _ => panic!("unexpected query"),
+                };
+                SystemResult::Ok(ContractResult::Ok(bin.unwrap()))
+            }
+            _ => panic!("unexpected query"),
+        });
+    }
+
+    fn receive(
+        deps: &mut MockDeps,
+        token: &str,
+        sender: &str,
+        amount: u128,
+        msg: ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_json_binary(&msg).unwrap(),
+        });
+        execute(deps.as_mut(), mock_env(), mock_info(token, &[]), msg)
+    }
+
+    fn stake(deps: &mut MockDeps, staker: &str, amount: u128) {
+        receive(deps, USTR, staker, amount, ReceiveMsg::Stake {}).unwrap();
+    }
+
+    fn distribute(deps: &mut MockDeps, amount: u128) {
+        receive(deps, UST1, POOL, amount, ReceiveMsg::DistributeRewards {}).unwrap();
+    }
+
+    fn staker(deps: &MockDeps, address: &str) -> StakerResponse {
+        query_staker(deps.as_ref(), &mock_env(), Addr::unchecked(address)).unwrap()
+    }
+
+    fn env_at(seconds_after_start: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds_after_start);
+        env
+    }
+
+    #[test]
+    fn test_rewards_split_by_stake() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        stake(&mut deps, ALICE, 100);
+        stake(&mut deps, BOB, 300);
+        distribute(&mut deps, 400);
+        assert_eq!(staker(&deps, ALICE).pending_rewards, Uint128::new(100));
+        assert_eq!(staker(&deps, BOB).pending_rewards, Uint128::new(300));
+
+        // Later stakers only earn from later distributions
+        stake(&mut deps, ALICE, 200);
+        distribute(&mut deps, 600);
+        assert_eq!(staker(&deps, ALICE).pending_rewards, Uint128::new(400));
+        assert_eq!(staker(&deps, BOB).pending_rewards, Uint128::new(600));
+
+        // Tokens must match the message
+        let err = receive(&mut deps, UST1, ALICE, 10, ReceiveMsg::Stake {}).unwrap_err();
+        assert_eq!(err, ContractError::UnauthorizedToken);
+        let err = receive(&mut deps, USTR, POOL, 10, ReceiveMsg::DistributeRewards {}).unwrap_err();
+        assert_eq!(err, ContractError::UnauthorizedToken);
+    }
+
+    #[test]
+    fn test_rewards_held_until_first_stake() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        distribute(&mut deps, 50);
+        let state = query_state(deps.as_ref()).unwrap();
+        assert_eq!(state.undistributed, Uint128::new(50));
+        assert_eq!(state.reward_per_token, Decimal256::zero());
+
+        stake(&mut deps, ALICE, 100);
+        assert_eq!(staker(&deps, ALICE).pending_rewards, Uint128::new(50));
+        assert_eq!(
+            query_state(deps.as_ref()).unwrap().undistributed,
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn test_unbonding_stops_rewards_and_releases_after_period() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        stake(&mut deps, ALICE, 100);
+        stake(&mut deps, BOB, 100);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Unbond {
+                amount: Uint128::new(101),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientStake {
+                requested: Uint128::new(101),
+                staked: Uint128::new(100),
+            }
+        );
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Unbond {
+                amount: Uint128::new(100),
+            },
+        )
+        .unwrap();
+        distribute(&mut deps, 300);
+        assert_eq!(staker(&deps, ALICE).pending_rewards, Uint128::zero());
+        assert_eq!(staker(&deps, BOB).pending_rewards, Uint128::new(300));
+
+        let err = execute(
+            deps.as_mut(),
+            env_at(UNBONDING_PERIOD - 1),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::WithdrawUnbonded {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NothingToWithdraw);
+
+        let res = execute(
+            deps.as_mut(),
+            env_at(UNBONDING_PERIOD),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::WithdrawUnbonded {},
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, USTR);
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: ALICE.to_string(),
+                        amount: Uint128::new(100),
+                    }
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+        let state = query_state(deps.as_ref()).unwrap();
+        assert_eq!(state.total_staked, Uint128::new(100));
+        assert_eq!(state.total_unbonding, Uint128::zero());
+        assert!(staker(&deps, ALICE).unbonding.is_empty());
+    }
+
+    #[test]
+    fn test_claim_sends_rewards_once() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        stake(&mut deps, ALICE, 100);
+        distribute(&mut deps, 70);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Claim {},
+        )
+        .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, UST1);
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Transfer {
+                        recipient: ALICE.to_string(),
+                        amount: Uint128::new(70),
+                    }
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Claim {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoRewards);
+        assert_eq!(
+            query_state(deps.as_ref()).unwrap().total_claimed,
+            Uint128::new(70)
+        );
+    }
+
+    #[test]
+    fn test_compound_swaps_then_stakes_received_ustr() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        stake(&mut deps, ALICE, 100);
+        distribute(&mut deps, 50);
+        mock_queries(&mut deps, 100, 0);
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::Compound {
+                belief_price: None,
+                max_spread: Some(Decimal::percent(1)),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 2);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, UST1);
+                assert_eq!(
+                    from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                    Cw20ExecuteMsg::Send {
+                        contract: PAIR.to_string(),
+                        amount: Uint128::new(50),
+                        msg: to_json_binary(&PairCw20HookMsg::Swap {
+                            belief_price: None,
+                            max_spread: Some(Decimal::percent(1)),
+                            to: None,
+                        })
+                        .unwrap(),
+                    }
+                );
+            }
+            _ => panic!("Expected wasm execute"),
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ALICE, &[]),
+            ExecuteMsg::CompoundCallback {},
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        // The swap returned 25 USTR
+        mock_queries(&mut deps, 125, 0);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::CompoundCallback {},
+        )
+        .unwrap();
+        let alice = staker(&deps, ALICE);
+        assert_eq!(alice.staked, Uint128::new(125));
+        assert_eq!(alice.pending_rewards, Uint128::zero());
+        assert!(PENDING_COMPOUND.may_load(&deps.storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_apr_from_rolling_pool_rate() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        // 5,000,000 UST1 in the pool streams 1,000,000 a year
+        mock_queries(&mut deps, 0, 5_000_000);
+        let res = query_apr(deps.as_ref(), None).unwrap();
+        assert_eq!(res.annual_rewards, Uint128::new(1_000_000));
+        assert_eq!(res.apr, None);
+
+        stake(&mut deps, ALICE, 4_000_000);
+        let res = query_apr(deps.as_ref(), None).unwrap();
+        assert_eq!(res.apr, Some(Decimal::percent(25)));
+
+        // USTR worth 0.5 UST1 doubles the APR
+        let res = query_apr(deps.as_ref(), Some(Decimal::percent(50))).unwrap();
+        assert_eq!(res.apr, Some(Decimal::percent(50)));
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::{balance, cw20_contract, instantiate_cw20};
+    use cosmwasm_std::{to_json_binary, Addr, Uint128};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{
+        ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg, StakerResponse, StateResponse,
+    };
+
+    const GOVERNANCE: &str = "governance";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+
+    /// 157,680,000 seconds (5 years) / 10
+    const HALF_YEAR: u64 = 15_768_000;
+
+    #[test]
+    fn test_rolling_pool_drips_into_stakers() {
+        let mut app = App::default();
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let cw20_code = app.store_code(cw20_contract());
+        let pool_code = app.store_code(contract_wrapper!(rolling_pool));
+        let staking_code = app.store_code(contract_wrapper!(crate));
+
+        let ustr = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTR",
+            vec![
+                Cw20Coin {
+                    address: ALICE.to_string(),
+                    amount: Uint128::new(1_000),
+                },
+                Cw20Coin {
+                    address: BOB.to_string(),
+                    amount: Uint128::new(3_000),
+                },
+            ],
+        );
+        let ust1 = instantiate_cw20(&mut app, cw20_code, GOVERNANCE, "USTONE", vec![]);
+
+        let pool = app
+            .instantiate_contract(
+                pool_code,
+                gov.clone(),
+                &rolling_pool::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    beneficiary: None,
+                },
+                &[],
+                "rolling-pool",
+                None,
+            )
+            .unwrap();
+        let staking = app
+            .instantiate_contract(
+                staking_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ustr_token: ustr.to_string(),
+                    ust1_token: ust1.to_string(),
+                    rolling_pool: Some(pool.to_string()),
+                    compound_pair: None,
+                    unbonding_period: 604_800,
+                },
+                &[],
+                "ustr-staking",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::SetBeneficiary {
+                beneficiary: Some(rolling_pool::msg::BeneficiaryMsg {
+                    address: staking.to_string(),
+                    hook_msg: Some(to_json_binary(&ReceiveMsg::DistributeRewards {}).unwrap()),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+        for (staker, amount) in [(ALICE, 1_000u128), (BOB, 3_000)] {
+            app.execute_contract(
+                Addr::unchecked(staker),
+                ustr.clone(),
+                &Cw20ExecuteMsg::Send {
+                    contract: staking.to_string(),
+                    amount: Uint128::new(amount),
+                    msg: to_json_binary(&ReceiveMsg::Stake {}).unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        app.execute_contract(
+            gov.clone(),
+            ust1.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: pool.to_string(),
+                amount: Uint128::new(40_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::Accrue {},
+            &[],
+        )
+        .unwrap();
+
+        // A tenth of the period drips 4,000 UST1, split 1:3
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(HALF_YEAR);
+        });
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::Drip {},
+            &[],
+        )
+        .unwrap();
+
+        let bob: StakerResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staking,
+                &QueryMsg::Staker {
+                    address: BOB.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(bob.pending_rewards, Uint128::new(3_000));
+
+        app.execute_contract(
+            Addr::unchecked(ALICE),
+            staking.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &ust1, ALICE), Uint128::new(1_000));
+        assert_eq!(balance(&app, &ust1, staking.as_str()), Uint128::new(3_000));
+    }
+
+    #[test]
+    fn test_late_staker_earns_nothing_from_earlier_accrual() {
+        let mut app = App::default();
+        let gov = Addr::unchecked(GOVERNANCE);
+
+        let cw20_code = app.store_code(cw20_contract());
+        let pool_code = app.store_code(contract_wrapper!(rolling_pool));
+        let staking_code = app.store_code(contract_wrapper!(crate));
+
+        let ustr = instantiate_cw20(
+            &mut app,
+            cw20_code,
+            GOVERNANCE,
+            "USTR",
+            vec![
+                Cw20Coin {
+                    address: ALICE.to_string(),
+                    amount: Uint128::new(1_000),
+                },
+                Cw20Coin {
+                    address: BOB.to_string(),
+                    amount: Uint128::new(3_000),
+                },
+            ],
+        );
+        let ust1 = instantiate_cw20(&mut app, cw20_code, GOVERNANCE, "USTONE", vec![]);
+
+        let pool = app
+            .instantiate_contract(
+                pool_code,
+                gov.clone(),
+                &rolling_pool::msg::InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ust1_token: ust1.to_string(),
+                    beneficiary: None,
+                },
+                &[],
+                "rolling-pool",
+                None,
+            )
+            .unwrap();
+        let staking = app
+            .instantiate_contract(
+                staking_code,
+                gov.clone(),
+                &InstantiateMsg {
+                    governance: GOVERNANCE.to_string(),
+                    ustr_token: ustr.to_string(),
+                    ust1_token: ust1.to_string(),
+                    rolling_pool: Some(pool.to_string()),
+                    compound_pair: None,
+                    unbonding_period: 604_800,
+                },
+                &[],
+                "ustr-staking",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::SetBeneficiary {
+                beneficiary: Some(rolling_pool::msg::BeneficiaryMsg {
+                    address: staking.to_string(),
+                    hook_msg: Some(to_json_binary(&ReceiveMsg::DistributeRewards {}).unwrap()),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let stake = |app: &mut App, staker: &str, amount: u128| {
+            app.execute_contract(
+                Addr::unchecked(staker),
+                ustr.clone(),
+                &Cw20ExecuteMsg::Send {
+                    contract: staking.to_string(),
+                    amount: Uint128::new(amount),
+                    msg: to_json_binary(&ReceiveMsg::Stake {}).unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+        };
+        let pending_rewards = |app: &App, staker: &str| {
+            let res: StakerResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &staking,
+                    &QueryMsg::Staker {
+                        address: staker.to_string(),
+                    },
+                )
+                .unwrap();
+            res.pending_rewards
+        };
+
+        stake(&mut app, ALICE, 1_000);
+        app.execute_contract(
+            gov.clone(),
+            ust1.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: pool.to_string(),
+                amount: Uint128::new(40_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            gov.clone(),
+            pool.clone(),
+            &rolling_pool::msg::ExecuteMsg::Accrue {},
+            &[],
+        )
+        .unwrap();
+
+        // 4,000 accrues while only Alice is staked; Bob's stake drips it to
+        // her before his stake counts
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(HALF_YEAR);
+        });
+        stake(&mut app, BOB, 3_000);
+        assert_eq!(pending_rewards(&app, ALICE), Uint128::new(4_000));
+        assert_eq!(pending_rewards(&app, BOB), Uint128::zero());
+        assert_eq!(balance(&app, &ust1, staking.as_str()), Uint128::new(4_000));
+
+        // A tenth of the remaining 36,000 is split 1:3; the claim drips it first
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(HALF_YEAR);
+        });
+        app.execute_contract(
+            Addr::unchecked(BOB),
+            staking.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, &ust1, BOB), Uint128::new(2_700));
+        assert_eq!(pending_rewards(&app, ALICE), Uint128::new(4_900));
+
+        let state: StateResponse = app
+            .wrap()
+            .query_wasm_smart(&staking, &QueryMsg::State {})
+            .unwrap();
+        assert_eq!(state.prefunded, Uint128::zero());
+        assert_eq!(state.total_distributed, Uint128::new(7_600));
+        assert_eq!(balance(&app, &ust1, staking.as_str()), Uint128::new(4_900));
+    }
+}
@@ -0,0 +1,41 @@
+use cosmwasm_std::{StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Unauthorized token: only USTR can be staked and only UST1 distributed")]
+    UnauthorizedToken,
+
+    #[error("Zero amount not allowed")]
+    ZeroAmount,
+
+    #[error("Insufficient stake: requested {requested}, staked {staked}")]
+    InsufficientStake { requested: Uint128, staked: Uint128 },
+
+    #[error("Too many unbonding entries: maximum is {max}")]
+    TooManyUnbondingEntries { max: usize },
+
+    #[error("Unbonding period too long: maximum is {max} seconds")]
+    InvalidUnbondingPeriod { max: u64 },
+
+    #[error("No rewards to claim")]
+    NoRewards,
+
+    #[error("No unbonded USTR ready to withdraw")]
+    NothingToWithdraw,
+
+    #[error("No UST1/USTR pair configured for compounding")]
+    NoCompoundPair,
+
+    #[error("No pending governance transfer")]
+    NoPendingGovernance,
+
+    #[error("Timelock not expired: {remaining_seconds} seconds remaining")]
+    TimelockNotExpired { remaining_seconds: u64 },
+}
@@ -0,0 +1,34 @@
+//! # USTR Staking Contract
+//!
+//! Stakes USTR and distributes UST1 rewards streamed from a rolling pool.
+//!
+//! ## Rewards
+//!
+//! The rolling pool drips UST1 into this contract with a CW20 `Send`
+//! carrying `DistributeRewards`. Each distribution raises a global
+//! reward-per-token index by `amount / total_staked`; a staker's rewards are
+//! their stake times the index growth since they last acted. Rewards that
+//! arrive while nothing is staked are held and distributed once someone
+//! stakes.
+//!
+//! Before every stake, unbond, claim or compound the contract credits
+//! whatever the rolling pool has accrued so far and dispatches the pool's
+//! `Drip`, as UST1 staking does, so a new stake only earns from accrual after
+//! it. The credited amount is tracked as `prefunded` until the drip arrives.
+//!
+//! ## Unbonding
+//!
+//! Unbonded USTR stops earning immediately and can be withdrawn once the
+//! unbonding period has passed.
+//!
+//! ## Compounding
+//!
+//! `Compound` swaps a staker's UST1 rewards for USTR on the configured
+//! UST1/USTR pair and stakes the USTR received in the same transaction.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,207 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary, Decimal, Decimal256, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::state::UnbondingEntry;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub governance: String,
+    pub ustr_token: String,
+    pub ust1_token: String,
+    pub rolling_pool: Option<String>,
+    pub compound_pair: Option<String>,
+    /// Seconds between `Unbond` and withdrawal (max 90 days)
+    pub unbonding_period: u64,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// CW20 receive hook; the embedded message is `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+
+    /// Starts unbonding staked USTR; it stops earning immediately
+    Unbond { amount: Uint128 },
+
+    /// Withdraws all USTR whose unbonding period has passed
+    WithdrawUnbonded {},
+
+    /// Sends the caller's pending UST1 rewards
+    Claim {},
+
+    /// Swaps the caller's pending UST1 rewards for USTR on the compound pair
+    /// and stakes the result
+    Compound {
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+    },
+
+    /// Second half of `Compound`, dispatched after the swap (contract-only)
+    CompoundCallback {},
+
+    /// Updates the rolling pool, compound pair and unbonding period (governance-only)
+    UpdateConfig {
+        rolling_pool: Option<String>,
+        compound_pair: Option<String>,
+        unbonding_period: Option<u64>,
+    },
+
+    /// Proposes governance transfer with 7-day timelock (governance-only)
+    ProposeGovernanceTransfer { new_governance: String },
+
+    /// Accepts a pending governance transfer after timelock (new governance only)
+    AcceptGovernanceTransfer {},
+
+    /// Cancels a pending governance transfer (governance-only)
+    CancelGovernanceTransfer {},
+}
+
+/// Messages embedded in a CW20 `Send` to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Stakes the sent USTR for the sender (USTR only)
+    Stake {},
+
+    /// Distributes the sent UST1 to stakers (UST1 only; used as the rolling
+    /// pool's hook message)
+    DistributeRewards {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    #[returns(StateResponse)]
+    State {},
+
+    #[returns(StakerResponse)]
+    Staker { address: String },
+
+    /// Stakers ordered by address
+    #[returns(StakersResponse)]
+    Stakers {
+        start_after: Option<String>,
+        /// Maximum number of entries to return (default: 10, max: 50)
+        limit: Option<u32>,
+    },
+
+    /// Annualized rewards at the rolling pool's current rate.
+    /// `ustr_price` is the value of one USTR in UST1 (default 1).
+    #[returns(AprResponse)]
+    Apr { ustr_price: Option<Decimal> },
+
+    #[returns(PendingGovernanceResponse)]
+    PendingGovernance {},
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub governance: Addr,
+    pub ustr_token: Addr,
+    pub ust1_token: Addr,
+    pub rolling_pool: Option<Addr>,
+    pub compound_pair: Option<Addr>,
+    pub unbonding_period: u64,
+}
+
+#[cw_serde]
+pub struct StateResponse {
+    pub total_staked: Uint128,
+    pub total_unbonding: Uint128,
+    pub reward_per_token: Decimal256,
+    pub undistributed: Uint128,
+    pub prefunded: Uint128,
+    pub total_distributed: Uint128,
+    pub total_claimed: Uint128,
+}
+
+#[cw_serde]
+pub struct StakerResponse {
+    pub address: Addr,
+    pub staked: Uint128,
+    /// Unclaimed rewards including those since the staker's last action
+    pub pending_rewards: Uint128,
+    pub unbonding: Vec<UnbondingEntry>,
+    /// Unbonded USTR withdrawable now
+    pub withdrawable: Uint128,
+}
+
+#[cw_serde]
+pub struct StakersResponse {
+    pub stakers: Vec<StakerResponse>,
+}
+
+#[cw_serde]
+pub struct AprResponse {
+    /// Rolling pool emission in UST1 base units per second
+    pub rate_per_second: Decimal256,
+    /// `rate_per_second` over a year
+    pub annual_rewards: Uint128,
+    pub total_staked: Uint128,
+    /// `annual_rewards / (total_staked * ustr_price)`; None when nothing is staked
+    pub apr: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct PendingGovernanceResponse {
+    pub new_governance: Option<Addr>,
+    pub execute_after: Option<Timestamp>,
+}
+
+/// Queries sent to the rolling pool
+#[cw_serde]
+pub enum RollingPoolQueryMsg {
+    Config {},
+    PoolState {},
+    PendingDistribution { start: Option<u64>, end: u64 },
+}
+
+/// Mirrors the rolling pool's ConfigResponse
+#[cw_serde]
+pub struct RollingPoolConfigResponse {
+    pub governance: Addr,
+    pub ust1_token: Addr,
+    pub beneficiary: Option<Addr>,
+    pub hook_msg: Option<Binary>,
+}
+
+/// Mirrors the rolling pool's PoolStateResponse
+#[cw_serde]
+pub struct RollingPoolStateResponse {
+    pub streaming_balance: Uint128,
+    pub accrued: Uint128,
+    pub rate_per_second: Decimal256,
+    pub last_update: Timestamp,
+    pub total_distributed: Uint128,
+}
+
+/// Mirrors the rolling pool's PendingDistributionResponse
+#[cw_serde]
+pub struct RollingPoolPendingResponse {
+    pub start: u64,
+    pub end: u64,
+    pub amount: Uint128,
+    pub accrued_before_start: Uint128,
+    pub rate_per_second: Decimal256,
+}
+
+/// Drip message sent to the rolling pool
+#[cw_serde]
+pub enum RollingPoolExecuteMsg {
+    Drip {},
+}
+
+/// Swap hook sent with UST1 to the compound pair
+#[cw_serde]
+pub enum PairCw20HookMsg {
+    Swap {
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+        to: Option<String>,
+    },
+}
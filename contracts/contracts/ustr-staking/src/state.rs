@@ -0,0 +1,88 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal256, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:ustr-staking";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 7 days in seconds
+pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+/// 90 days in seconds
+pub const MAX_UNBONDING_PERIOD: u64 = 7_776_000;
+
+/// Maximum concurrent unbonding entries per staker
+pub const MAX_UNBONDING_ENTRIES: usize = 10;
+
+/// 365 days in seconds, used for APR
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Rolling pool distribution period (5 years), used for APR
+pub const ROLLING_POOL_PERIOD: u64 = 157_680_000;
+
+/// Default page size for list queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for list queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    pub governance: Addr,
+    pub ustr_token: Addr,
+    pub ust1_token: Addr,
+    /// Rolling pool synced before every stake change and reported by the APR query
+    pub rolling_pool: Option<Addr>,
+    /// Astroport/Terraswap-style pair used by `Compound`
+    pub compound_pair: Option<Addr>,
+    pub unbonding_period: u64,
+}
+
+#[cw_serde]
+pub struct StakingState {
+    pub total_staked: Uint128,
+    pub total_unbonding: Uint128,
+    /// Cumulative UST1 rewarded per staked USTR base unit
+    pub reward_per_token: Decimal256,
+    /// Rewards received while nothing was staked
+    pub undistributed: Uint128,
+    /// Rewards credited from the rolling pool whose drip has not arrived yet
+    pub prefunded: Uint128,
+    pub total_distributed: Uint128,
+    pub total_claimed: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct StakerInfo {
+    pub staked: Uint128,
+    /// `reward_per_token` at the staker's last update
+    pub reward_index: Decimal256,
+    /// Rewards earned up to the last update and not yet claimed
+    pub pending_rewards: Uint128,
+}
+
+#[cw_serde]
+pub struct UnbondingEntry {
+    pub amount: Uint128,
+    pub release_at: Timestamp,
+}
+
+/// Staker and pre-swap USTR balance, held between `Compound` and its callback
+#[cw_serde]
+pub struct PendingCompound {
+    pub staker: Addr,
+    pub ustr_balance_before: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingGovernance {
+    pub new_address: Addr,
+    pub execute_after: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PENDING_GOVERNANCE: Item<PendingGovernance> = Item::new("pending_governance");
+pub const STAKING_STATE: Item<StakingState> = Item::new("staking_state");
+pub const STAKERS: Map<&Addr, StakerInfo> = Map::new("stakers");
+pub const UNBONDING: Map<&Addr, Vec<UnbondingEntry>> = Map::new("unbonding");
+pub const PENDING_COMPOUND: Item<PendingCompound> = Item::new("pending_compound");
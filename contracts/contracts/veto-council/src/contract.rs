@@ -0,0 +1,1246 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Order, Response, StdError, StdResult, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
+
+use common::AssetInfo;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MessageSummary, MigrateMsg, ProposalResponse,
+    ProposalSummaryResponse, ProposalsResponse, QueryMsg,
+};
+use crate::state::{
+    Config, Proposal, ProposalStatus, CONFIG, CONTRACT_NAME, CONTRACT_VERSION, DEFAULT_QUERY_LIMIT,
+    MAX_DELAY, MAX_QUERY_LIMIT, MAX_SIGNERS, MAX_TITLE_LENGTH, MIN_DELAY, NEXT_PROPOSAL_ID,
+    PROPOSALS,
+};
+
+// ============ INSTANTIATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        proposer: deps.api.addr_validate(&msg.proposer)?,
+        signers: validate_signers(deps.as_ref(), &msg.signers)?,
+        veto_threshold: msg.veto_threshold,
+        delay: msg.delay,
+        treasury: validate_optional(deps.as_ref(), msg.treasury)?,
+        wrap_mapper: validate_optional(deps.as_ref(), msg.wrap_mapper)?,
+        ustc_swap: validate_optional(deps.as_ref(), msg.ustc_swap)?,
+    };
+    validate_config(&config)?;
+    CONFIG.save(deps.storage, &config)?;
+    NEXT_PROPOSAL_ID.save(deps.storage, &1)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("proposer", config.proposer)
+        .add_attribute("signers", config.signers.len().to_string())
+        .add_attribute("veto_threshold", config.veto_threshold.to_string())
+        .add_attribute("delay", config.delay.to_string()))
+}
+
+// ============ MIGRATE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver = get_contract_version(deps.storage)?;
+    if ver.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::generic_err(format!(
+            "Cannot migrate from {}, expected {}",
+            ver.contract, CONTRACT_NAME
+        ))));
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", ver.version)
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+// ============ EXECUTE ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            msgs,
+        } => execute_propose(deps, env, info, title, description, msgs),
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, info, proposal_id),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, proposal_id),
+        ExecuteMsg::Cancel { proposal_id } => execute_cancel(deps, info, proposal_id),
+        ExecuteMsg::UpdateConfig {
+            proposer,
+            signers,
+            veto_threshold,
+            delay,
+            treasury,
+            wrap_mapper,
+            ustc_swap,
+        } => execute_update_config(
+            deps,
+            env,
+            info,
+            proposer,
+            signers,
+            veto_threshold,
+            delay,
+            treasury,
+            wrap_mapper,
+            ustc_swap,
+        ),
+    }
+}
+
+fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.proposer {
+        return Err(ContractError::Unauthorized);
+    }
+    if msgs.is_empty() {
+        return Err(ContractError::EmptyProposal);
+    }
+    let title = title.trim().to_string();
+    if title.is_empty() || title.len() > MAX_TITLE_LENGTH {
+        return Err(ContractError::InvalidTitle {
+            reason: format!("must be 1 to {} bytes", MAX_TITLE_LENGTH),
+        });
+    }
+
+    let id = NEXT_PROPOSAL_ID.load(deps.storage)?;
+    NEXT_PROPOSAL_ID.save(deps.storage, &(id + 1))?;
+    let proposal = Proposal {
+        title,
+        description,
+        msgs,
+        created_at: env.block.time,
+        executable_after: env.block.time.plus_seconds(config.delay),
+        vetoes: vec![],
+        status: ProposalStatus::Pending,
+    };
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("messages", proposal.msgs.len().to_string())
+        .add_attribute("executable_after", proposal.executable_after.to_string()))
+}
+
+fn execute_veto(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.signers.contains(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let mut proposal = load_pending(deps.as_ref(), proposal_id)?;
+    if proposal.vetoes.contains(&info.sender) {
+        return Err(ContractError::AlreadyVetoed { proposal_id });
+    }
+    proposal.vetoes.push(info.sender.clone());
+
+    let vetoes = config.active_vetoes(&proposal.vetoes);
+    if vetoes >= config.veto_threshold {
+        proposal.status = ProposalStatus::Vetoed;
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "veto")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("signer", info.sender)
+        .add_attribute("vetoes", vetoes.to_string())
+        .add_attribute(
+            "vetoed",
+            (proposal.status == ProposalStatus::Vetoed).to_string(),
+        ))
+}
+
+fn execute_execute(deps: DepsMut, env: Env, proposal_id: u64) -> Result<Response, ContractError> {
+    let mut proposal = load_pending(deps.as_ref(), proposal_id)?;
+    if env.block.time < proposal.executable_after {
+        return Err(ContractError::DelayNotElapsed {
+            proposal_id,
+            executable_after: proposal.executable_after,
+        });
+    }
+
+    proposal.status = ProposalStatus::Executed;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_messages(proposal.msgs)
+        .add_attribute("action", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+fn execute_cancel(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.proposer {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let mut proposal = load_pending(deps.as_ref(), proposal_id)?;
+    proposal.status = ProposalStatus::Cancelled;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel")
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn execute_update_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposer: Option<String>,
+    signers: Option<Vec<String>>,
+    veto_threshold: Option<u32>,
+    delay: Option<u64>,
+    treasury: Option<String>,
+    wrap_mapper: Option<String>,
+    ustc_swap: Option<String>,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+    let mut config = CONFIG.load(deps.storage)?;
+    if let Some(proposer) = proposer {
+        config.proposer = deps.api.addr_validate(&proposer)?;
+    }
+    if let Some(signers) = signers {
+        config.signers = validate_signers(deps.as_ref(), &signers)?;
+    }
+    if let Some(veto_threshold) = veto_threshold {
+        config.veto_threshold = veto_threshold;
+    }
+    if let Some(delay) = delay {
+        config.delay = delay;
+    }
+    if let Some(treasury) = treasury {
+        config.treasury = Some(deps.api.addr_validate(&treasury)?);
+    }
+    if let Some(wrap_mapper) = wrap_mapper {
+        config.wrap_mapper = Some(deps.api.addr_validate(&wrap_mapper)?);
+    }
+    if let Some(ustc_swap) = ustc_swap {
+        config.ustc_swap = Some(deps.api.addr_validate(&ustc_swap)?);
+    }
+    validate_config(&config)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("proposer", config.proposer)
+        .add_attribute("signers", config.signers.len().to_string())
+        .add_attribute("veto_threshold", config.veto_threshold.to_string())
+        .add_attribute("delay", config.delay.to_string()))
+}
+
+// ============ QUERY ============
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Proposal { proposal_id } => {
+            let proposal = load_proposal(deps, proposal_id)?;
+            to_json_binary(&proposal_response(&env, proposal_id, proposal))
+        }
+        QueryMsg::Proposals {
+            start_before,
+            limit,
+        } => to_json_binary(&query_proposals(deps, env, start_before, limit)?),
+        QueryMsg::ProposalSummary { proposal_id } => {
+            to_json_binary(&query_proposal_summary(deps, env, proposal_id)?)
+        }
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        proposer: config.proposer,
+        signers: config.signers,
+        veto_threshold: config.veto_threshold,
+        delay: config.delay,
+        treasury: config.treasury,
+        wrap_mapper: config.wrap_mapper,
+        ustc_swap: config.ustc_swap,
+    })
+}
+
+fn query_proposals(
+    deps: Deps,
+    env: Env,
+    start_before: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ProposalsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let end = start_before.map(Bound::exclusive);
+
+    let proposals = PROPOSALS
+        .range(deps.storage, None, end, Order::Descending)
+        .take(limit)
+        .map(|r| r.map(|(id, proposal)| proposal_response(&env, id, proposal)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ProposalsResponse { proposals })
+}
+
+fn query_proposal_summary(
+    deps: Deps,
+    env: Env,
+    proposal_id: u64,
+) -> StdResult<ProposalSummaryResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal = load_proposal(deps, proposal_id)?;
+
+    Ok(ProposalSummaryResponse {
+        id: proposal_id,
+        title: proposal.title.clone(),
+        status: display_status(&env, &proposal),
+        executable_after: proposal.executable_after,
+        vetoes: config.active_vetoes(&proposal.vetoes),
+        veto_threshold: config.veto_threshold,
+        messages: proposal
+            .msgs
+            .iter()
+            .map(|msg| summarize(&config, &env.contract.address, msg))
+            .collect(),
+    })
+}
+
+// ============ HELPERS ============
+
+fn load_proposal(deps: Deps, proposal_id: u64) -> StdResult<Proposal> {
+    PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or_else(|| StdError::not_found(format!("proposal {}", proposal_id)))
+}
+
+/// Loads a proposal that can still be vetoed, executed or cancelled
+fn load_pending(deps: Deps, proposal_id: u64) -> Result<Proposal, ContractError> {
+    let proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::ProposalNotFound { proposal_id })?;
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ContractError::ProposalNotPending { proposal_id });
+    }
+    Ok(proposal)
+}
+
+fn display_status(env: &Env, proposal: &Proposal) -> ProposalStatus {
+    match proposal.status {
+        ProposalStatus::Pending if env.block.time >= proposal.executable_after => {
+            ProposalStatus::Executable
+        }
+        ref status => status.clone(),
+    }
+}
+
+fn proposal_response(env: &Env, id: u64, proposal: Proposal) -> ProposalResponse {
+    ProposalResponse {
+        id,
+        status: display_status(env, &proposal),
+        title: proposal.title,
+        description: proposal.description,
+        msgs: proposal.msgs,
+        created_at: proposal.created_at,
+        executable_after: proposal.executable_after,
+        vetoes: proposal.vetoes,
+    }
+}
+
+fn validate_optional(deps: Deps, addr: Option<String>) -> StdResult<Option<Addr>> {
+    addr.map(|a| deps.api.addr_validate(&a)).transpose()
+}
+
+fn validate_signers(deps: Deps, signers: &[String]) -> Result<Vec<Addr>, ContractError> {
+    let mut validated: Vec<Addr> = Vec::with_capacity(signers.len());
+    for signer in signers {
+        let addr = deps.api.addr_validate(signer)?;
+        if validated.contains(&addr) {
+            return Err(ContractError::InvalidCouncil {
+                reason: format!("duplicate signer {}", addr),
+            });
+        }
+        validated.push(addr);
+    }
+    Ok(validated)
+}
+
+fn validate_config(config: &Config) -> Result<(), ContractError> {
+    if config.signers.is_empty() || config.signers.len() > MAX_SIGNERS {
+        return Err(ContractError::InvalidCouncil {
+            reason: format!("must have 1 to {} signers", MAX_SIGNERS),
+        });
+    }
+    if config.veto_threshold == 0 || config.veto_threshold as usize > config.signers.len() {
+        return Err(ContractError::InvalidCouncil {
+            reason: "veto_threshold must be between 1 and the number of signers".to_string(),
+        });
+    }
+    if config.signers.contains(&config.proposer) {
+        return Err(ContractError::InvalidCouncil {
+            reason: "proposer cannot be a signer".to_string(),
+        });
+    }
+    if config.delay < MIN_DELAY || config.delay > MAX_DELAY {
+        return Err(ContractError::InvalidDelay {
+            min: MIN_DELAY,
+            max: MAX_DELAY,
+        });
+    }
+    Ok(())
+}
+
+/// One-line description of a queued message for the dashboard
+fn summarize(config: &Config, council: &Addr, msg: &CosmosMsg) -> MessageSummary {
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => MessageSummary {
+            target: Some(to_address.clone()),
+            contract: None,
+            description: format!("Send {} to {}", coins(amount), to_address),
+        },
+        CosmosMsg::Bank(BankMsg::Burn { amount }) => MessageSummary {
+            target: None,
+            contract: None,
+            description: format!("Burn {}", coins(amount)),
+        },
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let (contract, mut description) = if council == contract_addr {
+                (
+                    "veto-council",
+                    describe::<crate::msg::ExecuteMsg>(msg, describe_council),
+                )
+            } else if is(&config.treasury, contract_addr) {
+                ("treasury", describe(msg, describe_treasury))
+            } else if is(&config.wrap_mapper, contract_addr) {
+                ("wrap-mapper", describe(msg, describe_wrap_mapper))
+            } else if is(&config.ustc_swap, contract_addr) {
+                ("ustc-swap", describe(msg, describe_ustc_swap))
+            } else {
+                return MessageSummary {
+                    target: Some(contract_addr.clone()),
+                    contract: None,
+                    description: format!(
+                        "Execute {} on {}{}",
+                        raw(msg),
+                        contract_addr,
+                        with_funds(funds)
+                    ),
+                };
+            };
+            description.push_str(&with_funds(funds));
+            MessageSummary {
+                target: Some(contract_addr.clone()),
+                contract: Some(contract.to_string()),
+                description,
+            }
+        }
+        CosmosMsg::Wasm(WasmMsg::Instantiate { code_id, label, .. }) => MessageSummary {
+            target: None,
+            contract: None,
+            description: format!("Instantiate code {} as \"{}\"", code_id, label),
+        },
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr,
+            new_code_id,
+            ..
+        }) => MessageSummary {
+            target: Some(contract_addr.clone()),
+            contract: None,
+            description: format!("Migrate {} to code {}", contract_addr, new_code_id),
+        },
+        CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+            contract_addr,
+            admin,
+        }) => MessageSummary {
+            target: Some(contract_addr.clone()),
+            contract: None,
+            description: format!("Set wasm admin of {} to {}", contract_addr, admin),
+        },
+        CosmosMsg::Wasm(WasmMsg::ClearAdmin { contract_addr }) => MessageSummary {
+            target: Some(contract_addr.clone()),
+            contract: None,
+            description: format!("Clear wasm admin of {}", contract_addr),
+        },
+        other => MessageSummary {
+            target: None,
+            contract: None,
+            description: format!("Unrecognized message: {:?}", other),
+        },
+    }
+}
+
+fn is(known: &Option<Addr>, contract_addr: &str) -> bool {
+    known.as_ref().is_some_and(|a| a.as_str() == contract_addr)
+}
+
+/// Decodes `msg` as `T` and describes it, falling back to the raw JSON
+fn describe<T: serde::de::DeserializeOwned>(msg: &Binary, f: fn(T) -> String) -> String {
+    match from_json::<T>(msg) {
+        Ok(decoded) => f(decoded),
+        Err(_) => format!("Execute {}", raw(msg)),
+    }
+}
+
+fn raw(msg: &Binary) -> String {
+    String::from_utf8_lossy(msg.as_slice()).into_owned()
+}
+
+fn coins(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn with_funds(funds: &[Coin]) -> String {
+    if funds.is_empty() {
+        String::new()
+    } else {
+        format!(" (attaching {})", coins(funds))
+    }
+}
+
+fn asset(info: &AssetInfo, amount: Uint128) -> String {
+    match info {
+        AssetInfo::Native { denom } => format!("{}{}", amount, denom),
+        AssetInfo::Cw20 { contract_addr } => format!("{} of CW20 {}", amount, contract_addr),
+    }
+}
+
+fn describe_council(msg: crate::msg::ExecuteMsg) -> String {
+    use crate::msg::ExecuteMsg;
+    match msg {
+        ExecuteMsg::UpdateConfig {
+            proposer,
+            signers,
+            veto_threshold,
+            delay,
+            treasury,
+            wrap_mapper,
+            ustc_swap,
+        } => {
+            let mut changes = vec![];
+            if let Some(proposer) = proposer {
+                changes.push(format!("proposer to {}", proposer));
+            }
+            if let Some(signers) = signers {
+                changes.push(format!("signers to [{}]", signers.join(", ")));
+            }
+            if let Some(veto_threshold) = veto_threshold {
+                changes.push(format!("veto threshold to {}", veto_threshold));
+            }
+            if let Some(delay) = delay {
+                changes.push(format!("delay to {} seconds", delay));
+            }
+            if let Some(treasury) = treasury {
+                changes.push(format!("treasury to {}", treasury));
+            }
+            if let Some(wrap_mapper) = wrap_mapper {
+                changes.push(format!("wrap-mapper to {}", wrap_mapper));
+            }
+            if let Some(ustc_swap) = ustc_swap {
+                changes.push(format!("ustc-swap to {}", ustc_swap));
+            }
+            format!("Set council {}", changes.join(", "))
+        }
+        ExecuteMsg::Propose { title, .. } => format!("Queue council proposal \"{}\"", title),
+        ExecuteMsg::Veto { proposal_id } => format!("Veto council proposal {}", proposal_id),
+        ExecuteMsg::Execute { proposal_id } => {
+            format!("Execute council proposal {}", proposal_id)
+        }
+        ExecuteMsg::Cancel { proposal_id } => format!("Cancel council proposal {}", proposal_id),
+    }
+}
+
+fn describe_treasury(msg: treasury::msg::ExecuteMsg) -> String {
+    use treasury::msg::ExecuteMsg;
+    match msg {
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => format!(
+            "Propose {} as treasury governance (7-day timelock)",
+            new_governance
+        ),
+        ExecuteMsg::AcceptGovernanceTransfer {} => "Accept treasury governance".to_string(),
+        ExecuteMsg::CancelGovernanceTransfer {
+            proposed_governance,
+        } => format!(
+            "Cancel treasury governance transfer to {}",
+            proposed_governance
+        ),
+        ExecuteMsg::ProposeWithdraw {
+            destination,
+            asset: info,
+            amount,
+        } => format!(
+            "Propose treasury withdrawal of {} to {}",
+            asset(&info, amount),
+            destination
+        ),
+        ExecuteMsg::ProposeWithdrawBatch { legs } => format!(
+            "Propose treasury batch withdrawal: {}",
+            legs.iter()
+                .map(|leg| format!("{} to {}", asset(&leg.asset, leg.amount), leg.destination))
+                .collect::<Vec<_>>()
+                .join("; ")
+        ),
+        ExecuteMsg::ExecuteWithdraw { withdrawal_id } => {
+            format!("Execute treasury withdrawal {}", withdrawal_id)
+        }
+        ExecuteMsg::CancelWithdraw { withdrawal_id } => {
+            format!("Cancel treasury withdrawal {}", withdrawal_id)
+        }
+        ExecuteMsg::AddCw20 { contract_addr } => {
+            format!("Add CW20 {} to the treasury whitelist", contract_addr)
+        }
+        ExecuteMsg::RemoveCw20 { contract_addr } => {
+            format!("Remove CW20 {} from the treasury whitelist", contract_addr)
+        }
+        ExecuteMsg::SetSwapContract { contract_addr } => {
+            format!("Set treasury swap contract to {}", contract_addr)
+        }
+        ExecuteMsg::SetDenomWrapper { denom, wrapper } => {
+            format!("Register {} as the treasury wrapper for {}", wrapper, denom)
+        }
+        ExecuteMsg::RemoveDenomWrapper { denom } => {
+            format!("Remove the treasury wrapper for {}", denom)
+        }
+        ExecuteMsg::SetWrappingPaused { paused } => {
+            format!("{} treasury wrapping", pause_verb(paused))
+        }
+        other => format!(
+            "Execute {} on treasury",
+            to_json_binary(&other).map(|b| raw(&b)).unwrap_or_default()
+        ),
+    }
+}
+
+fn describe_wrap_mapper(msg: wrap_mapper::msg::ExecuteMsg) -> String {
+    use wrap_mapper::msg::ExecuteMsg;
+    match msg {
+        ExecuteMsg::SetDenomMapping { denom, cw20_addr } => {
+            format!("Map {} to CW20 {} in the wrap-mapper", denom, cw20_addr)
+        }
+        ExecuteMsg::RemoveDenomMapping { denom } => {
+            format!("Remove the wrap-mapper mapping for {}", denom)
+        }
+        ExecuteMsg::SetRateLimit { denom, config } => format!(
+            "Limit {} wrapping to {} per {} seconds",
+            denom, config.max_amount_per_window, config.window_seconds
+        ),
+        ExecuteMsg::RemoveRateLimit { denom } => {
+            format!("Remove the wrap-mapper rate limit for {}", denom)
+        }
+        ExecuteMsg::ProposeGovernanceTransfer { new_governance } => format!(
+            "Propose {} as wrap-mapper governance (7-day timelock)",
+            new_governance
+        ),
+        ExecuteMsg::AcceptGovernanceTransfer {} => "Accept wrap-mapper governance".to_string(),
+        ExecuteMsg::CancelGovernanceTransfer {} => {
+            "Cancel the wrap-mapper governance transfer".to_string()
+        }
+        ExecuteMsg::SetPaused { paused } => format!("{} the wrap-mapper", pause_verb(paused)),
+        ExecuteMsg::SetFeeBps { fee_bps } => format!(
+            "Set the wrap-mapper fee to {} bps ({}%)",
+            fee_bps,
+            cosmwasm_std::Decimal::from_ratio(fee_bps, 100u16)
+        ),
+        ExecuteMsg::SetChangeDelay { delay } => {
+            format!("Set the wrap-mapper change delay to {} seconds", delay)
+        }
+        ExecuteMsg::ExecuteChange { change_id } => {
+            format!("Execute queued wrap-mapper change {}", change_id)
+        }
+        ExecuteMsg::CancelChange { change_id } => {
+            format!("Cancel queued wrap-mapper change {}", change_id)
+        }
+        ExecuteMsg::SetEnforceReserves { enabled } => format!(
+            "{} the wrap-mapper reserve check",
+            if enabled { "Enable" } else { "Disable" }
+        ),
+        ExecuteMsg::DirectMint {
+            denom,
+            recipient,
+            amount,
+        } => format!("Mint {} wrapped {} to {}", amount, denom, recipient),
+        other => format!(
+            "Execute {} on wrap-mapper",
+            to_json_binary(&other).map(|b| raw(&b)).unwrap_or_default()
+        ),
+    }
+}
+
+fn describe_ustc_swap(msg: ustc_swap::msg::ExecuteMsg) -> String {
+    use ustc_swap::msg::ExecuteMsg;
+    match msg {
+        ExecuteMsg::EmergencyPause {} => "Pause ustc-swap".to_string(),
+        ExecuteMsg::EmergencyResume {} => "Resume ustc-swap".to_string(),
+        ExecuteMsg::ProposeAdmin { new_admin } => {
+            format!("Propose {} as ustc-swap admin (7-day timelock)", new_admin)
+        }
+        ExecuteMsg::AcceptAdmin {} => "Accept ustc-swap admin".to_string(),
+        ExecuteMsg::CancelAdminProposal {} => "Cancel the ustc-swap admin proposal".to_string(),
+        ExecuteMsg::RecoverAsset {
+            asset: info,
+            amount,
+            recipient,
+        } => format!(
+            "Recover {} from ustc-swap to {}",
+            asset(&info, amount),
+            recipient
+        ),
+        other => format!(
+            "Execute {} on ustc-swap",
+            to_json_binary(&other).map(|b| raw(&b)).unwrap_or_default()
+        ),
+    }
+}
+
+fn pause_verb(paused: bool) -> &'static str {
+    if paused {
+        "Pause"
+    } else {
+        "Unpause"
+    }
+}
+
+// ============ TESTS ============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+        MOCK_CONTRACT_ADDR,
+    };
+    use cosmwasm_std::{coins as native_coins, OwnedDeps, Timestamp};
+
+    const PROPOSER: &str = "dev_admin";
+    const SIGNERS: [&str; 5] = ["signer1", "signer2", "signer3", "signer4", "signer5"];
+    const TREASURY: &str = "treasury";
+    const WRAP_MAPPER: &str = "wrap_mapper";
+    const USTC_SWAP: &str = "ustc_swap";
+    const DELAY: u64 = 172_800;
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env
+    }
+
+    fn setup_contract(deps: &mut MockDeps) {
+        let msg = InstantiateMsg {
+            proposer: PROPOSER.to_string(),
+            signers: SIGNERS.iter().map(|s| s.to_string()).collect(),
+            veto_threshold: 3,
+            delay: DELAY,
+            treasury: Some(TREASURY.to_string()),
+            wrap_mapper: Some(WRAP_MAPPER.to_string()),
+            ustc_swap: Some(USTC_SWAP.to_string()),
+        };
+        instantiate(deps.as_mut(), env_at(0), mock_info(PROPOSER, &[]), msg).unwrap();
+    }
+
+    fn wasm_execute<T: serde::Serialize>(contract: &str, msg: &T) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(msg).unwrap(),
+            funds: vec![],
+        })
+    }
+
+    fn propose(deps: &mut MockDeps, at: u64, msgs: Vec<CosmosMsg>) -> u64 {
+        let res = execute(
+            deps.as_mut(),
+            env_at(at),
+            mock_info(PROPOSER, &[]),
+            ExecuteMsg::Propose {
+                title: "Pause wrapping".to_string(),
+                description: "Incident response".to_string(),
+                msgs,
+            },
+        )
+        .unwrap();
+        res.attributes
+            .iter()
+            .find(|a| a.key == "proposal_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap()
+    }
+
+    fn veto(
+        deps: &mut MockDeps,
+        signer: &str,
+        proposal_id: u64,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env_at(100),
+            mock_info(signer, &[]),
+            ExecuteMsg::Veto { proposal_id },
+        )
+    }
+
+    fn run(deps: &mut MockDeps, at: u64, proposal_id: u64) -> Result<Response, ContractError> {
+        execute(
+            deps.as_mut(),
+            env_at(at),
+            mock_info("relayer", &[]),
+            ExecuteMsg::Execute { proposal_id },
+        )
+    }
+
+    fn pause_msg() -> CosmosMsg {
+        wasm_execute(
+            TREASURY,
+            &treasury::msg::ExecuteMsg::SetWrappingPaused { paused: true },
+        )
+    }
+
+    #[test]
+    fn test_only_proposer_queues_and_delay_applies() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let err = execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(SIGNERS[0], &[]),
+            ExecuteMsg::Propose {
+                title: "Sneaky".to_string(),
+                description: String::new(),
+                msgs: vec![pause_msg()],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        let id = propose(&mut deps, 0, vec![pause_msg()]);
+        assert_eq!(id, 1);
+
+        let err = run(&mut deps, DELAY - 1, id).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::DelayNotElapsed {
+                proposal_id: id,
+                executable_after: Timestamp::from_seconds(DELAY),
+            }
+        );
+
+        // Vetoes below the threshold do not block execution
+        veto(&mut deps, SIGNERS[0], id).unwrap();
+        veto(&mut deps, SIGNERS[1], id).unwrap();
+        let proposal = load_proposal(deps.as_ref(), id).unwrap();
+        assert_eq!(
+            display_status(&env_at(DELAY), &proposal),
+            ProposalStatus::Executable
+        );
+
+        let res = run(&mut deps, DELAY, id).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, pause_msg());
+
+        let err = run(&mut deps, DELAY, id).unwrap_err();
+        assert_eq!(err, ContractError::ProposalNotPending { proposal_id: id });
+    }
+
+    #[test]
+    fn test_threshold_vetoes_kill_proposal() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+        let id = propose(&mut deps, 0, vec![pause_msg()]);
+
+        let err = veto(&mut deps, PROPOSER, id).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        veto(&mut deps, SIGNERS[0], id).unwrap();
+        let err = veto(&mut deps, SIGNERS[0], id).unwrap_err();
+        assert_eq!(err, ContractError::AlreadyVetoed { proposal_id: id });
+        veto(&mut deps, SIGNERS[1], id).unwrap();
+        let res = veto(&mut deps, SIGNERS[2], id).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "vetoed" && a.value == "true"));
+
+        let err = run(&mut deps, DELAY, id).unwrap_err();
+        assert_eq!(err, ContractError::ProposalNotPending { proposal_id: id });
+        let err = veto(&mut deps, SIGNERS[3], id).unwrap_err();
+        assert_eq!(err, ContractError::ProposalNotPending { proposal_id: id });
+
+        // The proposer can still withdraw other proposals
+        let id = propose(&mut deps, 0, vec![pause_msg()]);
+        execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(PROPOSER, &[]),
+            ExecuteMsg::Cancel { proposal_id: id },
+        )
+        .unwrap();
+        let res = query_proposals(deps.as_ref(), env_at(DELAY), None, None).unwrap();
+        let statuses: Vec<_> = res.proposals.iter().map(|p| p.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![ProposalStatus::Cancelled, ProposalStatus::Vetoed]
+        );
+    }
+
+    #[test]
+    fn test_council_changes_go_through_proposals() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let update = ExecuteMsg::UpdateConfig {
+            proposer: None,
+            signers: Some(vec!["signer1".to_string(), "signer2".to_string()]),
+            veto_threshold: Some(2),
+            delay: None,
+            treasury: None,
+            wrap_mapper: None,
+            ustc_swap: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(PROPOSER, &[]),
+            update.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized);
+
+        // Threshold above the signer count is rejected
+        let err = execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            ExecuteMsg::UpdateConfig {
+                proposer: None,
+                signers: Some(vec!["signer1".to_string()]),
+                veto_threshold: None,
+                delay: None,
+                treasury: None,
+                wrap_mapper: None,
+                ustc_swap: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidCouncil { .. }));
+
+        execute(
+            deps.as_mut(),
+            env_at(0),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            update,
+        )
+        .unwrap();
+        let config = query_config(deps.as_ref()).unwrap();
+        assert_eq!(config.signers.len(), 2);
+        assert_eq!(config.veto_threshold, 2);
+    }
+
+    #[test]
+    fn test_summary_decodes_governed_contracts() {
+        let mut deps = mock_dependencies();
+        setup_contract(&mut deps);
+
+        let id = propose(
+            &mut deps,
+            0,
+            vec![
+                wasm_execute(
+                    TREASURY,
+                    &treasury::msg::ExecuteMsg::ProposeWithdraw {
+                        destination: "auction".to_string(),
+                        asset: AssetInfo::native("uusd"),
+                        amount: Uint128::new(5_000_000),
+                    },
+                ),
+                wasm_execute(
+                    WRAP_MAPPER,
+                    &wrap_mapper::msg::ExecuteMsg::SetFeeBps { fee_bps: 50 },
+                ),
+                wasm_execute(USTC_SWAP, &ustc_swap::msg::ExecuteMsg::EmergencyPause {}),
+                wasm_execute(
+                    MOCK_CONTRACT_ADDR,
+                    &ExecuteMsg::UpdateConfig {
+                        proposer: None,
+                        signers: None,
+                        veto_threshold: Some(2),
+                        delay: None,
+                        treasury: None,
+                        wrap_mapper: None,
+                        ustc_swap: None,
+                    },
+                ),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "recipient".to_string(),
+                    amount: native_coins(10, "uluna"),
+                }),
+                wasm_execute("unknown", &ustc_swap::msg::ExecuteMsg::EmergencyPause {}),
+            ],
+        );
+        veto(&mut deps, SIGNERS[0], id).unwrap();
+
+        let res = query_proposal_summary(deps.as_ref(), env_at(100), id).unwrap();
+        assert_eq!(res.status, ProposalStatus::Pending);
+        assert_eq!(res.vetoes, 1);
+        assert_eq!(res.veto_threshold, 3);
+        let descriptions: Vec<_> = res
+            .messages
+            .iter()
+            .map(|m| m.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                "Propose treasury withdrawal of 5000000uusd to auction",
+                "Set the wrap-mapper fee to 50 bps (0.5%)",
+                "Pause ustc-swap",
+                "Set council veto threshold to 2",
+                "Send 10uluna to recipient",
+                "Execute {\"emergency_pause\":{}} on unknown",
+            ]
+        );
+        assert_eq!(res.messages[0].contract, Some("treasury".to_string()));
+        assert_eq!(res.messages[5].contract, None);
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use common::contract_wrapper;
+    use common::testing::advance;
+    use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, WasmMsg};
+    use cw_multi_test::{App, Executor};
+
+    use crate::msg::{ExecuteMsg, InstantiateMsg, ProposalSummaryResponse, QueryMsg};
+    use crate::state::ProposalStatus;
+
+    const ADMIN: &str = "dev_admin";
+    const SIGNERS: [&str; 5] = ["signer1", "signer2", "signer3", "signer4", "signer5"];
+    const DELAY: u64 = 172_800;
+    const GOVERNANCE_TIMELOCK: u64 = 604_800;
+
+    fn call(contract: &Addr, msg: &treasury::msg::ExecuteMsg) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(msg).unwrap(),
+            funds: vec![],
+        })
+    }
+
+    fn propose(app: &mut App, council: &Addr, msgs: Vec<CosmosMsg>) -> u64 {
+        let res = app
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                council.clone(),
+                &ExecuteMsg::Propose {
+                    title: "Treasury change".to_string(),
+                    description: String::new(),
+                    msgs,
+                },
+                &[],
+            )
+            .unwrap();
+        res.events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "proposal_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_council_takes_over_and_governs_treasury() {
+        let mut app = App::default();
+        let admin = Addr::unchecked(ADMIN);
+
+        let council_code = app.store_code(contract_wrapper!(crate));
+        let treasury_code = app.store_code(contract_wrapper!(treasury));
+
+        let treasury = app
+            .instantiate_contract(
+                treasury_code,
+                admin.clone(),
+                &treasury::msg::InstantiateMsg {
+                    governance: ADMIN.to_string(),
+                },
+                &[],
+                "treasury",
+                None,
+            )
+            .unwrap();
+        let council = app
+            .instantiate_contract(
+                council_code,
+                admin.clone(),
+                &InstantiateMsg {
+                    proposer: ADMIN.to_string(),
+                    signers: SIGNERS.iter().map(|s| s.to_string()).collect(),
+                    veto_threshold: 3,
+                    delay: DELAY,
+                    treasury: Some(treasury.to_string()),
+                    wrap_mapper: None,
+                    ustc_swap: None,
+                },
+                &[],
+                "veto-council",
+                None,
+            )
+            .unwrap();
+
+        // Hand treasury governance to the council
+        app.execute_contract(
+            admin.clone(),
+            treasury.clone(),
+            &treasury::msg::ExecuteMsg::ProposeGovernanceTransfer {
+                new_governance: council.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        let accept = propose(
+            &mut app,
+            &council,
+            vec![call(
+                &treasury,
+                &treasury::msg::ExecuteMsg::AcceptGovernanceTransfer {},
+            )],
+        );
+        advance(&mut app, GOVERNANCE_TIMELOCK);
+        app.execute_contract(
+            admin.clone(),
+            council.clone(),
+            &ExecuteMsg::Execute {
+                proposal_id: accept,
+            },
+            &[],
+        )
+        .unwrap();
+        let config: treasury::msg::ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&treasury, &treasury::msg::QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(config.governance, council);
+
+        // A vetoed whitelist change never reaches the treasury
+        let add = call(
+            &treasury,
+            &treasury::msg::ExecuteMsg::AddCw20 {
+                contract_addr: "malicious_token".to_string(),
+            },
+        );
+        let vetoed = propose(&mut app, &council, vec![add.clone()]);
+        for signer in &SIGNERS[..3] {
+            app.execute_contract(
+                Addr::unchecked(*signer),
+                council.clone(),
+                &ExecuteMsg::Veto {
+                    proposal_id: vetoed,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        let summary: ProposalSummaryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &council,
+                &QueryMsg::ProposalSummary {
+                    proposal_id: vetoed,
+                },
+            )
+            .unwrap();
+        assert_eq!(summary.status, ProposalStatus::Vetoed);
+        assert_eq!(
+            summary.messages[0].description,
+            "Add CW20 malicious_token to the treasury whitelist"
+        );
+        advance(&mut app, DELAY);
+        app.execute_contract(
+            admin.clone(),
+            council.clone(),
+            &ExecuteMsg::Execute {
+                proposal_id: vetoed,
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        // An unvetoed one executes with the council as sender
+        let passed = propose(&mut app, &council, vec![add]);
+        advance(&mut app, DELAY);
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            council.clone(),
+            &ExecuteMsg::Execute {
+                proposal_id: passed,
+            },
+            &[],
+        )
+        .unwrap();
+        let whitelist: treasury::msg::Cw20WhitelistResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &treasury,
+                &treasury::msg::QueryMsg::Cw20Whitelist {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            whitelist.addresses,
+            vec![Addr::unchecked("malicious_token")]
+        );
+    }
+}
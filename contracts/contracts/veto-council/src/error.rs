@@ -0,0 +1,38 @@
+use cosmwasm_std::Timestamp;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] cosmwasm_std::StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Proposal {proposal_id} not found")]
+    ProposalNotFound { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} is not pending")]
+    ProposalNotPending { proposal_id: u64 },
+
+    #[error("Proposal {proposal_id} is not executable until {executable_after}")]
+    DelayNotElapsed {
+        proposal_id: u64,
+        executable_after: Timestamp,
+    },
+
+    #[error("Already vetoed proposal {proposal_id}")]
+    AlreadyVetoed { proposal_id: u64 },
+
+    #[error("Proposal must contain at least one message")]
+    EmptyProposal,
+
+    #[error("Invalid title: {reason}")]
+    InvalidTitle { reason: String },
+
+    #[error("Invalid council: {reason}")]
+    InvalidCouncil { reason: String },
+
+    #[error("Invalid delay: must be between {min} and {max} seconds")]
+    InvalidDelay { min: u64, max: u64 },
+}
@@ -0,0 +1,31 @@
+//! # Veto Council Contract
+//!
+//! Veto-only multisig layer that sits between the dev admin and the
+//! contracts it governs. The council is `governance` on the treasury and
+//! wrap-mapper and `admin` on ustc-swap.
+//!
+//! ## Proposals
+//!
+//! Only the proposer (the dev admin) can queue proposals. A proposal is an
+//! arbitrary list of `CosmosMsg`s that becomes executable once the delay has
+//! passed. Until it is executed, any signer can veto it; once the veto
+//! threshold is reached the proposal is dead. Signers cannot propose or
+//! execute anything themselves.
+//!
+//! ## Council Changes
+//!
+//! `UpdateConfig` is only accepted from the contract itself, so changing the
+//! proposer, signers, threshold or delay must go through a proposal that the
+//! current signers had the chance to veto.
+//!
+//! ## Summaries
+//!
+//! Messages addressed to the treasury, wrap-mapper, ustc-swap or the council
+//! itself are decoded into one-line descriptions for the multisig dashboard.
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,120 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, CosmosMsg, Timestamp};
+
+use crate::state::ProposalStatus;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub proposer: String,
+    pub signers: Vec<String>,
+    pub veto_threshold: u32,
+    /// Seconds between queueing and earliest execution (1 to 30 days)
+    pub delay: u64,
+    pub treasury: Option<String>,
+    pub wrap_mapper: Option<String>,
+    pub ustc_swap: Option<String>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Queues messages for execution after the delay (proposer-only)
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg>,
+    },
+
+    /// Vetoes a proposal that has not been executed (signer-only)
+    Veto { proposal_id: u64 },
+
+    /// Executes an unvetoed proposal once its delay has passed (permissionless)
+    Execute { proposal_id: u64 },
+
+    /// Withdraws a pending proposal (proposer-only)
+    Cancel { proposal_id: u64 },
+
+    /// Replaces council settings (only via an executed proposal)
+    UpdateConfig {
+        proposer: Option<String>,
+        signers: Option<Vec<String>>,
+        veto_threshold: Option<u32>,
+        delay: Option<u64>,
+        treasury: Option<String>,
+        wrap_mapper: Option<String>,
+        ustc_swap: Option<String>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+
+    #[returns(ProposalResponse)]
+    Proposal { proposal_id: u64 },
+
+    /// Proposals ordered by id, newest first
+    #[returns(ProposalsResponse)]
+    Proposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    /// Human-readable description of each message in a proposal
+    #[returns(ProposalSummaryResponse)]
+    ProposalSummary { proposal_id: u64 },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub proposer: Addr,
+    pub signers: Vec<Addr>,
+    pub veto_threshold: u32,
+    pub delay: u64,
+    pub treasury: Option<Addr>,
+    pub wrap_mapper: Option<Addr>,
+    pub ustc_swap: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg>,
+    pub created_at: Timestamp,
+    pub executable_after: Timestamp,
+    pub vetoes: Vec<Addr>,
+    pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+#[cw_serde]
+pub struct MessageSummary {
+    /// Recipient address of the message, if any
+    pub target: Option<String>,
+    /// Known contract the message is addressed to ("treasury", "wrap-mapper",
+    /// "ustc-swap" or "veto-council")
+    pub contract: Option<String>,
+    pub description: String,
+}
+
+#[cw_serde]
+pub struct ProposalSummaryResponse {
+    pub id: u64,
+    pub title: String,
+    pub status: ProposalStatus,
+    pub executable_after: Timestamp,
+    /// Vetoes from current signers
+    pub vetoes: u32,
+    pub veto_threshold: u32,
+    pub messages: Vec<MessageSummary>,
+}
@@ -0,0 +1,71 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+pub const CONTRACT_NAME: &str = "crates.io:veto-council";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 1 day in seconds
+pub const MIN_DELAY: u64 = 86_400;
+
+/// 30 days in seconds
+pub const MAX_DELAY: u64 = 2_592_000;
+
+/// Maximum number of veto signers
+pub const MAX_SIGNERS: usize = 20;
+
+/// Maximum title length in bytes
+pub const MAX_TITLE_LENGTH: usize = 128;
+
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
+#[cw_serde]
+pub struct Config {
+    /// Sole address allowed to queue proposals (the dev admin)
+    pub proposer: Addr,
+    /// Addresses allowed to veto
+    pub signers: Vec<Addr>,
+    /// Vetoes from current signers needed to kill a proposal
+    pub veto_threshold: u32,
+    /// Seconds between queueing and earliest execution
+    pub delay: u64,
+    /// Governed contracts, used to decode proposal summaries
+    pub treasury: Option<Addr>,
+    pub wrap_mapper: Option<Addr>,
+    pub ustc_swap: Option<Addr>,
+}
+
+impl Config {
+    /// Vetoes on a proposal that still count towards the threshold
+    pub fn active_vetoes(&self, vetoes: &[Addr]) -> u32 {
+        vetoes.iter().filter(|v| self.signers.contains(v)).count() as u32
+    }
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    /// Queued; executable once the delay has passed
+    Pending,
+    /// Delay passed without reaching the veto threshold (query-only)
+    Executable,
+    Vetoed,
+    Cancelled,
+    Executed,
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg>,
+    pub created_at: Timestamp,
+    pub executable_after: Timestamp,
+    pub vetoes: Vec<Addr>,
+    /// Stored status; never `Executable`, which is derived at query time
+    pub status: ProposalStatus,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const NEXT_PROPOSAL_ID: Item<u64> = Item::new("next_proposal_id");
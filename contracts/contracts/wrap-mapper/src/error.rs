@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -21,6 +21,12 @@ pub enum ContractError {
     #[error("Rate limit exceeded for denom: {denom}")]
     RateLimitExceeded { denom: String },
 
+    #[error("Per-address rate limit exceeded for {address} on denom: {denom}")]
+    AddressRateLimitExceeded { denom: String, address: String },
+
+    #[error("Invalid rate limit: {reason}")]
+    InvalidRateLimit { reason: String },
+
     #[error("Zero amount not allowed")]
     ZeroAmount,
 
@@ -36,9 +42,37 @@ pub enum ContractError {
     #[error("Fee too low: {fee_bps} bps below min {min_bps} bps (must cover chain tax)")]
     FeeTooLow { fee_bps: u16, min_bps: u16 },
 
+    #[error("Invalid fee tiers: {reason}")]
+    InvalidFeeTiers { reason: String },
+
     #[error("Rate limit usage overflow")]
     RateLimitOverflow,
 
     #[error("Wrap-mapper is not a minter on CW20 contract {cw20_addr}")]
     NotMinter { cw20_addr: String },
+
+    #[error("Change delay too long: {delay} seconds exceeds max {max_delay} seconds")]
+    ChangeDelayTooLong { delay: u64, max_delay: u64 },
+
+    #[error("No pending change with ID {change_id}")]
+    NoPendingChange { change_id: u64 },
+
+    #[error(
+        "Minting would push {denom} supply to {supply}, above treasury reserves of {reserves}"
+    )]
+    ReservesExceeded {
+        denom: String,
+        supply: Uint128,
+        reserves: Uint128,
+    },
+
+    #[error("No fee recipient configured")]
+    NoFeeRecipient,
+
+    #[error("Insufficient accrued fees for {denom}: requested {requested}, available {available}")]
+    InsufficientFees {
+        denom: String,
+        requested: Uint128,
+        available: Uint128,
+    },
 }
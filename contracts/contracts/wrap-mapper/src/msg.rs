@@ -1,8 +1,8 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
 
-use crate::state::RateLimitConfig;
+use crate::state::{DenomFees, ParamChange, RateLimitConfig};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -28,24 +28,24 @@ pub enum ExecuteMsg {
     /// CW20 receive hook for unwrapping
     Receive(Cw20ReceiveMsg),
 
-    /// Sets a denom->CW20 mapping (governance-only)
+    /// Sets a denom->CW20 mapping (governance-only, queued if a change delay is set)
     SetDenomMapping {
         denom: String,
         cw20_addr: String,
     },
 
-    /// Removes a denom mapping (governance-only)
+    /// Removes a denom mapping (governance-only, queued if a change delay is set)
     RemoveDenomMapping {
         denom: String,
     },
 
-    /// Sets rate limit for a denom (governance-only)
+    /// Sets rate limit for a denom (governance-only, queued if a change delay is set)
     SetRateLimit {
         denom: String,
         config: RateLimitConfig,
     },
 
-    /// Removes rate limit for a denom (governance-only)
+    /// Removes rate limit for a denom (governance-only, queued if a change delay is set)
     RemoveRateLimit {
         denom: String,
     },
@@ -61,15 +61,82 @@ pub enum ExecuteMsg {
     /// Cancels a pending governance transfer (governance-only)
     CancelGovernanceTransfer {},
 
-    /// Pauses or unpauses the contract (governance-only)
+    /// Pauses or unpauses the contract (governance-only). Pausing is always
+    /// instant; unpausing is queued if a change delay is set
     SetPaused {
         paused: bool,
     },
 
-    /// Updates the fee in basis points (governance-only, max 1000 = 10%)
+    /// Updates the fee in basis points (governance-only, max 1000 = 10%,
+    /// queued if a change delay is set)
     SetFeeBps {
         fee_bps: u16,
     },
+
+    /// Sets separate wrap and unwrap fee schedules for a denom, replacing
+    /// `fee_bps` for it (governance-only, queued if a change delay is set).
+    /// Every rate must be within `MIN_FEE_BPS..=MAX_FEE_BPS`
+    SetDenomFees {
+        denom: String,
+        fees: DenomFees,
+    },
+
+    /// Returns a denom to the global `fee_bps` (governance-only, queued if a
+    /// change delay is set)
+    RemoveDenomFees {
+        denom: String,
+    },
+
+    /// Exempts an address from wrap and unwrap fees, or revokes the
+    /// exemption (governance-only, queued if a change delay is set)
+    SetFeeExempt {
+        address: String,
+        exempt: bool,
+    },
+
+    /// Sets the recipient of swept fees (governance-only, queued if a change
+    /// delay is set)
+    SetFeeRecipient {
+        recipient: String,
+    },
+
+    /// Routes accrued fees for a denom from the treasury to the fee recipient
+    /// (governance-only). `amount` defaults to everything accrued
+    SweepFees {
+        denom: String,
+        amount: Option<Uint128>,
+    },
+
+    /// Sets the parameter change delay (governance-only, max 30 days).
+    /// Increases apply immediately; decreases are queued behind the current delay
+    SetChangeDelay {
+        delay: u64,
+    },
+
+    /// Applies a queued change once its delay has passed (governance-only)
+    ExecuteChange {
+        change_id: u64,
+    },
+
+    /// Drops a queued change (governance-only)
+    CancelChange {
+        change_id: u64,
+    },
+
+    /// Turns the reserve check on wraps and direct mints on or off
+    /// (governance-only). Enabling is always instant; disabling is queued if
+    /// a change delay is set
+    SetEnforceReserves {
+        enabled: bool,
+    },
+
+    /// Mints CW20 against native backing the treasury already holds, without
+    /// a fee (governance-only, [LOW-3]). Subject to the reserve check when enabled
+    DirectMint {
+        denom: String,
+        recipient: String,
+        amount: Uint128,
+    },
 }
 
 #[cw_serde]
@@ -89,11 +156,42 @@ pub enum QueryMsg {
     #[returns(AllDenomMappingsResponse)]
     AllDenomMappings {},
 
+    /// Rate limit configuration, raw shared state and remaining capacity
     #[returns(RateLimitResponse)]
     RateLimit { denom: String },
 
+    /// Remaining per-address capacity for a denom
+    #[returns(AddressRateLimitResponse)]
+    AddressRateLimit { denom: String, address: String },
+
     #[returns(PendingGovernanceResponse)]
     PendingGovernance {},
+
+    /// Fee schedules in force for a denom, falling back to `fee_bps`
+    #[returns(DenomFeesResponse)]
+    DenomFees { denom: String },
+
+    /// Fee-exempt addresses, ordered by address
+    #[returns(FeeExemptionsResponse)]
+    FeeExemptions {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Cumulative wrap and unwrap fees for a denom and how much has been swept
+    #[returns(FeeIncomeResponse)]
+    FeeIncome { denom: String },
+
+    /// Treasury balance, CW20 supply, fee income and backing ratio per mapped denom
+    #[returns(ReservesResponse)]
+    Reserves {},
+
+    /// Queued parameter changes, ordered by change ID
+    #[returns(PendingChangesResponse)]
+    PendingChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -102,6 +200,11 @@ pub struct ConfigResponse {
     pub treasury: Addr,
     pub paused: bool,
     pub fee_bps: u16,
+    /// Seconds a parameter change waits before it can be executed
+    pub change_delay: u64,
+    /// Whether mints are rejected when they would exceed treasury reserves
+    pub enforce_reserves: bool,
+    pub fee_recipient: Option<Addr>,
 }
 
 #[cw_serde]
@@ -130,11 +233,84 @@ pub struct AllDenomMappingsResponse {
 #[cw_serde]
 pub struct RateLimitResponse {
     pub config: Option<RateLimitConfig>,
+    /// Stored state of the shared budget as of the last wrap or unwrap
     pub current_window_start: Option<Timestamp>,
     pub amount_used: Uint128,
+    /// Wrap budget as of now (the shared budget unless `direction_limits` is set)
+    pub wrap: Option<BudgetStatus>,
+    /// Unwrap budget as of now (the shared budget unless `direction_limits` is set)
+    pub unwrap: Option<BudgetStatus>,
 }
 
-/// Message sent to the treasury for instant withdrawal
+#[cw_serde]
+pub struct AddressRateLimitResponse {
+    pub limit: Option<Uint128>,
+    pub status: Option<BudgetStatus>,
+}
+
+#[cw_serde]
+pub struct BudgetStatus {
+    pub used: Uint128,
+    pub remaining: Uint128,
+    /// Seconds until the whole budget is available again, assuming no further use
+    pub seconds_to_full: u64,
+}
+
+#[cw_serde]
+pub struct DenomFeesResponse {
+    pub denom: String,
+    /// Whether the schedules are specific to the denom rather than `fee_bps`
+    pub custom: bool,
+    pub fees: DenomFees,
+}
+
+#[cw_serde]
+pub struct FeeExemptionsResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct FeeIncomeResponse {
+    pub denom: String,
+    pub wrap: Uint128,
+    pub unwrap: Uint128,
+    pub swept: Uint128,
+    /// Fees still held by the treasury: `wrap + unwrap - swept`
+    pub accrued: Uint128,
+}
+
+#[cw_serde]
+pub struct DenomReserves {
+    pub denom: String,
+    pub cw20_addr: Addr,
+    /// Treasury's native balance of `denom`, including retained fees
+    pub treasury_balance: Uint128,
+    pub cw20_supply: Uint128,
+    /// Wrap and unwrap fees retained by the treasury since tracking began
+    pub fee_income: Uint128,
+    /// `treasury_balance / cw20_supply`; None while no CW20 is outstanding or
+    /// if the ratio overflows `Decimal`
+    pub backing_ratio: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct ReservesResponse {
+    pub reserves: Vec<DenomReserves>,
+}
+
+#[cw_serde]
+pub struct PendingChangeEntry {
+    pub change_id: u64,
+    pub change: ParamChange,
+    pub execute_after: Timestamp,
+}
+
+#[cw_serde]
+pub struct PendingChangesResponse {
+    pub changes: Vec<PendingChangeEntry>,
+}
+
+/// Messages sent to the treasury for unwraps and fee sweeps
 #[cw_serde]
 pub enum TreasuryExecuteMsg {
     InstantWithdraw {
@@ -142,4 +318,9 @@ pub enum TreasuryExecuteMsg {
         denom: String,
         amount: Uint128,
     },
+    SweepWrapperFees {
+        recipient: String,
+        denom: String,
+        amount: Uint128,
+    },
 }
@@ -8,6 +8,14 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// 7 days in seconds
 pub const GOVERNANCE_TIMELOCK: u64 = 604_800;
 
+/// Upper bound for the parameter change delay: 30 days in seconds
+pub const MAX_CHANGE_DELAY: u64 = 2_592_000;
+
+/// Default page size for paginated queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// Maximum page size for paginated queries
+pub const MAX_QUERY_LIMIT: u32 = 50;
+
 #[cw_serde]
 pub struct Config {
     pub governance: Addr,
@@ -26,6 +34,44 @@ pub const MAX_FEE_BPS: u16 = 1000; // 10% hard cap
 /// or the treasury's native balance will erode below outstanding CW20 supply.
 pub const MIN_FEE_BPS: u16 = 1;
 
+/// Maximum number of volume tiers per denom and direction
+pub const MAX_FEE_TIERS: usize = 10;
+
+/// Amounts of at least `min_amount` pay `fee_bps` on the whole amount
+#[cw_serde]
+pub struct FeeTier {
+    pub min_amount: Uint128,
+    pub fee_bps: u16,
+}
+
+/// Fee schedule for one direction of a denom
+#[cw_serde]
+pub struct DirectionFee {
+    /// Fee for amounts below the first tier
+    pub fee_bps: u16,
+    /// Volume tiers by strictly increasing `min_amount`; the highest tier
+    /// reached applies
+    #[serde(default)]
+    pub tiers: Vec<FeeTier>,
+}
+
+impl DirectionFee {
+    pub fn fee_bps_for(&self, amount: Uint128) -> u16 {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|t| amount >= t.min_amount)
+            .map_or(self.fee_bps, |t| t.fee_bps)
+    }
+}
+
+/// Fee schedules that replace `Config.fee_bps` for one denom
+#[cw_serde]
+pub struct DenomFees {
+    pub wrap: DirectionFee,
+    pub unwrap: DirectionFee,
+}
+
 #[cw_serde]
 pub struct PendingGovernance {
     pub new_address: Addr,
@@ -41,20 +87,180 @@ pub const DENOM_TO_CW20: Map<&str, Addr> = Map::new("denom_to_cw20");
 /// Reverse map: CW20 contract address -> native denom
 pub const CW20_TO_DENOM: Map<&str, String> = Map::new("cw20_to_denom");
 
+/// Maximum number of sub-windows for the sliding log algorithm
+pub const MAX_SLIDING_LOG_BUCKETS: u32 = 60;
+
+#[cw_serde]
+#[derive(Default)]
+pub enum RateLimitAlgorithm {
+    /// Fixed windows that reset entirely when they expire ([LOW-2]: allows a
+    /// 2x burst across a window boundary)
+    #[default]
+    Tumbling,
+    /// Usage is logged in `buckets` sub-windows and counts until it is
+    /// `window_seconds` old, so no boundary burst beyond one sub-window
+    SlidingLog { buckets: u32 },
+    /// Bucket of `max_amount_per_window` that refills continuously;
+    /// `window_seconds` is unused
+    TokenBucket { refill_per_second: Uint128 },
+}
+
+/// Separate wrap and unwrap budgets
+#[cw_serde]
+pub struct DirectionLimits {
+    pub wrap: Uint128,
+    pub unwrap: Uint128,
+}
+
 #[cw_serde]
+#[derive(Default)]
 pub struct RateLimitConfig {
+    /// Budget per window, or bucket capacity for the token bucket. Shared by
+    /// wrap and unwrap unless `direction_limits` is set.
     pub max_amount_per_window: Uint128,
+    /// Window length; must be positive for `Tumbling` and `SlidingLog`
     pub window_seconds: u64,
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
+    /// Replaces the shared budget with one budget per direction
+    #[serde(default)]
+    pub direction_limits: Option<DirectionLimits>,
+    /// Budget per depositor (wrap) or CW20 sender (unwrap), shared across
+    /// directions and tracked with the same algorithm
+    #[serde(default)]
+    pub per_address_limit: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct UsageBucket {
+    /// Sub-window start in unix seconds
+    pub start: u64,
+    pub amount: Uint128,
 }
 
 #[cw_serde]
 pub struct RateLimitState {
+    /// Tumbling: start of the current window. Token bucket: last refill.
     pub current_window_start: Timestamp,
+    /// Usage counted against the budget as of `current_window_start`
     pub amount_used: Uint128,
+    /// Sliding log: usage per sub-window, oldest first
+    #[serde(default)]
+    pub buckets: Vec<UsageBucket>,
 }
 
 /// Rate limit configuration per denom
 pub const RATE_LIMITS: Map<&str, RateLimitConfig> = Map::new("rate_limits");
 
-/// Current rate limit window state per denom
+/// Current shared (wrap + unwrap) rate limit state per denom
 pub const RATE_LIMIT_STATE: Map<&str, RateLimitState> = Map::new("rate_limit_state");
+
+/// Direction keys for `DIRECTION_RATE_LIMIT_STATE`
+pub const WRAP_DIRECTION: &str = "wrap";
+pub const UNWRAP_DIRECTION: &str = "unwrap";
+
+/// Rate limit state per (denom, direction) when `direction_limits` is set
+pub const DIRECTION_RATE_LIMIT_STATE: Map<(&str, &str), RateLimitState> =
+    Map::new("direction_rate_limit_state");
+
+/// Rate limit state per (denom, address) when `per_address_limit` is set
+pub const ADDRESS_RATE_LIMIT_STATE: Map<(&str, &Addr), RateLimitState> =
+    Map::new("address_rate_limit_state");
+
+/// Cumulative fees retained by the treasury for a denom, per direction
+#[cw_serde]
+#[derive(Default)]
+pub struct FeeIncome {
+    pub wrap: Uint128,
+    pub unwrap: Uint128,
+    /// Fees already routed to the fee recipient
+    #[serde(default)]
+    pub swept: Uint128,
+}
+
+impl FeeIncome {
+    pub fn total(&self) -> Uint128 {
+        self.wrap + self.unwrap
+    }
+
+    /// Fees still held by the treasury that can be swept
+    pub fn accrued(&self) -> Uint128 {
+        self.total() - self.swept
+    }
+}
+
+/// Fee income per denom, counted from the upgrade that introduced it
+pub const FEE_INCOME: Map<&str, FeeIncome> = Map::new("fee_income");
+
+/// Per-denom fee schedules; denoms without one pay `Config.fee_bps`
+pub const DENOM_FEES: Map<&str, DenomFees> = Map::new("denom_fees");
+
+/// Addresses that pay no fee when they wrap (as depositor) or unwrap (as
+/// CW20 sender), e.g. the DEX router or money market
+pub const FEE_EXEMPT: Map<&Addr, bool> = Map::new("fee_exempt");
+
+/// Destination of swept fees (e.g. a buy-and-burn contract). Unset until
+/// governance configures it.
+pub const FEE_RECIPIENT: Item<Addr> = Item::new("fee_recipient");
+
+/// When set, mints that would push a CW20's supply above the treasury's
+/// native balance of its denom are rejected
+pub const ENFORCE_RESERVES: Item<bool> = Item::new("enforce_reserves");
+
+/// Governance parameter change held back by the change delay
+#[cw_serde]
+pub enum ParamChange {
+    SetDenomMapping {
+        denom: String,
+        cw20_addr: Addr,
+    },
+    RemoveDenomMapping {
+        denom: String,
+    },
+    SetRateLimit {
+        denom: String,
+        config: RateLimitConfig,
+    },
+    RemoveRateLimit {
+        denom: String,
+    },
+    SetFeeBps {
+        fee_bps: u16,
+    },
+    SetDenomFees {
+        denom: String,
+        fees: DenomFees,
+    },
+    RemoveDenomFees {
+        denom: String,
+    },
+    SetFeeExempt {
+        address: Addr,
+        exempt: bool,
+    },
+    SetFeeRecipient {
+        recipient: Addr,
+    },
+    /// Unpausing is delayed; pausing is always instant
+    Unpause {},
+    /// Disabling the reserve check is delayed; enabling it is always instant
+    DisableReserveCheck {},
+    SetChangeDelay {
+        delay: u64,
+    },
+}
+
+#[cw_serde]
+pub struct PendingChange {
+    pub change: ParamChange,
+    pub execute_after: Timestamp,
+}
+
+/// Delay in seconds before a parameter change can be executed. Zero applies
+/// changes immediately.
+pub const CHANGE_DELAY: Item<u64> = Item::new("change_delay");
+
+/// Queued parameter changes keyed by sequential change ID
+pub const PENDING_CHANGES: Map<u64, PendingChange> = Map::new("pending_changes");
+
+pub const NEXT_CHANGE_ID: Item<u64> = Item::new("next_change_id");
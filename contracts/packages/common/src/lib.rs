@@ -4,6 +4,11 @@
 //! used across the USTR CMM smart contracts.
 
 pub mod asset;
+pub mod tier;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub use asset::{Asset, AssetInfo};
+pub use tier::CrTier;
 
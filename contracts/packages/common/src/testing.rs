@@ -0,0 +1,78 @@
+//! Multi-test fixtures shared by the contracts' integration tests
+//!
+//! Only built with the `testing` feature, which contracts enable on their
+//! dev-dependency on this package.
+
+use cosmwasm_std::{Addr, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20QueryMsg, MinterResponse};
+use cw_multi_test::{App, Executor};
+
+pub use cosmwasm_std::Empty;
+pub use cw_multi_test::{Contract, ContractWrapper};
+
+/// Wraps a contract crate's `execute`, `instantiate` and `query` entry points
+/// for `App::store_code`, e.g. `contract_wrapper!(treasury)` or
+/// `contract_wrapper!(crate)` for the crate under test
+#[macro_export]
+macro_rules! contract_wrapper {
+    ($krate:ident) => {
+        ::std::boxed::Box::new($crate::testing::ContractWrapper::new(
+            $krate::contract::execute,
+            $krate::contract::instantiate,
+            $krate::contract::query,
+        )) as ::std::boxed::Box<dyn $crate::testing::Contract<$crate::testing::Empty>>
+    };
+}
+
+pub fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    contract_wrapper!(cw20_base)
+}
+
+/// Instantiates an 18-decimal CW20 named and labelled `symbol`, mintable by
+/// `minter`
+pub fn instantiate_cw20(
+    app: &mut App,
+    code_id: u64,
+    minter: &str,
+    symbol: &str,
+    initial_balances: Vec<Cw20Coin>,
+) -> Addr {
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(minter),
+        &cw20_base::msg::InstantiateMsg {
+            name: symbol.to_string(),
+            symbol: symbol.to_string(),
+            decimals: 18,
+            initial_balances,
+            mint: Some(MinterResponse {
+                minter: minter.to_string(),
+                cap: None,
+            }),
+            marketing: None,
+        },
+        &[],
+        symbol,
+        None,
+    )
+    .unwrap()
+}
+
+/// CW20 balance of `address`
+pub fn balance(app: &App, token: &Addr, address: impl Into<String>) -> Uint128 {
+    let res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Balance {
+                address: address.into(),
+            },
+        )
+        .unwrap();
+    res.balance
+}
+
+/// Moves the block time forward by `seconds`
+pub fn advance(app: &mut App, seconds: u64) {
+    app.update_block(|block| block.time = block.time.plus_seconds(seconds));
+}
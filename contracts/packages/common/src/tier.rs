@@ -0,0 +1,36 @@
+//! Collateralization ratio tier definitions for UST1
+
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+
+/// UST1 collateralization ratio tier
+///
+/// | Tier   | CR Range     | Behavior                                             |
+/// |--------|--------------|------------------------------------------------------|
+/// | Red    | < 95%        | System locked; no minting or redemption              |
+/// | Yellow | 95% – 110%   | Auctions enabled solely to buy collateral            |
+/// | Green  | 110% – 190%  | Auctions additionally buy UST1 for collateral        |
+/// | Blue   | > 190%       | UST1 mints enabled for 5-year rolling pools          |
+///
+/// Thresholds are governance-set on the CR engine; the ranges above are the defaults.
+#[cw_serde]
+#[derive(Copy, Eq, PartialOrd, Ord)]
+pub enum CrTier {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+}
+
+impl fmt::Display for CrTier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CrTier::Red => "red",
+            CrTier::Yellow => "yellow",
+            CrTier::Green => "green",
+            CrTier::Blue => "blue",
+        };
+        write!(f, "{}", s)
+    }
+}
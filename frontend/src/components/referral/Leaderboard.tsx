@@ -228,7 +228,8 @@ export function Leaderboard() {
   }, [fetchData]);
 
   // Check if a code belongs to connected wallet (or mock wallet in dev mode)
-  const isOwnCode = useCallback((owner: string) => {
+  const isOwnCode = useCallback((owner: string | null) => {
+    if (!owner) return false;
     if (DEV_MODE) {
       return owner.toLowerCase() === MOCK_WALLET_ADDRESS.toLowerCase();
     }
@@ -378,14 +379,20 @@ export function Leaderboard() {
                             </span>
                           )}
                         </div>
-                        <a
-                          href={getAddressScannerUrl(entry.owner)}
-                          target="_blank"
-                          rel="noopener noreferrer"
-                          className="text-xs text-gray-500 hover:text-gray-400 font-mono mt-0.5 block"
-                        >
-                          {formatAddress(entry.owner, 6)}
-                        </a>
+                        {entry.owner ? (
+                          <a
+                            href={getAddressScannerUrl(entry.owner)}
+                            target="_blank"
+                            rel="noopener noreferrer"
+                            className="text-xs text-gray-500 hover:text-gray-400 font-mono mt-0.5 block"
+                          >
+                            {formatAddress(entry.owner, 6)}
+                          </a>
+                        ) : (
+                          <span className="text-xs text-gray-500 font-mono mt-0.5 block">
+                            Owner unknown
+                          </span>
+                        )}
                       </td>
                       <td className="px-3 py-3 whitespace-nowrap text-right">
                         <span className={`font-mono font-medium ${
@@ -608,7 +608,7 @@ class ContractService {
     try {
       interface ContractLeaderboardEntry {
         code: string;
-        owner: string;
+        owner: string | null;
         total_rewards_earned: string;
         total_user_bonuses: string;
         total_swaps: number;
@@ -707,7 +707,7 @@ class ContractService {
       const MAX_ENTRIES = 50;
       const allEntries: Array<{
         code: string;
-        owner: string;
+        owner: string | null;
         total_rewards_earned: string;
         total_user_bonuses: string;
         total_swaps: number;
@@ -784,7 +784,7 @@ class ContractService {
       console.warn('Treasury contract address not configured');
       return {
         governance: '',
-        timelock_duration: 604800, // 7 days default
+        governance_transfer_timelock: 604800, // 7 days default
       };
     }
     
@@ -792,7 +792,7 @@ class ContractService {
       // Contract returns ConfigResponse with these fields
       interface ContractConfigResponse {
         governance: string;
-        timelock_duration: number;
+        governance_transfer_timelock: number;
         swap_contract: string | null;
       }
       
@@ -805,13 +805,13 @@ class ContractService {
       
       return {
         governance: data.governance,
-        timelock_duration: data.timelock_duration,
+        governance_transfer_timelock: data.governance_transfer_timelock,
       };
     } catch (error) {
       console.error('Failed to get treasury config:', error);
       return {
         governance: '',
-        timelock_duration: 604800,
+        governance_transfer_timelock: 604800,
       };
     }
   }
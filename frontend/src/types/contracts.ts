@@ -66,13 +66,16 @@ export interface TreasuryBalanceResponse {
 
 export interface TreasuryConfig {
   governance: string;
-  timelock_duration: number;
+  governance_transfer_timelock: number;
 }
 
-export interface PendingGovernance {
-  new_governance: string;
+export interface PendingGovernanceEntry {
+  new_address: string;
   execute_after: string;
-  time_remaining: number;
+}
+
+export interface PendingGovernanceResponse {
+  proposals: PendingGovernanceEntry[];
 }
 
 export interface TreasuryBalance {
@@ -151,15 +154,15 @@ export interface LeaderboardHint {
 export interface LeaderboardEntry {
   /** The referral code */
   code: string;
-  /** Code owner address */
-  owner: string;
+  /** Code owner address, null while the contract has no cached owner */
+  owner: string | null;
   /** Total USTR earned by the code owner from referrals */
   total_rewards_earned: string;
   /** Total USTR bonuses given to users who used this code */
   total_user_bonuses: string;
   /** Number of swaps that used this referral code */
   total_swaps: number;
-  /** Position on the leaderboard (1-indexed) */
+  /** 1-indexed rank by total rewards; tied codes share a rank */
   rank: number;
 }
 
@@ -191,7 +194,7 @@ export interface ReferralCodeStats {
 
 export type TreasuryQueryMsg =
   | { config: Record<string, never> }
-  | { pending_governance: Record<string, never> }
+  | { pending_governance: { start_after?: string; limit?: number } }
   | { balance: { asset: AssetInfo } }
   | { all_balances: Record<string, never> };
 